@@ -15,6 +15,7 @@ import 'package:wild/pages/novel/paragraph_spacing_cubit.dart';
 import 'package:wild/pages/novel/theme_cubit.dart';
 import 'package:wild/pages/novel/reader_type_cubit.dart';
 import 'package:wild/src/rust/frb_generated.dart';
+import 'package:wild/src/rust/api/wenku8.dart' as w8;
 import 'package:wild/src/rust/wenku8/models.dart';
 import 'package:wild/pages/home/bookshelf_cubit.dart';
 import 'package:wild/pages/home/category_page.dart';
@@ -52,6 +53,11 @@ Future<void> main() async {
   WidgetsFlutterBinding.ensureInitialized();
   await AppInfo.init();
   await RustLib.init();
+  // 退到后台或退出时把还没写入的 cookie 写入数据库
+  AppLifecycleListener(
+    onPause: () => w8.flushCookies(),
+    onDetach: () => w8.flushCookies(),
+  );
   runApp(const MyApp());
 }
 
@@ -0,0 +1,108 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../chapter_document.dart';
+import '../frb_generated.dart';
+import '../wenku8/models.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'wenku8.dart';
+
+Future<List<NovelSourceInfo>> listSources() =>
+    RustLib.instance.api.crateApiSourceListSources();
+
+Future<List<HomeBlock>> sourceIndex({required String sourceId}) =>
+    RustLib.instance.api.crateApiSourceSourceIndex(sourceId: sourceId);
+
+Future<String> sourceDownloadImage({
+  required String sourceId,
+  required String url,
+}) => RustLib.instance.api.crateApiSourceSourceDownloadImage(
+  sourceId: sourceId,
+  url: url,
+);
+
+Future<String> sourceChapterContent({
+  required String sourceId,
+  required String aid,
+  required String cid,
+}) => RustLib.instance.api.crateApiSourceSourceChapterContent(
+  sourceId: sourceId,
+  aid: aid,
+  cid: cid,
+);
+
+Future<ChapterDocument> sourceChapterDocument({
+  required String sourceId,
+  required String aid,
+  required String cid,
+}) => RustLib.instance.api.crateApiSourceSourceChapterDocument(
+  sourceId: sourceId,
+  aid: aid,
+  cid: cid,
+);
+
+/// 小说详情，标题和简介按阅读设置转换简繁
+Future<NovelInfo> sourceNovelInfo({
+  required String sourceId,
+  required String aid,
+}) => RustLib.instance.api.crateApiSourceSourceNovelInfo(
+  sourceId: sourceId,
+  aid: aid,
+);
+
+Future<List<Volume>> sourceNovelReader({
+  required String sourceId,
+  required String aid,
+}) => RustLib.instance.api.crateApiSourceSourceNovelReader(
+  sourceId: sourceId,
+  aid: aid,
+);
+
+Future<List<BookshelfItem>> sourceBookshelf({required String sourceId}) =>
+    RustLib.instance.api.crateApiSourceSourceBookshelf(sourceId: sourceId);
+
+/// 保存阅读进度，last_read_at 忽略，使用保存时的时间
+Future<void> sourceUpdateHistory({required ReadingHistory history}) =>
+    RustLib.instance.api.crateApiSourceSourceUpdateHistory(history: history);
+
+Future<PageStatsNovelCover> sourceSearch({
+  required String sourceId,
+  required String searchType,
+  required String searchKey,
+  required int page,
+}) => RustLib.instance.api.crateApiSourceSourceSearch(
+  sourceId: sourceId,
+  searchType: searchType,
+  searchKey: searchKey,
+  page: page,
+);
+
+Future<void> sourceDownloadNovel({
+  required String sourceId,
+  required String aid,
+  required List<String> cidList,
+}) => RustLib.instance.api.crateApiSourceSourceDownloadNovel(
+  sourceId: sourceId,
+  aid: aid,
+  cidList: cidList,
+);
+
+class NovelSourceInfo {
+  final String id;
+  final String name;
+
+  const NovelSourceInfo({required this.id, required this.name});
+
+  @override
+  int get hashCode => id.hashCode ^ name.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is NovelSourceInfo &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          name == other.name;
+}
@@ -11,3 +11,11 @@ Future<String> desktopRoot() =>
 
 Future<void> init({required String root}) =>
     RustLib.instance.api.crateApiSystemInit(root: root);
+
+/// 初始化并用平台提供的密钥（例如 Android Keystore 中保存的随机值）解锁密码保险箱
+/// 保险箱未开启时与 init 相同
+Future<void> initWithSecret({required String root, required String secret}) =>
+    RustLib.instance.api.crateApiSystemInitWithSecret(
+      root: root,
+      secret: secret,
+    );
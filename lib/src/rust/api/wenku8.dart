@@ -3,11 +3,17 @@
 
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
+import '../chapter_document.dart';
+import '../cookie_file.dart';
+import '../downloading.dart';
 import '../frb_generated.dart';
+import '../proxy.dart';
+import '../vault.dart';
+import '../wenku8/mirror.dart';
 import '../wenku8/models.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
 
 Future<void> wenku8Login({
   required String username,
@@ -22,11 +28,78 @@ Future<void> wenku8Login({
 Future<List<BookshelfItem>> wenku8GetBookshelf() =>
     RustLib.instance.api.crateApiWenku8Wenku8GetBookshelf();
 
+/// 通过 app 接口登录，不需要验证码
+Future<void> appLogin({required String username, required String password}) =>
+    RustLib.instance.api.crateApiWenku8AppLogin(
+      username: username,
+      password: password,
+    );
+
+/// 当前账号是否已登录
 Future<bool> preLoginState() =>
     RustLib.instance.api.crateApiWenku8PreLoginState();
 
+/// 退出当前账号
 Future<void> logout() => RustLib.instance.api.crateApiWenku8Logout();
 
+/// 应用退到后台或退出前调用，把还没写入的 cookie 写入数据库
+Future<void> flushCookies() =>
+    RustLib.instance.api.crateApiWenku8FlushCookies();
+
+/// 导入浏览器导出的 cookie（只保留文库的），读取用户信息验证登录状态
+/// 验证成功后 cookie 归到该用户的账号并切换过去，失败时保持原来的账号不变
+Future<UserDetail> importCookies({
+  required CookieFileFormat format,
+  required String content,
+}) => RustLib.instance.api.crateApiWenku8ImportCookies(
+  format: format,
+  content: content,
+);
+
+/// 导出当前账号的 cookie
+Future<String> exportCookies({required CookieFileFormat format}) =>
+    RustLib.instance.api.crateApiWenku8ExportCookies(format: format);
+
+Future<List<Account>> listAccounts() =>
+    RustLib.instance.api.crateApiWenku8ListAccounts();
+
+/// 当前账号，未绑定账号时为 None
+Future<Account?> activeAccount() =>
+    RustLib.instance.api.crateApiWenku8ActiveAccount();
+
+/// 准备登录新账号，登录成功后自动添加并切换到该账号
+Future<void> addAccount() => RustLib.instance.api.crateApiWenku8AddAccount();
+
+Future<void> switchAccount({required PlatformInt64 accountId}) =>
+    RustLib.instance.api.crateApiWenku8SwitchAccount(accountId: accountId);
+
+Future<void> removeAccount({required PlatformInt64 accountId}) =>
+    RustLib.instance.api.crateApiWenku8RemoveAccount(accountId: accountId);
+
+Future<VaultState> vaultState() =>
+    RustLib.instance.api.crateApiWenku8VaultState();
+
+/// 开启密码保险箱，secret 为用户输入的口令或平台提供的密钥
+/// 开启后登录成功的密码会加密保存，登录过期时自动重新登录
+Future<void> enableVault({required String secret}) =>
+    RustLib.instance.api.crateApiWenku8EnableVault(secret: secret);
+
+Future<void> unlockVault({required String secret}) =>
+    RustLib.instance.api.crateApiWenku8UnlockVault(secret: secret);
+
+Future<void> lockVault() => RustLib.instance.api.crateApiWenku8LockVault();
+
+/// 关闭密码保险箱并删除保存的密码
+Future<void> disableVault() =>
+    RustLib.instance.api.crateApiWenku8DisableVault();
+
+Future<bool> getAccountScopedHistory() =>
+    RustLib.instance.api.crateApiWenku8GetAccountScopedHistory();
+
+/// 开启后阅读记录和签到记录只显示当前账号的
+Future<void> setAccountScopedHistory({required bool scoped}) =>
+    RustLib.instance.api.crateApiWenku8SetAccountScopedHistory(scoped: scoped);
+
 Future<Uint8List> downloadCheckcode() =>
     RustLib.instance.api.crateApiWenku8DownloadCheckcode();
 
@@ -41,6 +114,12 @@ Future<String> downloadImage({required String url}) =>
 Future<String> chapterContent({required String aid, required String cid}) =>
     RustLib.instance.api.crateApiWenku8ChapterContent(aid: aid, cid: cid);
 
+/// 结构化的章节内容，插图带有本地路径和尺寸（如果已缓存或已下载）
+Future<ChapterDocument> chapterDocument({
+  required String aid,
+  required String cid,
+}) => RustLib.instance.api.crateApiWenku8ChapterDocument(aid: aid, cid: cid);
+
 Future<NovelInfo> novelInfo({required String aid}) =>
     RustLib.instance.api.crateApiWenku8NovelInfo(aid: aid);
 
@@ -105,6 +184,12 @@ Future<PageStatsNovelCover> toplist({
   required int page,
 }) => RustLib.instance.api.crateApiWenku8Toplist(sort: sort, page: page);
 
+/// 使用 app 接口获取排行榜，网页被拦截时可用
+Future<PageStatsNovelCover> appToplist({
+  required String sort,
+  required int page,
+}) => RustLib.instance.api.crateApiWenku8AppToplist(sort: sort, page: page);
+
 Future<PageStatsNovelCover> articlelist({
   required int fullflag,
   required int page,
@@ -150,6 +235,22 @@ Future<PageStatsNovelCover> search({
 
 Future<bool> autoSign() => RustLib.instance.api.crateApiWenku8AutoSign();
 
+/// 推荐小说，成功后记录到本地的推荐记录
+Future<void> recommend({required String aid}) =>
+    RustLib.instance.api.crateApiWenku8Recommend(aid: aid);
+
+/// 今天剩余的推荐次数，按用户信息中的每天允许推荐次数减去今天的本地记录
+Future<PlatformInt64> recommendRemaining() =>
+    RustLib.instance.api.crateApiWenku8RecommendRemaining();
+
+Future<List<RecommendLog>> recommendLogList({
+  required int offset,
+  required int limit,
+}) => RustLib.instance.api.crateApiWenku8RecommendLogList(
+  offset: offset,
+  limit: limit,
+);
+
 Future<void> downloadNovel({
   required String aid,
   required List<String> cidList,
@@ -161,6 +262,7 @@ Future<void> downloadNovel({
 Future<List<NovelDownload>> allDownloads() =>
     RustLib.instance.api.crateApiWenku8AllDownloads();
 
+/// 当前语言的下载记录
 Future<ExistsDownload?> existsDownload({required String novelId}) =>
     RustLib.instance.api.crateApiWenku8ExistsDownload(novelId: novelId);
 
@@ -169,9 +271,170 @@ Future<String> getApiHost() => RustLib.instance.api.crateApiWenku8GetApiHost();
 Future<void> setApiHost({required String apiHost}) =>
     RustLib.instance.api.crateApiWenku8SetApiHost(apiHost: apiHost);
 
+/// WebView 通过 Cloudflare 验证后调用，写入 cf_clearance 并固定使用 WebView 的 UA
+Future<void> setCfClearance({
+  required String url,
+  required String cfClearance,
+  required String userAgent,
+}) => RustLib.instance.api.crateApiWenku8SetCfClearance(
+  url: url,
+  cfClearance: cfClearance,
+  userAgent: userAgent,
+);
+
+Future<void> unpinUserAgent() =>
+    RustLib.instance.api.crateApiWenku8UnpinUserAgent();
+
+Future<String> getUserAgent() =>
+    RustLib.instance.api.crateApiWenku8GetUserAgent();
+
+/// 内容语言，zh-Hans 为简体，zh-Hant 为繁体
+Future<String> getLanguage() =>
+    RustLib.instance.api.crateApiWenku8GetLanguage();
+
+Future<void> setLanguage({required String language}) =>
+    RustLib.instance.api.crateApiWenku8SetLanguage(language: language);
+
+/// 阅读和导出时转换成的文字，zh-Hans 或 zh-Hant，空字符串为不转换
+Future<String> getDisplayLanguage() =>
+    RustLib.instance.api.crateApiWenku8GetDisplayLanguage();
+
+Future<void> setDisplayLanguage({required String language}) =>
+    RustLib.instance.api.crateApiWenku8SetDisplayLanguage(language: language);
+
+Future<ProxySettings> loadProxySettings() =>
+    RustLib.instance.api.crateApiWenku8LoadProxySettings();
+
+/// 保存并立即应用代理设置
+Future<void> saveProxySettings({required ProxySettings settings}) =>
+    RustLib.instance.api.crateApiWenku8SaveProxySettings(settings: settings);
+
+/// 使用给定的代理访问当前镜像，不会保存设置
+Future<ProxyTestResult> testProxy({required ProxySettings settings}) =>
+    RustLib.instance.api.crateApiWenku8TestProxy(settings: settings);
+
+Future<List<String>> loadMirrorHosts() =>
+    RustLib.instance.api.crateApiWenku8LoadMirrorHosts();
+
+Future<void> setMirrorHosts({required List<String> hosts}) =>
+    RustLib.instance.api.crateApiWenku8SetMirrorHosts(hosts: hosts);
+
+/// 测试所有镜像的可用性和延迟
+Future<List<MirrorProbe>> probeMirrors() =>
+    RustLib.instance.api.crateApiWenku8ProbeMirrors();
+
+/// 切换到最快的可用镜像，返回切换后的镜像，都不可用时返回 None
+Future<String?> selectFastestMirror() =>
+    RustLib.instance.api.crateApiWenku8SelectFastestMirror();
+
+/// 自动切换镜像时推送新的镜像地址
+Stream<String> apiHostChanges() =>
+    RustLib.instance.api.crateApiWenku8ApiHostChanges();
+
+/// 暂停单本小说的下载
+Future<void> pauseDownload({required String novelId}) =>
+    RustLib.instance.api.crateApiWenku8PauseDownload(novelId: novelId);
+
+Future<void> resumeDownload({required String novelId}) =>
+    RustLib.instance.api.crateApiWenku8ResumeDownload(novelId: novelId);
+
+/// 暂停全部下载，重启应用后仍然保持
+Future<void> pauseAllDownloads() =>
+    RustLib.instance.api.crateApiWenku8PauseAllDownloads();
+
+Future<void> resumeAllDownloads() =>
+    RustLib.instance.api.crateApiWenku8ResumeAllDownloads();
+
+Future<bool> downloadsPaused() =>
+    RustLib.instance.api.crateApiWenku8DownloadsPaused();
+
+/// 优先下载这本小说
+Future<void> moveDownloadToFront({required String novelId}) =>
+    RustLib.instance.api.crateApiWenku8MoveDownloadToFront(novelId: novelId);
+
+/// 按 novel_ids 的顺序重排下载队列
+Future<void> reorderDownloads({required List<String> novelIds}) =>
+    RustLib.instance.api.crateApiWenku8ReorderDownloads(novelIds: novelIds);
+
+Future<int> loadDownloadConcurrency() =>
+    RustLib.instance.api.crateApiWenku8LoadDownloadConcurrency();
+
+/// 设置同时下载的章节和图片数，下一本小说开始时生效
+Future<void> setDownloadConcurrency({required int concurrency}) =>
+    RustLib.instance.api.crateApiWenku8SetDownloadConcurrency(
+      concurrency: concurrency,
+    );
+
+Future<double> loadTextRateLimit() =>
+    RustLib.instance.api.crateApiWenku8LoadTextRateLimit();
+
+Future<void> setTextRateLimit({required double rate}) =>
+    RustLib.instance.api.crateApiWenku8SetTextRateLimit(rate: rate);
+
+Future<double> loadImageRateLimit() =>
+    RustLib.instance.api.crateApiWenku8LoadImageRateLimit();
+
+Future<void> setImageRateLimit({required double rate}) =>
+    RustLib.instance.api.crateApiWenku8SetImageRateLimit(rate: rate);
+
 Future<void> deleteDownload({required String novelId}) =>
     RustLib.instance.api.crateApiWenku8DeleteDownload(novelId: novelId);
 
+/// 导出已下载的小说为 EPUB3
+/// volume_ids 为空时导出全部卷；split_volumes 为 true 时每卷一个文件，out_path 为目录
+Future<List<String>> exportEpub({
+  required String novelId,
+  required List<String> volumeIds,
+  required String outPath,
+  required bool splitVolumes,
+}) => RustLib.instance.api.crateApiWenku8ExportEpub(
+  novelId: novelId,
+  volumeIds: volumeIds,
+  outPath: outPath,
+  splitVolumes: splitVolumes,
+);
+
+/// 订阅下载进度事件，Flutter 端取消订阅后自动结束
+Stream<DownloadEvent> downloadEvents() =>
+    RustLib.instance.api.crateApiWenku8DownloadEvents();
+
+/// 未读的新章节
+Future<List<NovelUpdate>> unseenNovelUpdates({
+  required int offset,
+  required int limit,
+}) => RustLib.instance.api.crateApiWenku8UnseenNovelUpdates(
+  offset: offset,
+  limit: limit,
+);
+
+Future<void> markNovelUpdatesSeen({required Int64List ids}) =>
+    RustLib.instance.api.crateApiWenku8MarkNovelUpdatesSeen(ids: ids);
+
+Future<void> markNovelUpdatesSeenByNovel({required String novelId}) =>
+    RustLib.instance.api.crateApiWenku8MarkNovelUpdatesSeenByNovel(
+      novelId: novelId,
+    );
+
+Future<void> markAllNovelUpdatesSeen() =>
+    RustLib.instance.api.crateApiWenku8MarkAllNovelUpdatesSeen();
+
+/// 立即检查更新，返回新章节数
+Future<int> checkNovelUpdates() =>
+    RustLib.instance.api.crateApiWenku8CheckNovelUpdates();
+
+Future<PlatformInt64> loadUpdateCheckInterval() =>
+    RustLib.instance.api.crateApiWenku8LoadUpdateCheckInterval();
+
+/// 设置自动检查更新的间隔（小时），0 为关闭
+Future<void> setUpdateCheckInterval({required PlatformInt64 hours}) =>
+    RustLib.instance.api.crateApiWenku8SetUpdateCheckInterval(hours: hours);
+
+Future<bool> loadAutoDownloadUpdates() =>
+    RustLib.instance.api.crateApiWenku8LoadAutoDownloadUpdates();
+
+Future<void> setAutoDownloadUpdates({required bool enabled}) =>
+    RustLib.instance.api.crateApiWenku8SetAutoDownloadUpdates(enabled: enabled);
+
 Future<void> cleanAllWebCache() =>
     RustLib.instance.api.crateApiWenku8CleanAllWebCache();
 
@@ -186,6 +449,94 @@ Future<PageStatsReviews> reviews({
   pageNumber: pageNumber,
 );
 
+Future<ReviewDetail> reviewDetail({required String rid, required int page}) =>
+    RustLib.instance.api.crateApiWenku8ReviewDetail(rid: rid, page: page);
+
+/// 发表书评，成功后清理这本小说的书评列表缓存
+Future<void> postReview({
+  required String aid,
+  required String title,
+  required String content,
+}) => RustLib.instance.api.crateApiWenku8PostReview(
+  aid: aid,
+  title: title,
+  content: content,
+);
+
+/// 回复书评，aid 用于清理书评列表缓存（回复数会变化）
+Future<void> replyReview({
+  required String aid,
+  required String rid,
+  required String content,
+}) => RustLib.instance.api.crateApiWenku8ReplyReview(
+  aid: aid,
+  rid: rid,
+  content: content,
+);
+
+/// 短消息列表，box_name 为 inbox（收件箱）或 outbox（发件箱）
+Future<PageStatsMessage> messages({
+  required String boxName,
+  required int page,
+}) => RustLib.instance.api.crateApiWenku8Messages(boxName: boxName, page: page);
+
+Future<MessageDetail> messageDetail({required String id}) =>
+    RustLib.instance.api.crateApiWenku8MessageDetail(id: id);
+
+/// receiver 为对方的用户名
+Future<void> sendMessage({
+  required String receiver,
+  required String title,
+  required String content,
+}) => RustLib.instance.api.crateApiWenku8SendMessage(
+  receiver: receiver,
+  title: title,
+  content: content,
+);
+
+Future<void> deleteMessages({
+  required String boxName,
+  required List<String> ids,
+}) => RustLib.instance.api.crateApiWenku8DeleteMessages(
+  boxName: boxName,
+  ids: ids,
+);
+
+/// 解析接口抛出的异常信息，不是文库错误时返回 None
+Wenku8ErrorInfo? parseWenku8Error({required String message}) =>
+    RustLib.instance.api.crateApiWenku8ParseWenku8Error(message: message);
+
+class Account {
+  final PlatformInt64 id;
+  final String username;
+  final PlatformInt64 createTime;
+  final PlatformInt64 lastLoginTime;
+
+  const Account({
+    required this.id,
+    required this.username,
+    required this.createTime,
+    required this.lastLoginTime,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      username.hashCode ^
+      createTime.hashCode ^
+      lastLoginTime.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Account &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          username == other.username &&
+          createTime == other.createTime &&
+          lastLoginTime == other.lastLoginTime;
+}
+
 class ExistsDownload {
   final NovelDownload novelDownload;
   final List<NovelDownloadVolume> novelDownloadVolume;
@@ -230,6 +581,10 @@ class NovelDownload {
   final bool isAnimated;
   final String finUpdate;
   final String status;
+  final int priority;
+  final String sourceId;
+  /// 下载时的语言，简繁两个版本分开下载
+  final String language;
 
   const NovelDownload({
     required this.novelId,
@@ -248,6 +603,9 @@ class NovelDownload {
     required this.isAnimated,
     required this.finUpdate,
     required this.status,
+    required this.priority,
+    required this.sourceId,
+    required this.language,
   });
 
   @override
@@ -267,7 +625,10 @@ class NovelDownload {
       trending.hashCode ^
       isAnimated.hashCode ^
       finUpdate.hashCode ^
-      status.hashCode;
+      status.hashCode ^
+      priority.hashCode ^
+      sourceId.hashCode ^
+      language.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -289,7 +650,10 @@ class NovelDownload {
           trending == other.trending &&
           isAnimated == other.isAnimated &&
           finUpdate == other.finUpdate &&
-          status == other.status;
+          status == other.status &&
+          priority == other.priority &&
+          sourceId == other.sourceId &&
+          language == other.language;
 }
 
 class NovelDownloadChapter {
@@ -378,6 +742,78 @@ class NovelDownloadVolume {
           createTime == other.createTime;
 }
 
+class NovelUpdate {
+  final PlatformInt64 id;
+  final String novelId;
+  final String novelName;
+  final String volumeId;
+  final String volumeTitle;
+  final String chapterId;
+  final String chapterTitle;
+  final PlatformInt64 createTime;
+
+  const NovelUpdate({
+    required this.id,
+    required this.novelId,
+    required this.novelName,
+    required this.volumeId,
+    required this.volumeTitle,
+    required this.chapterId,
+    required this.chapterTitle,
+    required this.createTime,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      novelId.hashCode ^
+      novelName.hashCode ^
+      volumeId.hashCode ^
+      volumeTitle.hashCode ^
+      chapterId.hashCode ^
+      chapterTitle.hashCode ^
+      createTime.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is NovelUpdate &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          novelId == other.novelId &&
+          novelName == other.novelName &&
+          volumeId == other.volumeId &&
+          volumeTitle == other.volumeTitle &&
+          chapterId == other.chapterId &&
+          chapterTitle == other.chapterTitle &&
+          createTime == other.createTime;
+}
+
+class PageStatsMessage {
+  final int currentPage;
+  final int maxPage;
+  final List<Message> records;
+
+  const PageStatsMessage({
+    required this.currentPage,
+    required this.maxPage,
+    required this.records,
+  });
+
+  @override
+  int get hashCode =>
+      currentPage.hashCode ^ maxPage.hashCode ^ records.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PageStatsMessage &&
+          runtimeType == other.runtimeType &&
+          currentPage == other.currentPage &&
+          maxPage == other.maxPage &&
+          records == other.records;
+}
+
 class PageStatsNovelCover {
   final int currentPage;
   final int maxPage;
@@ -443,6 +879,7 @@ class ReadingHistory {
   final int progressPage;
   final String cover;
   final String author;
+  final String sourceId;
 
   const ReadingHistory({
     required this.novelId,
@@ -456,6 +893,7 @@ class ReadingHistory {
     required this.progressPage,
     required this.cover,
     required this.author,
+    required this.sourceId,
   });
 
   @override
@@ -470,7 +908,8 @@ class ReadingHistory {
       progress.hashCode ^
       progressPage.hashCode ^
       cover.hashCode ^
-      author.hashCode;
+      author.hashCode ^
+      sourceId.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -487,7 +926,43 @@ class ReadingHistory {
           progress == other.progress &&
           progressPage == other.progressPage &&
           cover == other.cover &&
-          author == other.author;
+          author == other.author &&
+          sourceId == other.sourceId;
+}
+
+class RecommendLog {
+  final PlatformInt64 id;
+  final PlatformInt64 accountId;
+  final String date;
+  final String novelId;
+  final PlatformInt64 createTime;
+
+  const RecommendLog({
+    required this.id,
+    required this.accountId,
+    required this.date,
+    required this.novelId,
+    required this.createTime,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      accountId.hashCode ^
+      date.hashCode ^
+      novelId.hashCode ^
+      createTime.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RecommendLog &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          accountId == other.accountId &&
+          date == other.date &&
+          novelId == other.novelId &&
+          createTime == other.createTime;
 }
 
 class SearchHistory {
@@ -514,3 +989,46 @@ class SearchHistory {
           searchKey == other.searchKey &&
           searchTime == other.searchTime;
 }
+
+/// http_status 只在 kind 为 Http 时有值，message 为服务器提示或网络错误信息
+class Wenku8ErrorInfo {
+  final Wenku8ErrorKind kind;
+  final int httpStatus;
+  final String message;
+
+  const Wenku8ErrorInfo({
+    required this.kind,
+    required this.httpStatus,
+    required this.message,
+  });
+
+  @override
+  int get hashCode => kind.hashCode ^ httpStatus.hashCode ^ message.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Wenku8ErrorInfo &&
+          runtimeType == other.runtimeType &&
+          kind == other.kind &&
+          httpStatus == other.httpStatus &&
+          message == other.message;
+}
+
+enum Wenku8ErrorKind {
+  cloudflareChallenge,
+  notLoggedIn,
+  captchaWrong,
+  captchaRequired,
+  loginFailed,
+  rateLimited,
+  notFound,
+  copyrightRestricted,
+  permissionDenied,
+  votesExhausted,
+  decode,
+  network,
+  http,
+  server,
+  ;
+}
@@ -0,0 +1,79 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// 结构化的章节内容，阅读器、导出和搜索共用
+class ChapterDocument {
+  final String aid;
+  final String cid;
+  final List<ChapterSegment> segments;
+
+  const ChapterDocument({
+    required this.aid,
+    required this.cid,
+    required this.segments,
+  });
+
+  @override
+  int get hashCode => aid.hashCode ^ cid.hashCode ^ segments.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ChapterDocument &&
+          runtimeType == other.runtimeType &&
+          aid == other.aid &&
+          cid == other.cid &&
+          segments == other.segments;
+}
+
+/// 章节中的一段内容，Paragraph 只有 text，Image 只有 url、本地路径和尺寸
+class ChapterSegment {
+  final ChapterSegmentKind kind;
+  final String text;
+  final String url;
+  final String? localPath;
+  final int? width;
+  final int? height;
+
+  const ChapterSegment({
+    required this.kind,
+    required this.text,
+    required this.url,
+    required this.localPath,
+    required this.width,
+    required this.height,
+  });
+
+  @override
+  int get hashCode =>
+      kind.hashCode ^
+      text.hashCode ^
+      url.hashCode ^
+      localPath.hashCode ^
+      width.hashCode ^
+      height.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ChapterSegment &&
+          runtimeType == other.runtimeType &&
+          kind == other.kind &&
+          text == other.text &&
+          url == other.url &&
+          localPath == other.localPath &&
+          width == other.width &&
+          height == other.height;
+}
+
+enum ChapterSegmentKind {
+  paragraph,
+  image,
+  separator,
+  ;
+}
@@ -0,0 +1,75 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// 下载进度事件，通过 StreamSink 推送给 Flutter
+/// 与 kind 无关的字段为空字符串或 0
+class DownloadEvent {
+  final DownloadEventKind kind;
+  final String sourceId;
+  final String language;
+  final String novelId;
+  final String volumeId;
+  final String chapterId;
+  /// ChapterDone/NovelCompleted 为已下载章节数，PictureDone 为已下载图片数
+  final int downloadCount;
+  /// ChapterDone 为选择的章节数，PictureDone 为图片总数
+  final int totalCount;
+  /// ChapterFailed 的错误信息
+  final String error;
+
+  const DownloadEvent({
+    required this.kind,
+    required this.sourceId,
+    required this.language,
+    required this.novelId,
+    required this.volumeId,
+    required this.chapterId,
+    required this.downloadCount,
+    required this.totalCount,
+    required this.error,
+  });
+
+  @override
+  int get hashCode =>
+      kind.hashCode ^
+      sourceId.hashCode ^
+      language.hashCode ^
+      novelId.hashCode ^
+      volumeId.hashCode ^
+      chapterId.hashCode ^
+      downloadCount.hashCode ^
+      totalCount.hashCode ^
+      error.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DownloadEvent &&
+          runtimeType == other.runtimeType &&
+          kind == other.kind &&
+          sourceId == other.sourceId &&
+          language == other.language &&
+          novelId == other.novelId &&
+          volumeId == other.volumeId &&
+          chapterId == other.chapterId &&
+          downloadCount == other.downloadCount &&
+          totalCount == other.totalCount &&
+          error == other.error;
+}
+
+/// 下载进度事件的类型
+enum DownloadEventKind {
+  chapterStarted,
+  chapterDone,
+  chapterFailed,
+  pictureDone,
+  novelCompleted,
+  novelFailed,
+  deleted,
+  ;
+}
@@ -5,14 +5,21 @@
 
 import 'api/database.dart';
 import 'api/simple.dart';
+import 'api/source.dart';
 import 'api/system.dart';
 import 'api/wenku8.dart';
+import 'chapter_document.dart';
+import 'cookie_file.dart';
 import 'dart:async';
 import 'dart:convert';
+import 'downloading.dart';
 import 'frb_generated.dart';
 import 'frb_generated.io.dart'
     if (dart.library.js_interop) 'frb_generated.web.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'proxy.dart';
+import 'vault.dart';
+import 'wenku8/mirror.dart';
 import 'wenku8/models.dart';
 
 /// Main entrypoint of the Rust API
@@ -70,7 +77,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   String get codegenVersion => '2.11.1';
 
   @override
-  int get rustContentHash => 707116458;
+  int get rustContentHash => 900237076;
 
   static const kDefaultExternalLibraryLoaderConfig =
       ExternalLibraryLoaderConfig(
@@ -81,10 +88,26 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
 }
 
 abstract class RustLibApi extends BaseApi {
+  Future<Account?> crateApiWenku8ActiveAccount();
+
+  Future<void> crateApiWenku8AddAccount();
+
   Future<void> crateApiWenku8AddBookshelf({required String aid});
 
   Future<List<NovelDownload>> crateApiWenku8AllDownloads();
 
+  Stream<String> crateApiWenku8ApiHostChanges();
+
+  Future<void> crateApiWenku8AppLogin({
+    required String username,
+    required String password,
+  });
+
+  Future<PageStatsNovelCover> crateApiWenku8AppToplist({
+    required String sort,
+    required int page,
+  });
+
   Future<PageStatsNovelCover> crateApiWenku8Articlelist({
     required int fullflag,
     required int page,
@@ -101,6 +124,13 @@ abstract class RustLibApi extends BaseApi {
     required String cid,
   });
 
+  Future<ChapterDocument> crateApiWenku8ChapterDocument({
+    required String aid,
+    required String cid,
+  });
+
+  Future<int> crateApiWenku8CheckNovelUpdates();
+
   Future<void> crateApiWenku8CleanAllWebCache();
 
   Future<void> crateApiWenku8DeleteAllHistory();
@@ -111,10 +141,19 @@ abstract class RustLibApi extends BaseApi {
 
   Future<void> crateApiWenku8DeleteHistoryByNovelId({required String novelId});
 
+  Future<void> crateApiWenku8DeleteMessages({
+    required String boxName,
+    required List<String> ids,
+  });
+
   Future<String> crateApiSystemDesktopRoot();
 
+  Future<void> crateApiWenku8DisableVault();
+
   Future<Uint8List> crateApiWenku8DownloadCheckcode();
 
+  Stream<DownloadEvent> crateApiWenku8DownloadEvents();
+
   Future<String> crateApiWenku8DownloadImage({required String url});
 
   Future<void> crateApiWenku8DownloadNovel({
@@ -122,35 +161,107 @@ abstract class RustLibApi extends BaseApi {
     required List<String> cidList,
   });
 
+  Future<bool> crateApiWenku8DownloadsPaused();
+
+  Future<void> crateApiWenku8EnableVault({required String secret});
+
   Future<ExistsDownload?> crateApiWenku8ExistsDownload({
     required String novelId,
   });
 
+  Future<String> crateApiWenku8ExportCookies({
+    required CookieFileFormat format,
+  });
+
+  Future<List<String>> crateApiWenku8ExportEpub({
+    required String novelId,
+    required List<String> volumeIds,
+    required String outPath,
+    required bool splitVolumes,
+  });
+
+  Future<void> crateApiWenku8FlushCookies();
+
+  Future<bool> crateApiWenku8GetAccountScopedHistory();
+
   Future<String> crateApiWenku8GetApiHost();
 
+  Future<String> crateApiWenku8GetDisplayLanguage();
+
+  Future<String> crateApiWenku8GetLanguage();
+
+  Future<String> crateApiWenku8GetUserAgent();
+
   String crateApiSimpleGreet({required String name});
 
+  Future<UserDetail> crateApiWenku8ImportCookies({
+    required CookieFileFormat format,
+    required String content,
+  });
+
   Future<List<HomeBlock>> crateApiWenku8Index();
 
   Future<void> crateApiSystemInit({required String root});
 
   Future<void> crateApiSimpleInitApp();
 
+  Future<void> crateApiSystemInitWithSecret({
+    required String root,
+    required String secret,
+  });
+
+  Future<List<Account>> crateApiWenku8ListAccounts();
+
   Future<List<ReadingHistory>> crateApiWenku8ListReadingHistory({
     required int offset,
     required int limit,
   });
 
+  Future<List<NovelSourceInfo>> crateApiSourceListSources();
+
+  Future<bool> crateApiWenku8LoadAutoDownloadUpdates();
+
+  Future<int> crateApiWenku8LoadDownloadConcurrency();
+
+  Future<double> crateApiWenku8LoadImageRateLimit();
+
+  Future<List<String>> crateApiWenku8LoadMirrorHosts();
+
   Future<String> crateApiDatabaseLoadProperty({required String key});
 
+  Future<ProxySettings> crateApiWenku8LoadProxySettings();
+
+  Future<double> crateApiWenku8LoadTextRateLimit();
+
+  Future<PlatformInt64> crateApiWenku8LoadUpdateCheckInterval();
+
+  Future<void> crateApiWenku8LockVault();
+
   Future<void> crateApiWenku8Logout();
 
+  Future<void> crateApiWenku8MarkAllNovelUpdatesSeen();
+
+  Future<void> crateApiWenku8MarkNovelUpdatesSeen({required Int64List ids});
+
+  Future<void> crateApiWenku8MarkNovelUpdatesSeenByNovel({
+    required String novelId,
+  });
+
+  Future<MessageDetail> crateApiWenku8MessageDetail({required String id});
+
+  Future<PageStatsMessage> crateApiWenku8Messages({
+    required String boxName,
+    required int page,
+  });
+
   Future<void> crateApiWenku8MoveBookcase({
     required List<String> bidList,
     required String fromBookcaseId,
     required String toBookcaseId,
   });
 
+  Future<void> crateApiWenku8MoveDownloadToFront({required String novelId});
+
   Future<ReadingHistory?> crateApiWenku8NovelHistoryById({
     required String novelId,
   });
@@ -161,10 +272,52 @@ abstract class RustLibApi extends BaseApi {
 
   Future<PageStatsNovelCover> crateApiWenku8PageStatsNovelCoverDefault();
 
+  Wenku8ErrorInfo? crateApiWenku8ParseWenku8Error({required String message});
+
+  Future<void> crateApiWenku8PauseAllDownloads();
+
+  Future<void> crateApiWenku8PauseDownload({required String novelId});
+
+  Future<void> crateApiWenku8PostReview({
+    required String aid,
+    required String title,
+    required String content,
+  });
+
   Future<bool> crateApiWenku8PreLoginState();
 
+  Future<List<MirrorProbe>> crateApiWenku8ProbeMirrors();
+
+  Future<void> crateApiWenku8Recommend({required String aid});
+
+  Future<List<RecommendLog>> crateApiWenku8RecommendLogList({
+    required int offset,
+    required int limit,
+  });
+
+  Future<PlatformInt64> crateApiWenku8RecommendRemaining();
+
+  Future<void> crateApiWenku8RemoveAccount({required PlatformInt64 accountId});
+
+  Future<void> crateApiWenku8ReorderDownloads({required List<String> novelIds});
+
+  Future<void> crateApiWenku8ReplyReview({
+    required String aid,
+    required String rid,
+    required String content,
+  });
+
   Future<void> crateApiWenku8ResetFailDownloads();
 
+  Future<void> crateApiWenku8ResumeAllDownloads();
+
+  Future<void> crateApiWenku8ResumeDownload({required String novelId});
+
+  Future<ReviewDetail> crateApiWenku8ReviewDetail({
+    required String rid,
+    required int page,
+  });
+
   Future<PageStatsReviews> crateApiWenku8Reviews({
     required String aid,
     required int pageNumber,
@@ -175,6 +328,10 @@ abstract class RustLibApi extends BaseApi {
     required String value,
   });
 
+  Future<void> crateApiWenku8SaveProxySettings({
+    required ProxySettings settings,
+  });
+
   Future<PageStatsNovelCover> crateApiWenku8Search({
     required String searchType,
     required String searchKey,
@@ -183,8 +340,94 @@ abstract class RustLibApi extends BaseApi {
 
   Future<List<SearchHistory>> crateApiWenku8SearchHistories();
 
+  Future<String?> crateApiWenku8SelectFastestMirror();
+
+  Future<void> crateApiWenku8SendMessage({
+    required String receiver,
+    required String title,
+    required String content,
+  });
+
+  Future<void> crateApiWenku8SetAccountScopedHistory({required bool scoped});
+
   Future<void> crateApiWenku8SetApiHost({required String apiHost});
 
+  Future<void> crateApiWenku8SetAutoDownloadUpdates({required bool enabled});
+
+  Future<void> crateApiWenku8SetCfClearance({
+    required String url,
+    required String cfClearance,
+    required String userAgent,
+  });
+
+  Future<void> crateApiWenku8SetDisplayLanguage({required String language});
+
+  Future<void> crateApiWenku8SetDownloadConcurrency({required int concurrency});
+
+  Future<void> crateApiWenku8SetImageRateLimit({required double rate});
+
+  Future<void> crateApiWenku8SetLanguage({required String language});
+
+  Future<void> crateApiWenku8SetMirrorHosts({required List<String> hosts});
+
+  Future<void> crateApiWenku8SetTextRateLimit({required double rate});
+
+  Future<void> crateApiWenku8SetUpdateCheckInterval({
+    required PlatformInt64 hours,
+  });
+
+  Future<List<BookshelfItem>> crateApiSourceSourceBookshelf({
+    required String sourceId,
+  });
+
+  Future<String> crateApiSourceSourceChapterContent({
+    required String sourceId,
+    required String aid,
+    required String cid,
+  });
+
+  Future<ChapterDocument> crateApiSourceSourceChapterDocument({
+    required String sourceId,
+    required String aid,
+    required String cid,
+  });
+
+  Future<String> crateApiSourceSourceDownloadImage({
+    required String sourceId,
+    required String url,
+  });
+
+  Future<void> crateApiSourceSourceDownloadNovel({
+    required String sourceId,
+    required String aid,
+    required List<String> cidList,
+  });
+
+  Future<List<HomeBlock>> crateApiSourceSourceIndex({required String sourceId});
+
+  Future<NovelInfo> crateApiSourceSourceNovelInfo({
+    required String sourceId,
+    required String aid,
+  });
+
+  Future<List<Volume>> crateApiSourceSourceNovelReader({
+    required String sourceId,
+    required String aid,
+  });
+
+  Future<PageStatsNovelCover> crateApiSourceSourceSearch({
+    required String sourceId,
+    required String searchType,
+    required String searchKey,
+    required int page,
+  });
+
+  Future<void> crateApiSourceSourceUpdateHistory({
+    required ReadingHistory history,
+  });
+
+  Future<void> crateApiWenku8SwitchAccount({required PlatformInt64 accountId});
+
   Future<PageStatsNovelCover> crateApiWenku8TagPage({
     required String tag,
     required String v,
@@ -193,11 +436,24 @@ abstract class RustLibApi extends BaseApi {
 
   Future<List<TagGroup>> crateApiWenku8Tags();
 
+  Future<ProxyTestResult> crateApiWenku8TestProxy({
+    required ProxySettings settings,
+  });
+
   Future<PageStatsNovelCover> crateApiWenku8Toplist({
     required String sort,
     required int page,
   });
 
+  Future<void> crateApiWenku8UnlockVault({required String secret});
+
+  Future<void> crateApiWenku8UnpinUserAgent();
+
+  Future<List<NovelUpdate>> crateApiWenku8UnseenNovelUpdates({
+    required int offset,
+    required int limit,
+  });
+
   Future<void> crateApiWenku8UpdateHistory({
     required String novelId,
     required String novelName,
@@ -213,6 +469,8 @@ abstract class RustLibApi extends BaseApi {
 
   Future<UserDetail> crateApiWenku8UserDetail();
 
+  Future<VaultState> crateApiWenku8VaultState();
+
   Future<List<BookshelfItem>> crateApiWenku8Wenku8GetBookshelf();
 
   Future<void> crateApiWenku8Wenku8Login({
@@ -230,6 +488,60 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     required super.portManager,
   });
 
+  @override
+  Future<Account?> crateApiWenku8ActiveAccount() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 1,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_account,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWenku8ActiveAccountConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWenku8ActiveAccountConstMeta =>
+      const TaskConstMeta(debugName: "active_account", argNames: []);
+
+  @override
+  Future<void> crateApiWenku8AddAccount() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 2,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWenku8AddAccountConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWenku8AddAccountConstMeta =>
+      const TaskConstMeta(debugName: "add_account", argNames: []);
+
   @override
   Future<void> crateApiWenku8AddBookshelf({required String aid}) {
     return handler.executeNormal(
@@ -240,7 +552,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 1,
+            funcId: 3,
             port: port_,
           );
         },
@@ -259,7 +571,2281 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       const TaskConstMeta(debugName: "add_bookshelf", argNames: ["aid"]);
 
   @override
-  Future<List<NovelDownload>> crateApiWenku8AllDownloads() {
+  Future<List<NovelDownload>> crateApiWenku8AllDownloads() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 4,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_novel_download,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWenku8AllDownloadsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWenku8AllDownloadsConstMeta =>
+      const TaskConstMeta(debugName: "all_downloads", argNames: []);
+
+  @override
+  Stream<String> crateApiWenku8ApiHostChanges() {
+    final sink = RustStreamSink<String>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_StreamSink_String_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 5,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_AnyhowException,
+          ),
+          constMeta: kCrateApiWenku8ApiHostChangesConstMeta,
+          argValues: [sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiWenku8ApiHostChangesConstMeta =>
+      const TaskConstMeta(debugName: "api_host_changes", argNames: ["sink"]);
+
+  @override
+  Future<void> crateApiWenku8AppLogin({
+    required String username,
+    required String password,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(username, serializer);
+          sse_encode_String(password, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 6,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWenku8AppLoginConstMeta,
+        argValues: [username, password],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWenku8AppLoginConstMeta => const TaskConstMeta(
+    debugName: "app_login",
+    argNames: ["username", "password"],
+  );
+
+  @override
+  Future<PageStatsNovelCover> crateApiWenku8AppToplist({
+    required String sort,
+    required int page,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(sort, serializer);
+          sse_encode_i_32(page, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 7,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_page_stats_novel_cover,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWenku8AppToplistConstMeta,
+        argValues: [sort, page],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWenku8AppToplistConstMeta =>
+      const TaskConstMeta(debugName: "app_toplist", argNames: ["sort", "page"]);
+
+  @override
+  Future<PageStatsNovelCover> crateApiWenku8Articlelist({
+    required int fullflag,
+    required int page,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_32(fullflag, serializer);
+          sse_encode_i_32(page, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 8,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_page_stats_novel_cover,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWenku8ArticlelistConstMeta,
+        argValues: [fullflag, page],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWenku8ArticlelistConstMeta => const TaskConstMeta(
+    debugName: "articlelist",
+    argNames: ["fullflag", "page"],
+  );
+
+  @override
+  Future<bool> crateApiWenku8AutoSign() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 9,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWenku8AutoSignConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWenku8AutoSignConstMeta =>
+      const TaskConstMeta(debugName: "auto_sign", argNames: []);
+
+  @override
+  Future<BookcaseDto> crateApiWenku8BookInCase({required String caseId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(caseId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 10,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bookcase_dto,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWenku8BookInCaseConstMeta,
+        argValues: [caseId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWenku8BookInCaseConstMeta =>
+      const TaskConstMeta(debugName: "book_in_case", argNames: ["caseId"]);
+
+  @override
+  Future<List<Bookcase>> crateApiWenku8BookcaseList() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 11,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_bookcase,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWenku8BookcaseListConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWenku8BookcaseListConstMeta =>
+      const TaskConstMeta(debugName: "bookcase_list", argNames: []);
+
+  @override
+  Future<String> crateApiWenku8ChapterContent({
+    required String aid,
+    required String cid,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(aid, serializer);
+          sse_encode_String(cid, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 12,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWenku8ChapterContentConstMeta,
+        argValues: [aid, cid],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWenku8ChapterContentConstMeta =>
+      const TaskConstMeta(
+        debugName: "chapter_content",
+        argNames: ["aid", "cid"],
+      );
+
+  @override
+  Future<ChapterDocument> crateApiWenku8ChapterDocument({
+    required String aid,
+    required String cid,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(aid, serializer);
+          sse_encode_String(cid, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 13,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_chapter_document,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWenku8ChapterDocumentConstMeta,
+        argValues: [aid, cid],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWenku8ChapterDocumentConstMeta =>
+      const TaskConstMeta(
+        debugName: "chapter_document",
+        argNames: ["aid", "cid"],
+      );
+
+  @override
+  Future<int> crateApiWenku8CheckNovelUpdates() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 14,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_i_32,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWenku8CheckNovelUpdatesConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWenku8CheckNovelUpdatesConstMeta =>
+      const TaskConstMeta(debugName: "check_novel_updates", argNames: []);
+
+  @override
+  Future<void> crateApiWenku8CleanAllWebCache() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 15,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWenku8CleanAllWebCacheConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWenku8CleanAllWebCacheConstMeta =>
+      const TaskConstMeta(debugName: "clean_all_web_cache", argNames: []);
+
+  @override
+  Future<void> crateApiWenku8DeleteAllHistory() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 16,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWenku8DeleteAllHistoryConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWenku8DeleteAllHistoryConstMeta =>
+      const TaskConstMeta(debugName: "delete_all_history", argNames: []);
+
+  @override
+  Future<void> crateApiWenku8DeleteBookcase({required String bid}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(bid, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 17,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWenku8DeleteBookcaseConstMeta,
+        argValues: [bid],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWenku8DeleteBookcaseConstMeta =>
+      const TaskConstMeta(debugName: "delete_bookcase", argNames: ["bid"]);
+
+  @override
+  Future<void> crateApiWenku8DeleteDownload({required String novelId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(novelId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 18,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWenku8DeleteDownloadConstMeta,
+        argValues: [novelId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWenku8DeleteDownloadConstMeta =>
+      const TaskConstMeta(debugName: "delete_download", argNames: ["novelId"]);
+
+  @override
+  Future<void> crateApiWenku8DeleteHistoryByNovelId({required String novelId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(novelId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 19,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWenku8DeleteHistoryByNovelIdConstMeta,
+        argValues: [novelId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWenku8DeleteHistoryByNovelIdConstMeta =>
+      const TaskConstMeta(
+        debugName: "delete_history_by_novel_id",
+        argNames: ["novelId"],
+      );
+
+  @override
+  Future<void> crateApiWenku8DeleteMessages({
+    required String boxName,
+    required List<String> ids,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(boxName, serializer);
+          sse_encode_list_String(ids, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 20,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWenku8DeleteMessagesConstMeta,
+        argValues: [boxName, ids],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWenku8DeleteMessagesConstMeta =>
+      const TaskConstMeta(
+        debugName: "delete_messages",
+        argNames: ["boxName", "ids"],
+      );
+
+  @override
+  Future<String> crateApiSystemDesktopRoot() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 21,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSystemDesktopRootConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSystemDesktopRootConstMeta =>
+      const TaskConstMeta(debugName: "desktop_root", argNames: []);
+
+  @override
+  Future<void> crateApiWenku8DisableVault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 22,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWenku8DisableVaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWenku8DisableVaultConstMeta =>
+      const TaskConstMeta(debugName: "disable_vault", argNames: []);
+
+  @override
+  Future<Uint8List> crateApiWenku8DownloadCheckcode() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 23,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWenku8DownloadCheckcodeConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWenku8DownloadCheckcodeConstMeta =>
+      const TaskConstMeta(debugName: "download_checkcode", argNames: []);
+
+  @override
+  Stream<DownloadEvent> crateApiWenku8DownloadEvents() {
+    final sink = RustStreamSink<DownloadEvent>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_StreamSink_download_event_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 24,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_AnyhowException,
+          ),
+          constMeta: kCrateApiWenku8DownloadEventsConstMeta,
+          argValues: [sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiWenku8DownloadEventsConstMeta =>
+      const TaskConstMeta(debugName: "download_events", argNames: ["sink"]);
+
+  @override
+  Future<String> crateApiWenku8DownloadImage({required String url}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(url, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 25,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWenku8DownloadImageConstMeta,
+        argValues: [url],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWenku8DownloadImageConstMeta =>
+      const TaskConstMeta(debugName: "download_image", argNames: ["url"]);
+
+  @override
+  Future<void> crateApiWenku8DownloadNovel({
+    required String aid,
+    required List<String> cidList,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(aid, serializer);
+          sse_encode_list_String(cidList, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 26,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWenku8DownloadNovelConstMeta,
+        argValues: [aid, cidList],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWenku8DownloadNovelConstMeta =>
+      const TaskConstMeta(
+        debugName: "download_novel",
+        argNames: ["aid", "cidList"],
+      );
+
+  @override
+  Future<bool> crateApiWenku8DownloadsPaused() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 27,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWenku8DownloadsPausedConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWenku8DownloadsPausedConstMeta =>
+      const TaskConstMeta(debugName: "downloads_paused", argNames: []);
+
+  @override
+  Future<void> crateApiWenku8EnableVault({required String secret}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(secret, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 28,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWenku8EnableVaultConstMeta,
+        argValues: [secret],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWenku8EnableVaultConstMeta =>
+      const TaskConstMeta(debugName: "enable_vault", argNames: ["secret"]);
+
+  @override
+  Future<ExistsDownload?> crateApiWenku8ExistsDownload({
+    required String novelId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(novelId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 29,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_exists_download,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWenku8ExistsDownloadConstMeta,
+        argValues: [novelId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWenku8ExistsDownloadConstMeta =>
+      const TaskConstMeta(debugName: "exists_download", argNames: ["novelId"]);
+
+  @override
+  Future<String> crateApiWenku8ExportCookies({
+    required CookieFileFormat format,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_cookie_file_format(format, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 30,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWenku8ExportCookiesConstMeta,
+        argValues: [format],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWenku8ExportCookiesConstMeta =>
+      const TaskConstMeta(debugName: "export_cookies", argNames: ["format"]);
+
+  @override
+  Future<List<String>> crateApiWenku8ExportEpub({
+    required String novelId,
+    required List<String> volumeIds,
+    required String outPath,
+    required bool splitVolumes,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(novelId, serializer);
+          sse_encode_list_String(volumeIds, serializer);
+          sse_encode_String(outPath, serializer);
+          sse_encode_bool(splitVolumes, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 31,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWenku8ExportEpubConstMeta,
+        argValues: [novelId, volumeIds, outPath, splitVolumes],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWenku8ExportEpubConstMeta => const TaskConstMeta(
+    debugName: "export_epub",
+    argNames: ["novelId", "volumeIds", "outPath", "splitVolumes"],
+  );
+
+  @override
+  Future<void> crateApiWenku8FlushCookies() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 32,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWenku8FlushCookiesConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWenku8FlushCookiesConstMeta =>
+      const TaskConstMeta(debugName: "flush_cookies", argNames: []);
+
+  @override
+  Future<bool> crateApiWenku8GetAccountScopedHistory() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 33,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiWenku8GetAccountScopedHistoryConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWenku8GetAccountScopedHistoryConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_account_scoped_history",
+        argNames: [],
+      );
+
+  @override
+  Future<String> crateApiWenku8GetApiHost() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 34,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWenku8GetApiHostConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWenku8GetApiHostConstMeta =>
+      const TaskConstMeta(debugName: "get_api_host", argNames: []);
+
+  @override
+  Future<String> crateApiWenku8GetDisplayLanguage() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 35,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWenku8GetDisplayLanguageConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWenku8GetDisplayLanguageConstMeta =>
+      const TaskConstMeta(debugName: "get_display_language", argNames: []);
+
+  @override
+  Future<String> crateApiWenku8GetLanguage() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 36,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWenku8GetLanguageConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWenku8GetLanguageConstMeta =>
+      const TaskConstMeta(debugName: "get_language", argNames: []);
+
+  @override
+  Future<String> crateApiWenku8GetUserAgent() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 37,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWenku8GetUserAgentConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWenku8GetUserAgentConstMeta =>
+      const TaskConstMeta(debugName: "get_user_agent", argNames: []);
+
+  @override
+  String crateApiSimpleGreet({required String name}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(name, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 38)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSimpleGreetConstMeta,
+        argValues: [name],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleGreetConstMeta =>
+      const TaskConstMeta(debugName: "greet", argNames: ["name"]);
+
+  @override
+  Future<UserDetail> crateApiWenku8ImportCookies({
+    required CookieFileFormat format,
+    required String content,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_cookie_file_format(format, serializer);
+          sse_encode_String(content, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 39,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_user_detail,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWenku8ImportCookiesConstMeta,
+        argValues: [format, content],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWenku8ImportCookiesConstMeta =>
+      const TaskConstMeta(
+        debugName: "import_cookies",
+        argNames: ["format", "content"],
+      );
+
+  @override
+  Future<List<HomeBlock>> crateApiWenku8Index() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 40,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_home_block,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWenku8IndexConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWenku8IndexConstMeta =>
+      const TaskConstMeta(debugName: "index", argNames: []);
+
+  @override
+  Future<void> crateApiSystemInit({required String root}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(root, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 41,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSystemInitConstMeta,
+        argValues: [root],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSystemInitConstMeta =>
+      const TaskConstMeta(debugName: "init", argNames: ["root"]);
+
+  @override
+  Future<void> crateApiSimpleInitApp() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 42,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSimpleInitAppConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleInitAppConstMeta =>
+      const TaskConstMeta(debugName: "init_app", argNames: []);
+
+  @override
+  Future<void> crateApiSystemInitWithSecret({
+    required String root,
+    required String secret,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(root, serializer);
+          sse_encode_String(secret, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 43,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSystemInitWithSecretConstMeta,
+        argValues: [root, secret],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSystemInitWithSecretConstMeta =>
+      const TaskConstMeta(
+        debugName: "init_with_secret",
+        argNames: ["root", "secret"],
+      );
+
+  @override
+  Future<List<Account>> crateApiWenku8ListAccounts() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 44,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_account,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWenku8ListAccountsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWenku8ListAccountsConstMeta =>
+      const TaskConstMeta(debugName: "list_accounts", argNames: []);
+
+  @override
+  Future<List<ReadingHistory>> crateApiWenku8ListReadingHistory({
+    required int offset,
+    required int limit,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_32(offset, serializer);
+          sse_encode_i_32(limit, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 45,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_reading_history,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWenku8ListReadingHistoryConstMeta,
+        argValues: [offset, limit],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWenku8ListReadingHistoryConstMeta =>
+      const TaskConstMeta(
+        debugName: "list_reading_history",
+        argNames: ["offset", "limit"],
+      );
+
+  @override
+  Future<List<NovelSourceInfo>> crateApiSourceListSources() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 46,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_novel_source_info,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSourceListSourcesConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSourceListSourcesConstMeta =>
+      const TaskConstMeta(debugName: "list_sources", argNames: []);
+
+  @override
+  Future<bool> crateApiWenku8LoadAutoDownloadUpdates() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 47,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWenku8LoadAutoDownloadUpdatesConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWenku8LoadAutoDownloadUpdatesConstMeta =>
+      const TaskConstMeta(
+        debugName: "load_auto_download_updates",
+        argNames: [],
+      );
+
+  @override
+  Future<int> crateApiWenku8LoadDownloadConcurrency() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 48,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_i_32,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWenku8LoadDownloadConcurrencyConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWenku8LoadDownloadConcurrencyConstMeta =>
+      const TaskConstMeta(debugName: "load_download_concurrency", argNames: []);
+
+  @override
+  Future<double> crateApiWenku8LoadImageRateLimit() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 49,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_f_64,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWenku8LoadImageRateLimitConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWenku8LoadImageRateLimitConstMeta =>
+      const TaskConstMeta(debugName: "load_image_rate_limit", argNames: []);
+
+  @override
+  Future<List<String>> crateApiWenku8LoadMirrorHosts() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 50,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWenku8LoadMirrorHostsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWenku8LoadMirrorHostsConstMeta =>
+      const TaskConstMeta(debugName: "load_mirror_hosts", argNames: []);
+
+  @override
+  Future<String> crateApiDatabaseLoadProperty({required String key}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(key, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 51,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiDatabaseLoadPropertyConstMeta,
+        argValues: [key],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDatabaseLoadPropertyConstMeta =>
+      const TaskConstMeta(debugName: "load_property", argNames: ["key"]);
+
+  @override
+  Future<ProxySettings> crateApiWenku8LoadProxySettings() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 52,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_proxy_settings,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWenku8LoadProxySettingsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWenku8LoadProxySettingsConstMeta =>
+      const TaskConstMeta(debugName: "load_proxy_settings", argNames: []);
+
+  @override
+  Future<double> crateApiWenku8LoadTextRateLimit() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 53,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_f_64,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWenku8LoadTextRateLimitConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWenku8LoadTextRateLimitConstMeta =>
+      const TaskConstMeta(debugName: "load_text_rate_limit", argNames: []);
+
+  @override
+  Future<PlatformInt64> crateApiWenku8LoadUpdateCheckInterval() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 54,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_i_64,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWenku8LoadUpdateCheckIntervalConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWenku8LoadUpdateCheckIntervalConstMeta =>
+      const TaskConstMeta(
+        debugName: "load_update_check_interval",
+        argNames: [],
+      );
+
+  @override
+  Future<void> crateApiWenku8LockVault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 55,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWenku8LockVaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWenku8LockVaultConstMeta =>
+      const TaskConstMeta(debugName: "lock_vault", argNames: []);
+
+  @override
+  Future<void> crateApiWenku8Logout() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 56,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWenku8LogoutConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWenku8LogoutConstMeta =>
+      const TaskConstMeta(debugName: "logout", argNames: []);
+
+  @override
+  Future<void> crateApiWenku8MarkAllNovelUpdatesSeen() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 57,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWenku8MarkAllNovelUpdatesSeenConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWenku8MarkAllNovelUpdatesSeenConstMeta =>
+      const TaskConstMeta(
+        debugName: "mark_all_novel_updates_seen",
+        argNames: [],
+      );
+
+  @override
+  Future<void> crateApiWenku8MarkNovelUpdatesSeen({required Int64List ids}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_i_64_strict(ids, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 58,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWenku8MarkNovelUpdatesSeenConstMeta,
+        argValues: [ids],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWenku8MarkNovelUpdatesSeenConstMeta =>
+      const TaskConstMeta(
+        debugName: "mark_novel_updates_seen",
+        argNames: ["ids"],
+      );
+
+  @override
+  Future<void> crateApiWenku8MarkNovelUpdatesSeenByNovel({
+    required String novelId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(novelId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 59,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWenku8MarkNovelUpdatesSeenByNovelConstMeta,
+        argValues: [novelId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWenku8MarkNovelUpdatesSeenByNovelConstMeta =>
+      const TaskConstMeta(
+        debugName: "mark_novel_updates_seen_by_novel",
+        argNames: ["novelId"],
+      );
+
+  @override
+  Future<MessageDetail> crateApiWenku8MessageDetail({required String id}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(id, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 60,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_message_detail,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWenku8MessageDetailConstMeta,
+        argValues: [id],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWenku8MessageDetailConstMeta =>
+      const TaskConstMeta(debugName: "message_detail", argNames: ["id"]);
+
+  @override
+  Future<PageStatsMessage> crateApiWenku8Messages({
+    required String boxName,
+    required int page,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(boxName, serializer);
+          sse_encode_i_32(page, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 61,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_page_stats_message,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWenku8MessagesConstMeta,
+        argValues: [boxName, page],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWenku8MessagesConstMeta =>
+      const TaskConstMeta(debugName: "messages", argNames: ["boxName", "page"]);
+
+  @override
+  Future<void> crateApiWenku8MoveBookcase({
+    required List<String> bidList,
+    required String fromBookcaseId,
+    required String toBookcaseId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_String(bidList, serializer);
+          sse_encode_String(fromBookcaseId, serializer);
+          sse_encode_String(toBookcaseId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 62,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWenku8MoveBookcaseConstMeta,
+        argValues: [bidList, fromBookcaseId, toBookcaseId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWenku8MoveBookcaseConstMeta => const TaskConstMeta(
+    debugName: "move_bookcase",
+    argNames: ["bidList", "fromBookcaseId", "toBookcaseId"],
+  );
+
+  @override
+  Future<void> crateApiWenku8MoveDownloadToFront({required String novelId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(novelId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 63,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWenku8MoveDownloadToFrontConstMeta,
+        argValues: [novelId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWenku8MoveDownloadToFrontConstMeta =>
+      const TaskConstMeta(
+        debugName: "move_download_to_front",
+        argNames: ["novelId"],
+      );
+
+  @override
+  Future<ReadingHistory?> crateApiWenku8NovelHistoryById({
+    required String novelId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(novelId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 64,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_reading_history,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWenku8NovelHistoryByIdConstMeta,
+        argValues: [novelId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWenku8NovelHistoryByIdConstMeta =>
+      const TaskConstMeta(
+        debugName: "novel_history_by_id",
+        argNames: ["novelId"],
+      );
+
+  @override
+  Future<NovelInfo> crateApiWenku8NovelInfo({required String aid}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(aid, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 65,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_novel_info,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWenku8NovelInfoConstMeta,
+        argValues: [aid],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWenku8NovelInfoConstMeta =>
+      const TaskConstMeta(debugName: "novel_info", argNames: ["aid"]);
+
+  @override
+  Future<List<Volume>> crateApiWenku8NovelReader({required String aid}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(aid, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 66,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_volume,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWenku8NovelReaderConstMeta,
+        argValues: [aid],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWenku8NovelReaderConstMeta =>
+      const TaskConstMeta(debugName: "novel_reader", argNames: ["aid"]);
+
+  @override
+  Future<PageStatsNovelCover> crateApiWenku8PageStatsNovelCoverDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 67,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_page_stats_novel_cover,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiWenku8PageStatsNovelCoverDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWenku8PageStatsNovelCoverDefaultConstMeta =>
+      const TaskConstMeta(
+        debugName: "page_stats_novel_cover_default",
+        argNames: [],
+      );
+
+  @override
+  Wenku8ErrorInfo? crateApiWenku8ParseWenku8Error({required String message}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(message, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 68)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_wenku_8_error_info,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiWenku8ParseWenku8ErrorConstMeta,
+        argValues: [message],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWenku8ParseWenku8ErrorConstMeta =>
+      const TaskConstMeta(
+        debugName: "parse_wenku8_error",
+        argNames: ["message"],
+      );
+
+  @override
+  Future<void> crateApiWenku8PauseAllDownloads() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 69,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWenku8PauseAllDownloadsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWenku8PauseAllDownloadsConstMeta =>
+      const TaskConstMeta(debugName: "pause_all_downloads", argNames: []);
+
+  @override
+  Future<void> crateApiWenku8PauseDownload({required String novelId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(novelId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 70,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWenku8PauseDownloadConstMeta,
+        argValues: [novelId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWenku8PauseDownloadConstMeta =>
+      const TaskConstMeta(debugName: "pause_download", argNames: ["novelId"]);
+
+  @override
+  Future<void> crateApiWenku8PostReview({
+    required String aid,
+    required String title,
+    required String content,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(aid, serializer);
+          sse_encode_String(title, serializer);
+          sse_encode_String(content, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 71,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWenku8PostReviewConstMeta,
+        argValues: [aid, title, content],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWenku8PostReviewConstMeta => const TaskConstMeta(
+    debugName: "post_review",
+    argNames: ["aid", "title", "content"],
+  );
+
+  @override
+  Future<bool> crateApiWenku8PreLoginState() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 72,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWenku8PreLoginStateConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWenku8PreLoginStateConstMeta =>
+      const TaskConstMeta(debugName: "pre_login_state", argNames: []);
+
+  @override
+  Future<List<MirrorProbe>> crateApiWenku8ProbeMirrors() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 73,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_mirror_probe,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWenku8ProbeMirrorsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWenku8ProbeMirrorsConstMeta =>
+      const TaskConstMeta(debugName: "probe_mirrors", argNames: []);
+
+  @override
+  Future<void> crateApiWenku8Recommend({required String aid}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(aid, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 74,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWenku8RecommendConstMeta,
+        argValues: [aid],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWenku8RecommendConstMeta =>
+      const TaskConstMeta(debugName: "recommend", argNames: ["aid"]);
+
+  @override
+  Future<List<RecommendLog>> crateApiWenku8RecommendLogList({
+    required int offset,
+    required int limit,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_32(offset, serializer);
+          sse_encode_i_32(limit, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 75,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_recommend_log,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWenku8RecommendLogListConstMeta,
+        argValues: [offset, limit],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWenku8RecommendLogListConstMeta =>
+      const TaskConstMeta(
+        debugName: "recommend_log_list",
+        argNames: ["offset", "limit"],
+      );
+
+  @override
+  Future<PlatformInt64> crateApiWenku8RecommendRemaining() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 76,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_i_64,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWenku8RecommendRemainingConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWenku8RecommendRemainingConstMeta =>
+      const TaskConstMeta(debugName: "recommend_remaining", argNames: []);
+
+  @override
+  Future<void> crateApiWenku8RemoveAccount({required PlatformInt64 accountId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(accountId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 77,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWenku8RemoveAccountConstMeta,
+        argValues: [accountId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWenku8RemoveAccountConstMeta =>
+      const TaskConstMeta(debugName: "remove_account", argNames: ["accountId"]);
+
+  @override
+  Future<void> crateApiWenku8ReorderDownloads({
+    required List<String> novelIds,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_String(novelIds, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 78,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWenku8ReorderDownloadsConstMeta,
+        argValues: [novelIds],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWenku8ReorderDownloadsConstMeta =>
+      const TaskConstMeta(
+        debugName: "reorder_downloads",
+        argNames: ["novelIds"],
+      );
+
+  @override
+  Future<void> crateApiWenku8ReplyReview({
+    required String aid,
+    required String rid,
+    required String content,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(aid, serializer);
+          sse_encode_String(rid, serializer);
+          sse_encode_String(content, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 79,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWenku8ReplyReviewConstMeta,
+        argValues: [aid, rid, content],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWenku8ReplyReviewConstMeta => const TaskConstMeta(
+    debugName: "reply_review",
+    argNames: ["aid", "rid", "content"],
+  );
+
+  @override
+  Future<void> crateApiWenku8ResetFailDownloads() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 80,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWenku8ResetFailDownloadsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWenku8ResetFailDownloadsConstMeta =>
+      const TaskConstMeta(debugName: "reset_fail_downloads", argNames: []);
+
+  @override
+  Future<void> crateApiWenku8ResumeAllDownloads() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -267,204 +2853,226 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 2,
+            funcId: 81,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_novel_download,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiWenku8AllDownloadsConstMeta,
+        constMeta: kCrateApiWenku8ResumeAllDownloadsConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWenku8AllDownloadsConstMeta =>
-      const TaskConstMeta(debugName: "all_downloads", argNames: []);
+  TaskConstMeta get kCrateApiWenku8ResumeAllDownloadsConstMeta =>
+      const TaskConstMeta(debugName: "resume_all_downloads", argNames: []);
 
   @override
-  Future<PageStatsNovelCover> crateApiWenku8Articlelist({
-    required int fullflag,
-    required int page,
-  }) {
+  Future<void> crateApiWenku8ResumeDownload({required String novelId}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_i_32(fullflag, serializer);
-          sse_encode_i_32(page, serializer);
+          sse_encode_String(novelId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 3,
+            funcId: 82,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_page_stats_novel_cover,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiWenku8ArticlelistConstMeta,
-        argValues: [fullflag, page],
+        constMeta: kCrateApiWenku8ResumeDownloadConstMeta,
+        argValues: [novelId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWenku8ArticlelistConstMeta => const TaskConstMeta(
-    debugName: "articlelist",
-    argNames: ["fullflag", "page"],
-  );
+  TaskConstMeta get kCrateApiWenku8ResumeDownloadConstMeta =>
+      const TaskConstMeta(debugName: "resume_download", argNames: ["novelId"]);
 
   @override
-  Future<bool> crateApiWenku8AutoSign() {
+  Future<ReviewDetail> crateApiWenku8ReviewDetail({
+    required String rid,
+    required int page,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(rid, serializer);
+          sse_encode_i_32(page, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 4,
+            funcId: 83,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
+          decodeSuccessData: sse_decode_review_detail,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiWenku8AutoSignConstMeta,
-        argValues: [],
+        constMeta: kCrateApiWenku8ReviewDetailConstMeta,
+        argValues: [rid, page],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWenku8AutoSignConstMeta =>
-      const TaskConstMeta(debugName: "auto_sign", argNames: []);
+  TaskConstMeta get kCrateApiWenku8ReviewDetailConstMeta => const TaskConstMeta(
+    debugName: "review_detail",
+    argNames: ["rid", "page"],
+  );
 
   @override
-  Future<BookcaseDto> crateApiWenku8BookInCase({required String caseId}) {
+  Future<PageStatsReviews> crateApiWenku8Reviews({
+    required String aid,
+    required int pageNumber,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(caseId, serializer);
+          sse_encode_String(aid, serializer);
+          sse_encode_i_32(pageNumber, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 5,
+            funcId: 84,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bookcase_dto,
+          decodeSuccessData: sse_decode_page_stats_reviews,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiWenku8BookInCaseConstMeta,
-        argValues: [caseId],
+        constMeta: kCrateApiWenku8ReviewsConstMeta,
+        argValues: [aid, pageNumber],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWenku8BookInCaseConstMeta =>
-      const TaskConstMeta(debugName: "book_in_case", argNames: ["caseId"]);
+  TaskConstMeta get kCrateApiWenku8ReviewsConstMeta => const TaskConstMeta(
+    debugName: "reviews",
+    argNames: ["aid", "pageNumber"],
+  );
 
   @override
-  Future<List<Bookcase>> crateApiWenku8BookcaseList() {
+  Future<void> crateApiDatabaseSaveProperty({
+    required String key,
+    required String value,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(key, serializer);
+          sse_encode_String(value, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 6,
+            funcId: 85,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_bookcase,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiWenku8BookcaseListConstMeta,
-        argValues: [],
+        constMeta: kCrateApiDatabaseSavePropertyConstMeta,
+        argValues: [key, value],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWenku8BookcaseListConstMeta =>
-      const TaskConstMeta(debugName: "bookcase_list", argNames: []);
+  TaskConstMeta get kCrateApiDatabaseSavePropertyConstMeta =>
+      const TaskConstMeta(
+        debugName: "save_property",
+        argNames: ["key", "value"],
+      );
 
   @override
-  Future<String> crateApiWenku8ChapterContent({
-    required String aid,
-    required String cid,
+  Future<void> crateApiWenku8SaveProxySettings({
+    required ProxySettings settings,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(aid, serializer);
-          sse_encode_String(cid, serializer);
+          sse_encode_box_autoadd_proxy_settings(settings, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 7,
+            funcId: 86,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiWenku8ChapterContentConstMeta,
-        argValues: [aid, cid],
+        constMeta: kCrateApiWenku8SaveProxySettingsConstMeta,
+        argValues: [settings],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWenku8ChapterContentConstMeta =>
+  TaskConstMeta get kCrateApiWenku8SaveProxySettingsConstMeta =>
       const TaskConstMeta(
-        debugName: "chapter_content",
-        argNames: ["aid", "cid"],
+        debugName: "save_proxy_settings",
+        argNames: ["settings"],
       );
 
   @override
-  Future<void> crateApiWenku8CleanAllWebCache() {
+  Future<PageStatsNovelCover> crateApiWenku8Search({
+    required String searchType,
+    required String searchKey,
+    required int page,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(searchType, serializer);
+          sse_encode_String(searchKey, serializer);
+          sse_encode_i_32(page, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 8,
+            funcId: 87,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_page_stats_novel_cover,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiWenku8CleanAllWebCacheConstMeta,
-        argValues: [],
+        constMeta: kCrateApiWenku8SearchConstMeta,
+        argValues: [searchType, searchKey, page],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWenku8CleanAllWebCacheConstMeta =>
-      const TaskConstMeta(debugName: "clean_all_web_cache", argNames: []);
+  TaskConstMeta get kCrateApiWenku8SearchConstMeta => const TaskConstMeta(
+    debugName: "search",
+    argNames: ["searchType", "searchKey", "page"],
+  );
 
   @override
-  Future<void> crateApiWenku8DeleteAllHistory() {
+  Future<List<SearchHistory>> crateApiWenku8SearchHistories() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -472,63 +3080,68 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 9,
+            funcId: 88,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_list_search_history,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiWenku8DeleteAllHistoryConstMeta,
+        constMeta: kCrateApiWenku8SearchHistoriesConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWenku8DeleteAllHistoryConstMeta =>
-      const TaskConstMeta(debugName: "delete_all_history", argNames: []);
+  TaskConstMeta get kCrateApiWenku8SearchHistoriesConstMeta =>
+      const TaskConstMeta(debugName: "search_histories", argNames: []);
 
   @override
-  Future<void> crateApiWenku8DeleteBookcase({required String bid}) {
+  Future<String?> crateApiWenku8SelectFastestMirror() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(bid, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 10,
+            funcId: 89,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_opt_String,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiWenku8DeleteBookcaseConstMeta,
-        argValues: [bid],
+        constMeta: kCrateApiWenku8SelectFastestMirrorConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWenku8DeleteBookcaseConstMeta =>
-      const TaskConstMeta(debugName: "delete_bookcase", argNames: ["bid"]);
+  TaskConstMeta get kCrateApiWenku8SelectFastestMirrorConstMeta =>
+      const TaskConstMeta(debugName: "select_fastest_mirror", argNames: []);
 
   @override
-  Future<void> crateApiWenku8DeleteDownload({required String novelId}) {
+  Future<void> crateApiWenku8SendMessage({
+    required String receiver,
+    required String title,
+    required String content,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(novelId, serializer);
+          sse_encode_String(receiver, serializer);
+          sse_encode_String(title, serializer);
+          sse_encode_String(content, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 11,
+            funcId: 90,
             port: port_,
           );
         },
@@ -536,27 +3149,29 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiWenku8DeleteDownloadConstMeta,
-        argValues: [novelId],
+        constMeta: kCrateApiWenku8SendMessageConstMeta,
+        argValues: [receiver, title, content],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWenku8DeleteDownloadConstMeta =>
-      const TaskConstMeta(debugName: "delete_download", argNames: ["novelId"]);
+  TaskConstMeta get kCrateApiWenku8SendMessageConstMeta => const TaskConstMeta(
+    debugName: "send_message",
+    argNames: ["receiver", "title", "content"],
+  );
 
   @override
-  Future<void> crateApiWenku8DeleteHistoryByNovelId({required String novelId}) {
+  Future<void> crateApiWenku8SetAccountScopedHistory({required bool scoped}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(novelId, serializer);
+          sse_encode_bool(scoped, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 12,
+            funcId: 91,
             port: port_,
           );
         },
@@ -564,116 +3179,126 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiWenku8DeleteHistoryByNovelIdConstMeta,
-        argValues: [novelId],
+        constMeta: kCrateApiWenku8SetAccountScopedHistoryConstMeta,
+        argValues: [scoped],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWenku8DeleteHistoryByNovelIdConstMeta =>
+  TaskConstMeta get kCrateApiWenku8SetAccountScopedHistoryConstMeta =>
       const TaskConstMeta(
-        debugName: "delete_history_by_novel_id",
-        argNames: ["novelId"],
+        debugName: "set_account_scoped_history",
+        argNames: ["scoped"],
       );
 
   @override
-  Future<String> crateApiSystemDesktopRoot() {
+  Future<void> crateApiWenku8SetApiHost({required String apiHost}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(apiHost, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 13,
+            funcId: 92,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiSystemDesktopRootConstMeta,
-        argValues: [],
+        constMeta: kCrateApiWenku8SetApiHostConstMeta,
+        argValues: [apiHost],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSystemDesktopRootConstMeta =>
-      const TaskConstMeta(debugName: "desktop_root", argNames: []);
+  TaskConstMeta get kCrateApiWenku8SetApiHostConstMeta =>
+      const TaskConstMeta(debugName: "set_api_host", argNames: ["apiHost"]);
 
   @override
-  Future<Uint8List> crateApiWenku8DownloadCheckcode() {
+  Future<void> crateApiWenku8SetAutoDownloadUpdates({required bool enabled}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_bool(enabled, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 14,
+            funcId: 93,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiWenku8DownloadCheckcodeConstMeta,
-        argValues: [],
+        constMeta: kCrateApiWenku8SetAutoDownloadUpdatesConstMeta,
+        argValues: [enabled],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWenku8DownloadCheckcodeConstMeta =>
-      const TaskConstMeta(debugName: "download_checkcode", argNames: []);
+  TaskConstMeta get kCrateApiWenku8SetAutoDownloadUpdatesConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_auto_download_updates",
+        argNames: ["enabled"],
+      );
 
   @override
-  Future<String> crateApiWenku8DownloadImage({required String url}) {
+  Future<void> crateApiWenku8SetCfClearance({
+    required String url,
+    required String cfClearance,
+    required String userAgent,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(url, serializer);
+          sse_encode_String(cfClearance, serializer);
+          sse_encode_String(userAgent, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 15,
+            funcId: 94,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiWenku8DownloadImageConstMeta,
-        argValues: [url],
+        constMeta: kCrateApiWenku8SetCfClearanceConstMeta,
+        argValues: [url, cfClearance, userAgent],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWenku8DownloadImageConstMeta =>
-      const TaskConstMeta(debugName: "download_image", argNames: ["url"]);
+  TaskConstMeta get kCrateApiWenku8SetCfClearanceConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_cf_clearance",
+        argNames: ["url", "cfClearance", "userAgent"],
+      );
 
   @override
-  Future<void> crateApiWenku8DownloadNovel({
-    required String aid,
-    required List<String> cidList,
-  }) {
+  Future<void> crateApiWenku8SetDisplayLanguage({required String language}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(aid, serializer);
-          sse_encode_list_String(cidList, serializer);
+          sse_encode_String(language, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 16,
+            funcId: 95,
             port: port_,
           );
         },
@@ -681,137 +3306,150 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiWenku8DownloadNovelConstMeta,
-        argValues: [aid, cidList],
+        constMeta: kCrateApiWenku8SetDisplayLanguageConstMeta,
+        argValues: [language],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWenku8DownloadNovelConstMeta =>
+  TaskConstMeta get kCrateApiWenku8SetDisplayLanguageConstMeta =>
       const TaskConstMeta(
-        debugName: "download_novel",
-        argNames: ["aid", "cidList"],
+        debugName: "set_display_language",
+        argNames: ["language"],
       );
 
   @override
-  Future<ExistsDownload?> crateApiWenku8ExistsDownload({
-    required String novelId,
+  Future<void> crateApiWenku8SetDownloadConcurrency({
+    required int concurrency,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(novelId, serializer);
+          sse_encode_i_32(concurrency, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 17,
+            funcId: 96,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_opt_box_autoadd_exists_download,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiWenku8ExistsDownloadConstMeta,
-        argValues: [novelId],
+        constMeta: kCrateApiWenku8SetDownloadConcurrencyConstMeta,
+        argValues: [concurrency],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWenku8ExistsDownloadConstMeta =>
-      const TaskConstMeta(debugName: "exists_download", argNames: ["novelId"]);
+  TaskConstMeta get kCrateApiWenku8SetDownloadConcurrencyConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_download_concurrency",
+        argNames: ["concurrency"],
+      );
 
   @override
-  Future<String> crateApiWenku8GetApiHost() {
+  Future<void> crateApiWenku8SetImageRateLimit({required double rate}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_f_64(rate, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 18,
+            funcId: 97,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiWenku8GetApiHostConstMeta,
-        argValues: [],
+        constMeta: kCrateApiWenku8SetImageRateLimitConstMeta,
+        argValues: [rate],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWenku8GetApiHostConstMeta =>
-      const TaskConstMeta(debugName: "get_api_host", argNames: []);
+  TaskConstMeta get kCrateApiWenku8SetImageRateLimitConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_image_rate_limit",
+        argNames: ["rate"],
+      );
 
   @override
-  String crateApiSimpleGreet({required String name}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
+  Future<void> crateApiWenku8SetLanguage({required String language}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(name, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19)!;
+          sse_encode_String(language, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 98,
+            port: port_,
+          );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiSimpleGreetConstMeta,
-        argValues: [name],
+        constMeta: kCrateApiWenku8SetLanguageConstMeta,
+        argValues: [language],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleGreetConstMeta =>
-      const TaskConstMeta(debugName: "greet", argNames: ["name"]);
+  TaskConstMeta get kCrateApiWenku8SetLanguageConstMeta =>
+      const TaskConstMeta(debugName: "set_language", argNames: ["language"]);
 
   @override
-  Future<List<HomeBlock>> crateApiWenku8Index() {
+  Future<void> crateApiWenku8SetMirrorHosts({required List<String> hosts}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_String(hosts, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 20,
+            funcId: 99,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_home_block,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiWenku8IndexConstMeta,
-        argValues: [],
+        constMeta: kCrateApiWenku8SetMirrorHostsConstMeta,
+        argValues: [hosts],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWenku8IndexConstMeta =>
-      const TaskConstMeta(debugName: "index", argNames: []);
+  TaskConstMeta get kCrateApiWenku8SetMirrorHostsConstMeta =>
+      const TaskConstMeta(debugName: "set_mirror_hosts", argNames: ["hosts"]);
 
   @override
-  Future<void> crateApiSystemInit({required String root}) {
+  Future<void> crateApiWenku8SetTextRateLimit({required double rate}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(root, serializer);
+          sse_encode_f_64(rate, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 21,
+            funcId: 100,
             port: port_,
           );
         },
@@ -819,89 +3457,99 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiSystemInitConstMeta,
-        argValues: [root],
+        constMeta: kCrateApiWenku8SetTextRateLimitConstMeta,
+        argValues: [rate],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSystemInitConstMeta =>
-      const TaskConstMeta(debugName: "init", argNames: ["root"]);
+  TaskConstMeta get kCrateApiWenku8SetTextRateLimitConstMeta =>
+      const TaskConstMeta(debugName: "set_text_rate_limit", argNames: ["rate"]);
 
   @override
-  Future<void> crateApiSimpleInitApp() {
+  Future<void> crateApiWenku8SetUpdateCheckInterval({
+    required PlatformInt64 hours,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(hours, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 22,
+            funcId: 101,
             port: port_,
           );
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiSimpleInitAppConstMeta,
-        argValues: [],
+        constMeta: kCrateApiWenku8SetUpdateCheckIntervalConstMeta,
+        argValues: [hours],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleInitAppConstMeta =>
-      const TaskConstMeta(debugName: "init_app", argNames: []);
+  TaskConstMeta get kCrateApiWenku8SetUpdateCheckIntervalConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_update_check_interval",
+        argNames: ["hours"],
+      );
 
   @override
-  Future<List<ReadingHistory>> crateApiWenku8ListReadingHistory({
-    required int offset,
-    required int limit,
+  Future<List<BookshelfItem>> crateApiSourceSourceBookshelf({
+    required String sourceId,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_i_32(offset, serializer);
-          sse_encode_i_32(limit, serializer);
+          sse_encode_String(sourceId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 23,
+            funcId: 102,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_reading_history,
+          decodeSuccessData: sse_decode_list_bookshelf_item,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiWenku8ListReadingHistoryConstMeta,
-        argValues: [offset, limit],
+        constMeta: kCrateApiSourceSourceBookshelfConstMeta,
+        argValues: [sourceId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWenku8ListReadingHistoryConstMeta =>
+  TaskConstMeta get kCrateApiSourceSourceBookshelfConstMeta =>
       const TaskConstMeta(
-        debugName: "list_reading_history",
-        argNames: ["offset", "limit"],
+        debugName: "source_bookshelf",
+        argNames: ["sourceId"],
       );
 
   @override
-  Future<String> crateApiDatabaseLoadProperty({required String key}) {
+  Future<String> crateApiSourceSourceChapterContent({
+    required String sourceId,
+    required String aid,
+    required String cid,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(key, serializer);
+          sse_encode_String(sourceId, serializer);
+          sse_encode_String(aid, serializer);
+          sse_encode_String(cid, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 24,
+            funcId: 103,
             port: port_,
           );
         },
@@ -909,235 +3557,279 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiDatabaseLoadPropertyConstMeta,
-        argValues: [key],
+        constMeta: kCrateApiSourceSourceChapterContentConstMeta,
+        argValues: [sourceId, aid, cid],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiDatabaseLoadPropertyConstMeta =>
-      const TaskConstMeta(debugName: "load_property", argNames: ["key"]);
+  TaskConstMeta get kCrateApiSourceSourceChapterContentConstMeta =>
+      const TaskConstMeta(
+        debugName: "source_chapter_content",
+        argNames: ["sourceId", "aid", "cid"],
+      );
 
   @override
-  Future<void> crateApiWenku8Logout() {
+  Future<ChapterDocument> crateApiSourceSourceChapterDocument({
+    required String sourceId,
+    required String aid,
+    required String cid,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(sourceId, serializer);
+          sse_encode_String(aid, serializer);
+          sse_encode_String(cid, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 25,
+            funcId: 104,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_chapter_document,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiWenku8LogoutConstMeta,
-        argValues: [],
+        constMeta: kCrateApiSourceSourceChapterDocumentConstMeta,
+        argValues: [sourceId, aid, cid],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWenku8LogoutConstMeta =>
-      const TaskConstMeta(debugName: "logout", argNames: []);
+  TaskConstMeta get kCrateApiSourceSourceChapterDocumentConstMeta =>
+      const TaskConstMeta(
+        debugName: "source_chapter_document",
+        argNames: ["sourceId", "aid", "cid"],
+      );
 
   @override
-  Future<void> crateApiWenku8MoveBookcase({
-    required List<String> bidList,
-    required String fromBookcaseId,
-    required String toBookcaseId,
+  Future<String> crateApiSourceSourceDownloadImage({
+    required String sourceId,
+    required String url,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_String(bidList, serializer);
-          sse_encode_String(fromBookcaseId, serializer);
-          sse_encode_String(toBookcaseId, serializer);
+          sse_encode_String(sourceId, serializer);
+          sse_encode_String(url, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 26,
+            funcId: 105,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiWenku8MoveBookcaseConstMeta,
-        argValues: [bidList, fromBookcaseId, toBookcaseId],
+        constMeta: kCrateApiSourceSourceDownloadImageConstMeta,
+        argValues: [sourceId, url],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWenku8MoveBookcaseConstMeta => const TaskConstMeta(
-    debugName: "move_bookcase",
-    argNames: ["bidList", "fromBookcaseId", "toBookcaseId"],
-  );
+  TaskConstMeta get kCrateApiSourceSourceDownloadImageConstMeta =>
+      const TaskConstMeta(
+        debugName: "source_download_image",
+        argNames: ["sourceId", "url"],
+      );
 
   @override
-  Future<ReadingHistory?> crateApiWenku8NovelHistoryById({
-    required String novelId,
+  Future<void> crateApiSourceSourceDownloadNovel({
+    required String sourceId,
+    required String aid,
+    required List<String> cidList,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(novelId, serializer);
+          sse_encode_String(sourceId, serializer);
+          sse_encode_String(aid, serializer);
+          sse_encode_list_String(cidList, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 27,
+            funcId: 106,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_opt_box_autoadd_reading_history,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiWenku8NovelHistoryByIdConstMeta,
-        argValues: [novelId],
+        constMeta: kCrateApiSourceSourceDownloadNovelConstMeta,
+        argValues: [sourceId, aid, cidList],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWenku8NovelHistoryByIdConstMeta =>
+  TaskConstMeta get kCrateApiSourceSourceDownloadNovelConstMeta =>
       const TaskConstMeta(
-        debugName: "novel_history_by_id",
-        argNames: ["novelId"],
+        debugName: "source_download_novel",
+        argNames: ["sourceId", "aid", "cidList"],
       );
 
   @override
-  Future<NovelInfo> crateApiWenku8NovelInfo({required String aid}) {
+  Future<List<HomeBlock>> crateApiSourceSourceIndex({
+    required String sourceId,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(aid, serializer);
+          sse_encode_String(sourceId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 28,
+            funcId: 107,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_novel_info,
+          decodeSuccessData: sse_decode_list_home_block,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiWenku8NovelInfoConstMeta,
-        argValues: [aid],
+        constMeta: kCrateApiSourceSourceIndexConstMeta,
+        argValues: [sourceId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWenku8NovelInfoConstMeta =>
-      const TaskConstMeta(debugName: "novel_info", argNames: ["aid"]);
+  TaskConstMeta get kCrateApiSourceSourceIndexConstMeta =>
+      const TaskConstMeta(debugName: "source_index", argNames: ["sourceId"]);
 
   @override
-  Future<List<Volume>> crateApiWenku8NovelReader({required String aid}) {
+  Future<NovelInfo> crateApiSourceSourceNovelInfo({
+    required String sourceId,
+    required String aid,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(sourceId, serializer);
           sse_encode_String(aid, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 29,
+            funcId: 108,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_volume,
+          decodeSuccessData: sse_decode_novel_info,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiWenku8NovelReaderConstMeta,
-        argValues: [aid],
+        constMeta: kCrateApiSourceSourceNovelInfoConstMeta,
+        argValues: [sourceId, aid],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWenku8NovelReaderConstMeta =>
-      const TaskConstMeta(debugName: "novel_reader", argNames: ["aid"]);
+  TaskConstMeta get kCrateApiSourceSourceNovelInfoConstMeta =>
+      const TaskConstMeta(
+        debugName: "source_novel_info",
+        argNames: ["sourceId", "aid"],
+      );
 
   @override
-  Future<PageStatsNovelCover> crateApiWenku8PageStatsNovelCoverDefault() {
+  Future<List<Volume>> crateApiSourceSourceNovelReader({
+    required String sourceId,
+    required String aid,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(sourceId, serializer);
+          sse_encode_String(aid, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 30,
+            funcId: 109,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_page_stats_novel_cover,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_list_volume,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiWenku8PageStatsNovelCoverDefaultConstMeta,
-        argValues: [],
+        constMeta: kCrateApiSourceSourceNovelReaderConstMeta,
+        argValues: [sourceId, aid],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWenku8PageStatsNovelCoverDefaultConstMeta =>
+  TaskConstMeta get kCrateApiSourceSourceNovelReaderConstMeta =>
       const TaskConstMeta(
-        debugName: "page_stats_novel_cover_default",
-        argNames: [],
+        debugName: "source_novel_reader",
+        argNames: ["sourceId", "aid"],
       );
 
   @override
-  Future<bool> crateApiWenku8PreLoginState() {
+  Future<PageStatsNovelCover> crateApiSourceSourceSearch({
+    required String sourceId,
+    required String searchType,
+    required String searchKey,
+    required int page,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(sourceId, serializer);
+          sse_encode_String(searchType, serializer);
+          sse_encode_String(searchKey, serializer);
+          sse_encode_i_32(page, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 31,
+            funcId: 110,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
+          decodeSuccessData: sse_decode_page_stats_novel_cover,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiWenku8PreLoginStateConstMeta,
-        argValues: [],
+        constMeta: kCrateApiSourceSourceSearchConstMeta,
+        argValues: [sourceId, searchType, searchKey, page],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWenku8PreLoginStateConstMeta =>
-      const TaskConstMeta(debugName: "pre_login_state", argNames: []);
+  TaskConstMeta get kCrateApiSourceSourceSearchConstMeta => const TaskConstMeta(
+    debugName: "source_search",
+    argNames: ["sourceId", "searchType", "searchKey", "page"],
+  );
 
   @override
-  Future<void> crateApiWenku8ResetFailDownloads() {
+  Future<void> crateApiSourceSourceUpdateHistory({
+    required ReadingHistory history,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_reading_history(history, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 32,
+            funcId: 111,
             port: port_,
           );
         },
@@ -1145,214 +3837,202 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiWenku8ResetFailDownloadsConstMeta,
-        argValues: [],
+        constMeta: kCrateApiSourceSourceUpdateHistoryConstMeta,
+        argValues: [history],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWenku8ResetFailDownloadsConstMeta =>
-      const TaskConstMeta(debugName: "reset_fail_downloads", argNames: []);
+  TaskConstMeta get kCrateApiSourceSourceUpdateHistoryConstMeta =>
+      const TaskConstMeta(
+        debugName: "source_update_history",
+        argNames: ["history"],
+      );
 
   @override
-  Future<PageStatsReviews> crateApiWenku8Reviews({
-    required String aid,
-    required int pageNumber,
-  }) {
+  Future<void> crateApiWenku8SwitchAccount({required PlatformInt64 accountId}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(aid, serializer);
-          sse_encode_i_32(pageNumber, serializer);
+          sse_encode_i_64(accountId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 33,
+            funcId: 112,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_page_stats_reviews,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiWenku8ReviewsConstMeta,
-        argValues: [aid, pageNumber],
+        constMeta: kCrateApiWenku8SwitchAccountConstMeta,
+        argValues: [accountId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWenku8ReviewsConstMeta => const TaskConstMeta(
-    debugName: "reviews",
-    argNames: ["aid", "pageNumber"],
-  );
+  TaskConstMeta get kCrateApiWenku8SwitchAccountConstMeta =>
+      const TaskConstMeta(debugName: "switch_account", argNames: ["accountId"]);
 
   @override
-  Future<void> crateApiDatabaseSaveProperty({
-    required String key,
-    required String value,
+  Future<PageStatsNovelCover> crateApiWenku8TagPage({
+    required String tag,
+    required String v,
+    required int pageNumber,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(key, serializer);
-          sse_encode_String(value, serializer);
+          sse_encode_String(tag, serializer);
+          sse_encode_String(v, serializer);
+          sse_encode_i_32(pageNumber, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 34,
+            funcId: 113,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_page_stats_novel_cover,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiDatabaseSavePropertyConstMeta,
-        argValues: [key, value],
+        constMeta: kCrateApiWenku8TagPageConstMeta,
+        argValues: [tag, v, pageNumber],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiDatabaseSavePropertyConstMeta =>
-      const TaskConstMeta(
-        debugName: "save_property",
-        argNames: ["key", "value"],
-      );
+  TaskConstMeta get kCrateApiWenku8TagPageConstMeta => const TaskConstMeta(
+    debugName: "tag_page",
+    argNames: ["tag", "v", "pageNumber"],
+  );
 
   @override
-  Future<PageStatsNovelCover> crateApiWenku8Search({
-    required String searchType,
-    required String searchKey,
-    required int page,
-  }) {
+  Future<List<TagGroup>> crateApiWenku8Tags() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(searchType, serializer);
-          sse_encode_String(searchKey, serializer);
-          sse_encode_i_32(page, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 35,
+            funcId: 114,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_page_stats_novel_cover,
+          decodeSuccessData: sse_decode_list_tag_group,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiWenku8SearchConstMeta,
-        argValues: [searchType, searchKey, page],
+        constMeta: kCrateApiWenku8TagsConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWenku8SearchConstMeta => const TaskConstMeta(
-    debugName: "search",
-    argNames: ["searchType", "searchKey", "page"],
-  );
+  TaskConstMeta get kCrateApiWenku8TagsConstMeta =>
+      const TaskConstMeta(debugName: "tags", argNames: []);
 
   @override
-  Future<List<SearchHistory>> crateApiWenku8SearchHistories() {
+  Future<ProxyTestResult> crateApiWenku8TestProxy({
+    required ProxySettings settings,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_proxy_settings(settings, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 36,
+            funcId: 115,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_search_history,
+          decodeSuccessData: sse_decode_proxy_test_result,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiWenku8SearchHistoriesConstMeta,
-        argValues: [],
+        constMeta: kCrateApiWenku8TestProxyConstMeta,
+        argValues: [settings],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWenku8SearchHistoriesConstMeta =>
-      const TaskConstMeta(debugName: "search_histories", argNames: []);
+  TaskConstMeta get kCrateApiWenku8TestProxyConstMeta =>
+      const TaskConstMeta(debugName: "test_proxy", argNames: ["settings"]);
 
   @override
-  Future<void> crateApiWenku8SetApiHost({required String apiHost}) {
+  Future<PageStatsNovelCover> crateApiWenku8Toplist({
+    required String sort,
+    required int page,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(apiHost, serializer);
+          sse_encode_String(sort, serializer);
+          sse_encode_i_32(page, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 37,
+            funcId: 116,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_page_stats_novel_cover,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiWenku8SetApiHostConstMeta,
-        argValues: [apiHost],
+        constMeta: kCrateApiWenku8ToplistConstMeta,
+        argValues: [sort, page],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWenku8SetApiHostConstMeta =>
-      const TaskConstMeta(debugName: "set_api_host", argNames: ["apiHost"]);
+  TaskConstMeta get kCrateApiWenku8ToplistConstMeta =>
+      const TaskConstMeta(debugName: "toplist", argNames: ["sort", "page"]);
 
   @override
-  Future<PageStatsNovelCover> crateApiWenku8TagPage({
-    required String tag,
-    required String v,
-    required int pageNumber,
-  }) {
+  Future<void> crateApiWenku8UnlockVault({required String secret}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(tag, serializer);
-          sse_encode_String(v, serializer);
-          sse_encode_i_32(pageNumber, serializer);
+          sse_encode_String(secret, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 38,
+            funcId: 117,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_page_stats_novel_cover,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiWenku8TagPageConstMeta,
-        argValues: [tag, v, pageNumber],
+        constMeta: kCrateApiWenku8UnlockVaultConstMeta,
+        argValues: [secret],
         apiImpl: this,
       ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiWenku8TagPageConstMeta => const TaskConstMeta(
-    debugName: "tag_page",
-    argNames: ["tag", "v", "pageNumber"],
-  );
+    );
+  }
+
+  TaskConstMeta get kCrateApiWenku8UnlockVaultConstMeta =>
+      const TaskConstMeta(debugName: "unlock_vault", argNames: ["secret"]);
 
   @override
-  Future<List<TagGroup>> crateApiWenku8Tags() {
+  Future<void> crateApiWenku8UnpinUserAgent() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -1360,55 +4040,58 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 39,
+            funcId: 118,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_tag_group,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiWenku8TagsConstMeta,
+        constMeta: kCrateApiWenku8UnpinUserAgentConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWenku8TagsConstMeta =>
-      const TaskConstMeta(debugName: "tags", argNames: []);
+  TaskConstMeta get kCrateApiWenku8UnpinUserAgentConstMeta =>
+      const TaskConstMeta(debugName: "unpin_user_agent", argNames: []);
 
   @override
-  Future<PageStatsNovelCover> crateApiWenku8Toplist({
-    required String sort,
-    required int page,
+  Future<List<NovelUpdate>> crateApiWenku8UnseenNovelUpdates({
+    required int offset,
+    required int limit,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(sort, serializer);
-          sse_encode_i_32(page, serializer);
+          sse_encode_i_32(offset, serializer);
+          sse_encode_i_32(limit, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 40,
+            funcId: 119,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_page_stats_novel_cover,
+          decodeSuccessData: sse_decode_list_novel_update,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiWenku8ToplistConstMeta,
-        argValues: [sort, page],
+        constMeta: kCrateApiWenku8UnseenNovelUpdatesConstMeta,
+        argValues: [offset, limit],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWenku8ToplistConstMeta =>
-      const TaskConstMeta(debugName: "toplist", argNames: ["sort", "page"]);
+  TaskConstMeta get kCrateApiWenku8UnseenNovelUpdatesConstMeta =>
+      const TaskConstMeta(
+        debugName: "unseen_novel_updates",
+        argNames: ["offset", "limit"],
+      );
 
   @override
   Future<void> crateApiWenku8UpdateHistory({
@@ -1440,7 +4123,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 41,
+            funcId: 120,
             port: port_,
           );
         },
@@ -1492,7 +4175,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 42,
+            funcId: 121,
             port: port_,
           );
         },
@@ -1510,6 +4193,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   TaskConstMeta get kCrateApiWenku8UserDetailConstMeta =>
       const TaskConstMeta(debugName: "user_detail", argNames: []);
 
+  @override
+  Future<VaultState> crateApiWenku8VaultState() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 122,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_vault_state,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWenku8VaultStateConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWenku8VaultStateConstMeta =>
+      const TaskConstMeta(debugName: "vault_state", argNames: []);
+
   @override
   Future<List<BookshelfItem>> crateApiWenku8Wenku8GetBookshelf() {
     return handler.executeNormal(
@@ -1519,7 +4229,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 43,
+            funcId: 123,
             port: port_,
           );
         },
@@ -1553,7 +4263,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 44,
+            funcId: 124,
             port: port_,
           );
         },
@@ -1579,12 +4289,40 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return AnyhowException(raw as String);
   }
 
+  @protected
+  RustStreamSink<String> dco_decode_StreamSink_String_Sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<DownloadEvent> dco_decode_StreamSink_download_event_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
   @protected
   String dco_decode_String(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return raw as String;
   }
 
+  @protected
+  Account dco_decode_account(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return Account(
+      id: dco_decode_i_64(arr[0]),
+      username: dco_decode_String(arr[1]),
+      createTime: dco_decode_i_64(arr[2]),
+      lastLoginTime: dco_decode_i_64(arr[3]),
+    );
+  }
+
   @protected
   Bookcase dco_decode_bookcase(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1643,18 +4381,42 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw as bool;
   }
 
+  @protected
+  Account dco_decode_box_autoadd_account(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_account(raw);
+  }
+
   @protected
   ExistsDownload dco_decode_box_autoadd_exists_download(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return dco_decode_exists_download(raw);
   }
 
+  @protected
+  int dco_decode_box_autoadd_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  ProxySettings dco_decode_box_autoadd_proxy_settings(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_proxy_settings(raw);
+  }
+
   @protected
   ReadingHistory dco_decode_box_autoadd_reading_history(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return dco_decode_reading_history(raw);
   }
 
+  @protected
+  Wenku8ErrorInfo dco_decode_box_autoadd_wenku_8_error_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_wenku_8_error_info(raw);
+  }
+
   @protected
   Chapter dco_decode_chapter(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1669,6 +4431,72 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  ChapterDocument dco_decode_chapter_document(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ChapterDocument(
+      aid: dco_decode_String(arr[0]),
+      cid: dco_decode_String(arr[1]),
+      segments: dco_decode_list_chapter_segment(arr[2]),
+    );
+  }
+
+  @protected
+  ChapterSegment dco_decode_chapter_segment(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return ChapterSegment(
+      kind: dco_decode_chapter_segment_kind(arr[0]),
+      text: dco_decode_String(arr[1]),
+      url: dco_decode_String(arr[2]),
+      localPath: dco_decode_opt_String(arr[3]),
+      width: dco_decode_opt_box_autoadd_i_32(arr[4]),
+      height: dco_decode_opt_box_autoadd_i_32(arr[5]),
+    );
+  }
+
+  @protected
+  ChapterSegmentKind dco_decode_chapter_segment_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ChapterSegmentKind.values[raw as int];
+  }
+
+  @protected
+  CookieFileFormat dco_decode_cookie_file_format(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return CookieFileFormat.values[raw as int];
+  }
+
+  @protected
+  DownloadEvent dco_decode_download_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 9)
+      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    return DownloadEvent(
+      kind: dco_decode_download_event_kind(arr[0]),
+      sourceId: dco_decode_String(arr[1]),
+      language: dco_decode_String(arr[2]),
+      novelId: dco_decode_String(arr[3]),
+      volumeId: dco_decode_String(arr[4]),
+      chapterId: dco_decode_String(arr[5]),
+      downloadCount: dco_decode_i_32(arr[6]),
+      totalCount: dco_decode_i_32(arr[7]),
+      error: dco_decode_String(arr[8]),
+    );
+  }
+
+  @protected
+  DownloadEventKind dco_decode_download_event_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return DownloadEventKind.values[raw as int];
+  }
+
   @protected
   ExistsDownload dco_decode_exists_download(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1682,6 +4510,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  double dco_decode_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
   @protected
   HomeBlock dco_decode_home_block(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1712,6 +4546,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (raw as List<dynamic>).map(dco_decode_String).toList();
   }
 
+  @protected
+  List<Account> dco_decode_list_account(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_account).toList();
+  }
+
   @protected
   List<Bookcase> dco_decode_list_bookcase(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1736,12 +4576,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (raw as List<dynamic>).map(dco_decode_chapter).toList();
   }
 
+  @protected
+  List<ChapterSegment> dco_decode_list_chapter_segment(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_chapter_segment).toList();
+  }
+
   @protected
   List<HomeBlock> dco_decode_list_home_block(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return (raw as List<dynamic>).map(dco_decode_home_block).toList();
   }
 
+  @protected
+  List<Message> dco_decode_list_message(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_message).toList();
+  }
+
+  @protected
+  List<MirrorProbe> dco_decode_list_mirror_probe(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_mirror_probe).toList();
+  }
+
   @protected
   List<NovelCover> dco_decode_list_novel_cover(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1772,6 +4630,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         .toList();
   }
 
+  @protected
+  List<NovelSourceInfo> dco_decode_list_novel_source_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_novel_source_info).toList();
+  }
+
+  @protected
+  List<NovelUpdate> dco_decode_list_novel_update(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_novel_update).toList();
+  }
+
+  @protected
+  Int64List dco_decode_list_prim_i_64_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeInt64List(raw);
+  }
+
   @protected
   Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1784,12 +4660,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (raw as List<dynamic>).map(dco_decode_reading_history).toList();
   }
 
+  @protected
+  List<RecommendLog> dco_decode_list_recommend_log(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_recommend_log).toList();
+  }
+
   @protected
   List<Review> dco_decode_list_review(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return (raw as List<dynamic>).map(dco_decode_review).toList();
   }
 
+  @protected
+  List<ReviewBlock> dco_decode_list_review_block(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_review_block).toList();
+  }
+
+  @protected
+  List<ReviewPost> dco_decode_list_review_post(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_review_post).toList();
+  }
+
   @protected
   List<SearchHistory> dco_decode_list_search_history(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1808,6 +4702,52 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (raw as List<dynamic>).map(dco_decode_volume).toList();
   }
 
+  @protected
+  Message dco_decode_message(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return Message(
+      id: dco_decode_String(arr[0]),
+      title: dco_decode_String(arr[1]),
+      userId: dco_decode_String(arr[2]),
+      username: dco_decode_String(arr[3]),
+      time: dco_decode_String(arr[4]),
+      unread: dco_decode_bool(arr[5]),
+    );
+  }
+
+  @protected
+  MessageDetail dco_decode_message_detail(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return MessageDetail(
+      id: dco_decode_String(arr[0]),
+      title: dco_decode_String(arr[1]),
+      sender: dco_decode_String(arr[2]),
+      receiver: dco_decode_String(arr[3]),
+      time: dco_decode_String(arr[4]),
+      content: dco_decode_String(arr[5]),
+    );
+  }
+
+  @protected
+  MirrorProbe dco_decode_mirror_probe(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return MirrorProbe(
+      host: dco_decode_String(arr[0]),
+      reachable: dco_decode_bool(arr[1]),
+      latencyMs: dco_decode_i_64(arr[2]),
+      error: dco_decode_String(arr[3]),
+    );
+  }
+
   @protected
   Novel dco_decode_novel(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1842,8 +4782,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   NovelDownload dco_decode_novel_download(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 16)
-      throw Exception('unexpected arr length: expect 16 but see ${arr.length}');
+    if (arr.length != 19)
+      throw Exception('unexpected arr length: expect 19 but see ${arr.length}');
     return NovelDownload(
       novelId: dco_decode_String(arr[0]),
       novelName: dco_decode_String(arr[1]),
@@ -1861,6 +4801,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       isAnimated: dco_decode_bool(arr[13]),
       finUpdate: dco_decode_String(arr[14]),
       status: dco_decode_String(arr[15]),
+      priority: dco_decode_i_32(arr[16]),
+      sourceId: dco_decode_String(arr[17]),
+      language: dco_decode_String(arr[18]),
     );
   }
 
@@ -1918,18 +4861,85 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  NovelSourceInfo dco_decode_novel_source_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return NovelSourceInfo(
+      id: dco_decode_String(arr[0]),
+      name: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  NovelUpdate dco_decode_novel_update(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return NovelUpdate(
+      id: dco_decode_i_64(arr[0]),
+      novelId: dco_decode_String(arr[1]),
+      novelName: dco_decode_String(arr[2]),
+      volumeId: dco_decode_String(arr[3]),
+      volumeTitle: dco_decode_String(arr[4]),
+      chapterId: dco_decode_String(arr[5]),
+      chapterTitle: dco_decode_String(arr[6]),
+      createTime: dco_decode_i_64(arr[7]),
+    );
+  }
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_String(raw);
+  }
+
+  @protected
+  Account? dco_decode_opt_box_autoadd_account(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_account(raw);
+  }
+
   @protected
   ExistsDownload? dco_decode_opt_box_autoadd_exists_download(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return raw == null ? null : dco_decode_box_autoadd_exists_download(raw);
   }
 
+  @protected
+  int? dco_decode_opt_box_autoadd_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_i_32(raw);
+  }
+
   @protected
   ReadingHistory? dco_decode_opt_box_autoadd_reading_history(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return raw == null ? null : dco_decode_box_autoadd_reading_history(raw);
   }
 
+  @protected
+  Wenku8ErrorInfo? dco_decode_opt_box_autoadd_wenku_8_error_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_wenku_8_error_info(raw);
+  }
+
+  @protected
+  PageStatsMessage dco_decode_page_stats_message(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return PageStatsMessage(
+      currentPage: dco_decode_i_32(arr[0]),
+      maxPage: dco_decode_i_32(arr[1]),
+      records: dco_decode_list_message(arr[2]),
+    );
+  }
+
   @protected
   PageStatsNovelCover dco_decode_page_stats_novel_cover(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1956,12 +4966,40 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  ProxySettings dco_decode_proxy_settings(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return ProxySettings(
+      enabled: dco_decode_bool(arr[0]),
+      url: dco_decode_String(arr[1]),
+      username: dco_decode_String(arr[2]),
+      password: dco_decode_String(arr[3]),
+      bypass: dco_decode_list_String(arr[4]),
+    );
+  }
+
+  @protected
+  ProxyTestResult dco_decode_proxy_test_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ProxyTestResult(
+      success: dco_decode_bool(arr[0]),
+      latencyMs: dco_decode_i_64(arr[1]),
+      error: dco_decode_String(arr[2]),
+    );
+  }
+
   @protected
   ReadingHistory dco_decode_reading_history(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 11)
-      throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
+    if (arr.length != 12)
+      throw Exception('unexpected arr length: expect 12 but see ${arr.length}');
     return ReadingHistory(
       novelId: dco_decode_String(arr[0]),
       novelName: dco_decode_String(arr[1]),
@@ -1974,6 +5012,22 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       progressPage: dco_decode_i_32(arr[8]),
       cover: dco_decode_String(arr[9]),
       author: dco_decode_String(arr[10]),
+      sourceId: dco_decode_String(arr[11]),
+    );
+  }
+
+  @protected
+  RecommendLog dco_decode_recommend_log(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return RecommendLog(
+      id: dco_decode_i_64(arr[0]),
+      accountId: dco_decode_i_64(arr[1]),
+      date: dco_decode_String(arr[2]),
+      novelId: dco_decode_String(arr[3]),
+      createTime: dco_decode_i_64(arr[4]),
     );
   }
 
@@ -1993,6 +5047,55 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  ReviewBlock dco_decode_review_block(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ReviewBlock(
+      kind: dco_decode_review_block_kind(arr[0]),
+      text: dco_decode_String(arr[1]),
+      url: dco_decode_String(arr[2]),
+    );
+  }
+
+  @protected
+  ReviewBlockKind dco_decode_review_block_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ReviewBlockKind.values[raw as int];
+  }
+
+  @protected
+  ReviewDetail dco_decode_review_detail(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return ReviewDetail(
+      rid: dco_decode_String(arr[0]),
+      title: dco_decode_String(arr[1]),
+      currentPage: dco_decode_i_32(arr[2]),
+      maxPage: dco_decode_i_32(arr[3]),
+      posts: dco_decode_list_review_post(arr[4]),
+    );
+  }
+
+  @protected
+  ReviewPost dco_decode_review_post(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return ReviewPost(
+      uid: dco_decode_String(arr[0]),
+      uname: dco_decode_String(arr[1]),
+      time: dco_decode_String(arr[2]),
+      floor: dco_decode_i_32(arr[3]),
+      blocks: dco_decode_list_review_block(arr[4]),
+    );
+  }
+
   @protected
   SearchHistory dco_decode_search_history(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2060,6 +5163,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  VaultState dco_decode_vault_state(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return VaultState(
+      enabled: dco_decode_bool(arr[0]),
+      unlocked: dco_decode_bool(arr[1]),
+    );
+  }
+
   @protected
   Volume dco_decode_volume(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2073,6 +5188,25 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  Wenku8ErrorInfo dco_decode_wenku_8_error_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return Wenku8ErrorInfo(
+      kind: dco_decode_wenku_8_error_kind(arr[0]),
+      httpStatus: dco_decode_i_32(arr[1]),
+      message: dco_decode_String(arr[2]),
+    );
+  }
+
+  @protected
+  Wenku8ErrorKind dco_decode_wenku_8_error_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return Wenku8ErrorKind.values[raw as int];
+  }
+
   @protected
   AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2080,6 +5214,22 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return AnyhowException(inner);
   }
 
+  @protected
+  RustStreamSink<String> sse_decode_StreamSink_String_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<DownloadEvent> sse_decode_StreamSink_download_event_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
   @protected
   String sse_decode_String(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2087,6 +5237,21 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return utf8.decoder.convert(inner);
   }
 
+  @protected
+  Account sse_decode_account(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_i_64(deserializer);
+    var var_username = sse_decode_String(deserializer);
+    var var_createTime = sse_decode_i_64(deserializer);
+    var var_lastLoginTime = sse_decode_i_64(deserializer);
+    return Account(
+      id: var_id,
+      username: var_username,
+      createTime: var_createTime,
+      lastLoginTime: var_lastLoginTime,
+    );
+  }
+
   @protected
   Bookcase sse_decode_bookcase(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2136,6 +5301,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return deserializer.buffer.getUint8() != 0;
   }
 
+  @protected
+  Account sse_decode_box_autoadd_account(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_account(deserializer));
+  }
+
   @protected
   ExistsDownload sse_decode_box_autoadd_exists_download(
     SseDeserializer deserializer,
@@ -2144,6 +5315,20 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_exists_download(deserializer));
   }
 
+  @protected
+  int sse_decode_box_autoadd_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  ProxySettings sse_decode_box_autoadd_proxy_settings(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_proxy_settings(deserializer));
+  }
+
   @protected
   ReadingHistory sse_decode_box_autoadd_reading_history(
     SseDeserializer deserializer,
@@ -2152,6 +5337,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_reading_history(deserializer));
   }
 
+  @protected
+  Wenku8ErrorInfo sse_decode_box_autoadd_wenku_8_error_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_wenku_8_error_info(deserializer));
+  }
+
   @protected
   Chapter sse_decode_chapter(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2162,6 +5355,84 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return Chapter(title: var_title, url: var_url, cid: var_cid, aid: var_aid);
   }
 
+  @protected
+  ChapterDocument sse_decode_chapter_document(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_aid = sse_decode_String(deserializer);
+    var var_cid = sse_decode_String(deserializer);
+    var var_segments = sse_decode_list_chapter_segment(deserializer);
+    return ChapterDocument(aid: var_aid, cid: var_cid, segments: var_segments);
+  }
+
+  @protected
+  ChapterSegment sse_decode_chapter_segment(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_kind = sse_decode_chapter_segment_kind(deserializer);
+    var var_text = sse_decode_String(deserializer);
+    var var_url = sse_decode_String(deserializer);
+    var var_localPath = sse_decode_opt_String(deserializer);
+    var var_width = sse_decode_opt_box_autoadd_i_32(deserializer);
+    var var_height = sse_decode_opt_box_autoadd_i_32(deserializer);
+    return ChapterSegment(
+      kind: var_kind,
+      text: var_text,
+      url: var_url,
+      localPath: var_localPath,
+      width: var_width,
+      height: var_height,
+    );
+  }
+
+  @protected
+  ChapterSegmentKind sse_decode_chapter_segment_kind(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return ChapterSegmentKind.values[inner];
+  }
+
+  @protected
+  CookieFileFormat sse_decode_cookie_file_format(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return CookieFileFormat.values[inner];
+  }
+
+  @protected
+  DownloadEvent sse_decode_download_event(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_kind = sse_decode_download_event_kind(deserializer);
+    var var_sourceId = sse_decode_String(deserializer);
+    var var_language = sse_decode_String(deserializer);
+    var var_novelId = sse_decode_String(deserializer);
+    var var_volumeId = sse_decode_String(deserializer);
+    var var_chapterId = sse_decode_String(deserializer);
+    var var_downloadCount = sse_decode_i_32(deserializer);
+    var var_totalCount = sse_decode_i_32(deserializer);
+    var var_error = sse_decode_String(deserializer);
+    return DownloadEvent(
+      kind: var_kind,
+      sourceId: var_sourceId,
+      language: var_language,
+      novelId: var_novelId,
+      volumeId: var_volumeId,
+      chapterId: var_chapterId,
+      downloadCount: var_downloadCount,
+      totalCount: var_totalCount,
+      error: var_error,
+    );
+  }
+
+  @protected
+  DownloadEventKind sse_decode_download_event_kind(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return DownloadEventKind.values[inner];
+  }
+
   @protected
   ExistsDownload sse_decode_exists_download(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2179,6 +5450,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  double sse_decode_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getFloat64();
+  }
+
   @protected
   HomeBlock sse_decode_home_block(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2211,6 +5488,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<Account> sse_decode_list_account(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <Account>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_account(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<Bookcase> sse_decode_list_bookcase(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2263,6 +5552,20 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<ChapterSegment> sse_decode_list_chapter_segment(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ChapterSegment>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_chapter_segment(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<HomeBlock> sse_decode_list_home_block(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2275,6 +5578,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<Message> sse_decode_list_message(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <Message>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_message(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<MirrorProbe> sse_decode_list_mirror_probe(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <MirrorProbe>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_mirror_probe(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<NovelCover> sse_decode_list_novel_cover(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2329,6 +5656,39 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<NovelSourceInfo> sse_decode_list_novel_source_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <NovelSourceInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_novel_source_info(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<NovelUpdate> sse_decode_list_novel_update(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <NovelUpdate>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_novel_update(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  Int64List sse_decode_list_prim_i_64_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getInt64List(len_);
+  }
+
   @protected
   Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2350,6 +5710,20 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<RecommendLog> sse_decode_list_recommend_log(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <RecommendLog>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_recommend_log(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<Review> sse_decode_list_review(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2362,6 +5736,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<ReviewBlock> sse_decode_list_review_block(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ReviewBlock>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_review_block(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ReviewPost> sse_decode_list_review_post(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ReviewPost>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_review_post(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<SearchHistory> sse_decode_list_search_history(
     SseDeserializer deserializer,
@@ -2389,15 +5787,68 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  List<Volume> sse_decode_list_volume(SseDeserializer deserializer) {
+  List<Volume> sse_decode_list_volume(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <Volume>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_volume(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  Message sse_decode_message(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_title = sse_decode_String(deserializer);
+    var var_userId = sse_decode_String(deserializer);
+    var var_username = sse_decode_String(deserializer);
+    var var_time = sse_decode_String(deserializer);
+    var var_unread = sse_decode_bool(deserializer);
+    return Message(
+      id: var_id,
+      title: var_title,
+      userId: var_userId,
+      username: var_username,
+      time: var_time,
+      unread: var_unread,
+    );
+  }
+
+  @protected
+  MessageDetail sse_decode_message_detail(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_title = sse_decode_String(deserializer);
+    var var_sender = sse_decode_String(deserializer);
+    var var_receiver = sse_decode_String(deserializer);
+    var var_time = sse_decode_String(deserializer);
+    var var_content = sse_decode_String(deserializer);
+    return MessageDetail(
+      id: var_id,
+      title: var_title,
+      sender: var_sender,
+      receiver: var_receiver,
+      time: var_time,
+      content: var_content,
+    );
+  }
 
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <Volume>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_volume(deserializer));
-    }
-    return ans_;
+  @protected
+  MirrorProbe sse_decode_mirror_probe(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_host = sse_decode_String(deserializer);
+    var var_reachable = sse_decode_bool(deserializer);
+    var var_latencyMs = sse_decode_i_64(deserializer);
+    var var_error = sse_decode_String(deserializer);
+    return MirrorProbe(
+      host: var_host,
+      reachable: var_reachable,
+      latencyMs: var_latencyMs,
+      error: var_error,
+    );
   }
 
   @protected
@@ -2453,6 +5904,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     var var_isAnimated = sse_decode_bool(deserializer);
     var var_finUpdate = sse_decode_String(deserializer);
     var var_status = sse_decode_String(deserializer);
+    var var_priority = sse_decode_i_32(deserializer);
+    var var_sourceId = sse_decode_String(deserializer);
+    var var_language = sse_decode_String(deserializer);
     return NovelDownload(
       novelId: var_novelId,
       novelName: var_novelName,
@@ -2470,6 +5924,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       isAnimated: var_isAnimated,
       finUpdate: var_finUpdate,
       status: var_status,
+      priority: var_priority,
+      sourceId: var_sourceId,
+      language: var_language,
     );
   }
 
@@ -2546,6 +6003,59 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  NovelSourceInfo sse_decode_novel_source_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    return NovelSourceInfo(id: var_id, name: var_name);
+  }
+
+  @protected
+  NovelUpdate sse_decode_novel_update(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_i_64(deserializer);
+    var var_novelId = sse_decode_String(deserializer);
+    var var_novelName = sse_decode_String(deserializer);
+    var var_volumeId = sse_decode_String(deserializer);
+    var var_volumeTitle = sse_decode_String(deserializer);
+    var var_chapterId = sse_decode_String(deserializer);
+    var var_chapterTitle = sse_decode_String(deserializer);
+    var var_createTime = sse_decode_i_64(deserializer);
+    return NovelUpdate(
+      id: var_id,
+      novelId: var_novelId,
+      novelName: var_novelName,
+      volumeId: var_volumeId,
+      volumeTitle: var_volumeTitle,
+      chapterId: var_chapterId,
+      chapterTitle: var_chapterTitle,
+      createTime: var_createTime,
+    );
+  }
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_String(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  Account? sse_decode_opt_box_autoadd_account(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_account(deserializer));
+    } else {
+      return null;
+    }
+  }
+
   @protected
   ExistsDownload? sse_decode_opt_box_autoadd_exists_download(
     SseDeserializer deserializer,
@@ -2559,6 +6069,17 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_i_32(deserializer));
+    } else {
+      return null;
+    }
+  }
+
   @protected
   ReadingHistory? sse_decode_opt_box_autoadd_reading_history(
     SseDeserializer deserializer,
@@ -2572,6 +6093,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  Wenku8ErrorInfo? sse_decode_opt_box_autoadd_wenku_8_error_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_wenku_8_error_info(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  PageStatsMessage sse_decode_page_stats_message(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_currentPage = sse_decode_i_32(deserializer);
+    var var_maxPage = sse_decode_i_32(deserializer);
+    var var_records = sse_decode_list_message(deserializer);
+    return PageStatsMessage(
+      currentPage: var_currentPage,
+      maxPage: var_maxPage,
+      records: var_records,
+    );
+  }
+
   @protected
   PageStatsNovelCover sse_decode_page_stats_novel_cover(
     SseDeserializer deserializer,
@@ -2600,6 +6147,36 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  ProxySettings sse_decode_proxy_settings(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_enabled = sse_decode_bool(deserializer);
+    var var_url = sse_decode_String(deserializer);
+    var var_username = sse_decode_String(deserializer);
+    var var_password = sse_decode_String(deserializer);
+    var var_bypass = sse_decode_list_String(deserializer);
+    return ProxySettings(
+      enabled: var_enabled,
+      url: var_url,
+      username: var_username,
+      password: var_password,
+      bypass: var_bypass,
+    );
+  }
+
+  @protected
+  ProxyTestResult sse_decode_proxy_test_result(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_success = sse_decode_bool(deserializer);
+    var var_latencyMs = sse_decode_i_64(deserializer);
+    var var_error = sse_decode_String(deserializer);
+    return ProxyTestResult(
+      success: var_success,
+      latencyMs: var_latencyMs,
+      error: var_error,
+    );
+  }
+
   @protected
   ReadingHistory sse_decode_reading_history(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2614,6 +6191,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     var var_progressPage = sse_decode_i_32(deserializer);
     var var_cover = sse_decode_String(deserializer);
     var var_author = sse_decode_String(deserializer);
+    var var_sourceId = sse_decode_String(deserializer);
     return ReadingHistory(
       novelId: var_novelId,
       novelName: var_novelName,
@@ -2626,6 +6204,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       progressPage: var_progressPage,
       cover: var_cover,
       author: var_author,
+      sourceId: var_sourceId,
+    );
+  }
+
+  @protected
+  RecommendLog sse_decode_recommend_log(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_i_64(deserializer);
+    var var_accountId = sse_decode_i_64(deserializer);
+    var var_date = sse_decode_String(deserializer);
+    var var_novelId = sse_decode_String(deserializer);
+    var var_createTime = sse_decode_i_64(deserializer);
+    return RecommendLog(
+      id: var_id,
+      accountId: var_accountId,
+      date: var_date,
+      novelId: var_novelId,
+      createTime: var_createTime,
     );
   }
 
@@ -2648,6 +6244,56 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  ReviewBlock sse_decode_review_block(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_kind = sse_decode_review_block_kind(deserializer);
+    var var_text = sse_decode_String(deserializer);
+    var var_url = sse_decode_String(deserializer);
+    return ReviewBlock(kind: var_kind, text: var_text, url: var_url);
+  }
+
+  @protected
+  ReviewBlockKind sse_decode_review_block_kind(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return ReviewBlockKind.values[inner];
+  }
+
+  @protected
+  ReviewDetail sse_decode_review_detail(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_rid = sse_decode_String(deserializer);
+    var var_title = sse_decode_String(deserializer);
+    var var_currentPage = sse_decode_i_32(deserializer);
+    var var_maxPage = sse_decode_i_32(deserializer);
+    var var_posts = sse_decode_list_review_post(deserializer);
+    return ReviewDetail(
+      rid: var_rid,
+      title: var_title,
+      currentPage: var_currentPage,
+      maxPage: var_maxPage,
+      posts: var_posts,
+    );
+  }
+
+  @protected
+  ReviewPost sse_decode_review_post(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_uid = sse_decode_String(deserializer);
+    var var_uname = sse_decode_String(deserializer);
+    var var_time = sse_decode_String(deserializer);
+    var var_floor = sse_decode_i_32(deserializer);
+    var var_blocks = sse_decode_list_review_block(deserializer);
+    return ReviewPost(
+      uid: var_uid,
+      uname: var_uname,
+      time: var_time,
+      floor: var_floor,
+      blocks: var_blocks,
+    );
+  }
+
   @protected
   SearchHistory sse_decode_search_history(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2727,6 +6373,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  VaultState sse_decode_vault_state(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_enabled = sse_decode_bool(deserializer);
+    var var_unlocked = sse_decode_bool(deserializer);
+    return VaultState(enabled: var_enabled, unlocked: var_unlocked);
+  }
+
   @protected
   Volume sse_decode_volume(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2736,6 +6390,26 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return Volume(id: var_id, title: var_title, chapters: var_chapters);
   }
 
+  @protected
+  Wenku8ErrorInfo sse_decode_wenku_8_error_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_kind = sse_decode_wenku_8_error_kind(deserializer);
+    var var_httpStatus = sse_decode_i_32(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    return Wenku8ErrorInfo(
+      kind: var_kind,
+      httpStatus: var_httpStatus,
+      message: var_message,
+    );
+  }
+
+  @protected
+  Wenku8ErrorKind sse_decode_wenku_8_error_kind(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return Wenku8ErrorKind.values[inner];
+  }
+
   @protected
   void sse_encode_AnyhowException(
     AnyhowException self,
@@ -2745,12 +6419,55 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_String(self.message, serializer);
   }
 
+  @protected
+  void sse_encode_StreamSink_String_Sse(
+    RustStreamSink<String> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_download_event_Sse(
+    RustStreamSink<DownloadEvent> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_download_event,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
   @protected
   void sse_encode_String(String self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
   }
 
+  @protected
+  void sse_encode_account(Account self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_64(self.id, serializer);
+    sse_encode_String(self.username, serializer);
+    sse_encode_i_64(self.createTime, serializer);
+    sse_encode_i_64(self.lastLoginTime, serializer);
+  }
+
   @protected
   void sse_encode_bookcase(Bookcase self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2784,36 +6501,132 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
+  void sse_encode_bool(bool self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint8(self ? 1 : 0);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_account(Account self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_account(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_exists_download(
+    ExistsDownload self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_exists_download(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_proxy_settings(
+    ProxySettings self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_proxy_settings(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_reading_history(
+    ReadingHistory self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_reading_history(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_wenku_8_error_info(
+    Wenku8ErrorInfo self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_wenku_8_error_info(self, serializer);
+  }
+
+  @protected
+  void sse_encode_chapter(Chapter self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.title, serializer);
+    sse_encode_String(self.url, serializer);
+    sse_encode_String(self.cid, serializer);
+    sse_encode_String(self.aid, serializer);
+  }
+
+  @protected
+  void sse_encode_chapter_document(
+    ChapterDocument self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.aid, serializer);
+    sse_encode_String(self.cid, serializer);
+    sse_encode_list_chapter_segment(self.segments, serializer);
+  }
+
+  @protected
+  void sse_encode_chapter_segment(
+    ChapterSegment self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
+    sse_encode_chapter_segment_kind(self.kind, serializer);
+    sse_encode_String(self.text, serializer);
+    sse_encode_String(self.url, serializer);
+    sse_encode_opt_String(self.localPath, serializer);
+    sse_encode_opt_box_autoadd_i_32(self.width, serializer);
+    sse_encode_opt_box_autoadd_i_32(self.height, serializer);
   }
 
   @protected
-  void sse_encode_box_autoadd_exists_download(
-    ExistsDownload self,
+  void sse_encode_chapter_segment_kind(
+    ChapterSegmentKind self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_exists_download(self, serializer);
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_box_autoadd_reading_history(
-    ReadingHistory self,
+  void sse_encode_cookie_file_format(
+    CookieFileFormat self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_reading_history(self, serializer);
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_chapter(Chapter self, SseSerializer serializer) {
+  void sse_encode_download_event(DownloadEvent self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.title, serializer);
-    sse_encode_String(self.url, serializer);
-    sse_encode_String(self.cid, serializer);
-    sse_encode_String(self.aid, serializer);
+    sse_encode_download_event_kind(self.kind, serializer);
+    sse_encode_String(self.sourceId, serializer);
+    sse_encode_String(self.language, serializer);
+    sse_encode_String(self.novelId, serializer);
+    sse_encode_String(self.volumeId, serializer);
+    sse_encode_String(self.chapterId, serializer);
+    sse_encode_i_32(self.downloadCount, serializer);
+    sse_encode_i_32(self.totalCount, serializer);
+    sse_encode_String(self.error, serializer);
+  }
+
+  @protected
+  void sse_encode_download_event_kind(
+    DownloadEventKind self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
@@ -2830,6 +6643,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  void sse_encode_f_64(double self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putFloat64(self);
+  }
+
   @protected
   void sse_encode_home_block(HomeBlock self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2858,6 +6677,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_account(List<Account> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_account(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_bookcase(List<Bookcase> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2900,6 +6728,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_chapter_segment(
+    List<ChapterSegment> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_chapter_segment(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_home_block(
     List<HomeBlock> self,
@@ -2912,6 +6752,27 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_message(List<Message> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_message(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_mirror_probe(
+    List<MirrorProbe> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_mirror_probe(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_novel_cover(
     List<NovelCover> self,
@@ -2960,6 +6821,40 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_novel_source_info(
+    List<NovelSourceInfo> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_novel_source_info(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_novel_update(
+    List<NovelUpdate> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_novel_update(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_prim_i_64_strict(
+    Int64List self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putInt64List(self);
+  }
+
   @protected
   void sse_encode_list_prim_u_8_strict(
     Uint8List self,
@@ -2982,6 +6877,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_recommend_log(
+    List<RecommendLog> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_recommend_log(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_review(List<Review> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2991,6 +6898,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_review_block(
+    List<ReviewBlock> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_review_block(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_review_post(
+    List<ReviewPost> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_review_post(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_search_history(
     List<SearchHistory> self,
@@ -3024,6 +6955,37 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_message(Message self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.id, serializer);
+    sse_encode_String(self.title, serializer);
+    sse_encode_String(self.userId, serializer);
+    sse_encode_String(self.username, serializer);
+    sse_encode_String(self.time, serializer);
+    sse_encode_bool(self.unread, serializer);
+  }
+
+  @protected
+  void sse_encode_message_detail(MessageDetail self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.id, serializer);
+    sse_encode_String(self.title, serializer);
+    sse_encode_String(self.sender, serializer);
+    sse_encode_String(self.receiver, serializer);
+    sse_encode_String(self.time, serializer);
+    sse_encode_String(self.content, serializer);
+  }
+
+  @protected
+  void sse_encode_mirror_probe(MirrorProbe self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.host, serializer);
+    sse_encode_bool(self.reachable, serializer);
+    sse_encode_i_64(self.latencyMs, serializer);
+    sse_encode_String(self.error, serializer);
+  }
+
   @protected
   void sse_encode_novel(Novel self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3063,6 +7025,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_bool(self.isAnimated, serializer);
     sse_encode_String(self.finUpdate, serializer);
     sse_encode_String(self.status, serializer);
+    sse_encode_i_32(self.priority, serializer);
+    sse_encode_String(self.sourceId, serializer);
+    sse_encode_String(self.language, serializer);
   }
 
   @protected
@@ -3110,6 +7075,52 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_bool(self.isAnimated, serializer);
   }
 
+  @protected
+  void sse_encode_novel_source_info(
+    NovelSourceInfo self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.id, serializer);
+    sse_encode_String(self.name, serializer);
+  }
+
+  @protected
+  void sse_encode_novel_update(NovelUpdate self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_64(self.id, serializer);
+    sse_encode_String(self.novelId, serializer);
+    sse_encode_String(self.novelName, serializer);
+    sse_encode_String(self.volumeId, serializer);
+    sse_encode_String(self.volumeTitle, serializer);
+    sse_encode_String(self.chapterId, serializer);
+    sse_encode_String(self.chapterTitle, serializer);
+    sse_encode_i_64(self.createTime, serializer);
+  }
+
+  @protected
+  void sse_encode_opt_String(String? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_String(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_account(
+    Account? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_account(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_box_autoadd_exists_download(
     ExistsDownload? self,
@@ -3123,6 +7134,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_i_32(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_box_autoadd_reading_history(
     ReadingHistory? self,
@@ -3136,6 +7157,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_box_autoadd_wenku_8_error_info(
+    Wenku8ErrorInfo? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_wenku_8_error_info(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_page_stats_message(
+    PageStatsMessage self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.currentPage, serializer);
+    sse_encode_i_32(self.maxPage, serializer);
+    sse_encode_list_message(self.records, serializer);
+  }
+
   @protected
   void sse_encode_page_stats_novel_cover(
     PageStatsNovelCover self,
@@ -3158,6 +7203,27 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_list_review(self.records, serializer);
   }
 
+  @protected
+  void sse_encode_proxy_settings(ProxySettings self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.enabled, serializer);
+    sse_encode_String(self.url, serializer);
+    sse_encode_String(self.username, serializer);
+    sse_encode_String(self.password, serializer);
+    sse_encode_list_String(self.bypass, serializer);
+  }
+
+  @protected
+  void sse_encode_proxy_test_result(
+    ProxyTestResult self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.success, serializer);
+    sse_encode_i_64(self.latencyMs, serializer);
+    sse_encode_String(self.error, serializer);
+  }
+
   @protected
   void sse_encode_reading_history(
     ReadingHistory self,
@@ -3175,6 +7241,17 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_i_32(self.progressPage, serializer);
     sse_encode_String(self.cover, serializer);
     sse_encode_String(self.author, serializer);
+    sse_encode_String(self.sourceId, serializer);
+  }
+
+  @protected
+  void sse_encode_recommend_log(RecommendLog self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_64(self.id, serializer);
+    sse_encode_i_64(self.accountId, serializer);
+    sse_encode_String(self.date, serializer);
+    sse_encode_String(self.novelId, serializer);
+    sse_encode_i_64(self.createTime, serializer);
   }
 
   @protected
@@ -3188,6 +7265,43 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_String(self.time, serializer);
   }
 
+  @protected
+  void sse_encode_review_block(ReviewBlock self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_review_block_kind(self.kind, serializer);
+    sse_encode_String(self.text, serializer);
+    sse_encode_String(self.url, serializer);
+  }
+
+  @protected
+  void sse_encode_review_block_kind(
+    ReviewBlockKind self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_review_detail(ReviewDetail self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.rid, serializer);
+    sse_encode_String(self.title, serializer);
+    sse_encode_i_32(self.currentPage, serializer);
+    sse_encode_i_32(self.maxPage, serializer);
+    sse_encode_list_review_post(self.posts, serializer);
+  }
+
+  @protected
+  void sse_encode_review_post(ReviewPost self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.uid, serializer);
+    sse_encode_String(self.uname, serializer);
+    sse_encode_String(self.time, serializer);
+    sse_encode_i_32(self.floor, serializer);
+    sse_encode_list_review_block(self.blocks, serializer);
+  }
+
   @protected
   void sse_encode_search_history(SearchHistory self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3239,6 +7353,13 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_String(self.personalizedDescription, serializer);
   }
 
+  @protected
+  void sse_encode_vault_state(VaultState self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.enabled, serializer);
+    sse_encode_bool(self.unlocked, serializer);
+  }
+
   @protected
   void sse_encode_volume(Volume self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3246,4 +7367,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_String(self.title, serializer);
     sse_encode_list_chapter(self.chapters, serializer);
   }
+
+  @protected
+  void sse_encode_wenku_8_error_info(
+    Wenku8ErrorInfo self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_wenku_8_error_kind(self.kind, serializer);
+    sse_encode_i_32(self.httpStatus, serializer);
+    sse_encode_String(self.message, serializer);
+  }
+
+  @protected
+  void sse_encode_wenku_8_error_kind(
+    Wenku8ErrorKind self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
 }
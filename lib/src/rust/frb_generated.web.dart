@@ -8,12 +8,19 @@
 
 import 'api/database.dart';
 import 'api/simple.dart';
+import 'api/source.dart';
 import 'api/system.dart';
 import 'api/wenku8.dart';
+import 'chapter_document.dart';
+import 'cookie_file.dart';
 import 'dart:async';
 import 'dart:convert';
+import 'downloading.dart';
 import 'frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_web.dart';
+import 'proxy.dart';
+import 'vault.dart';
+import 'wenku8/mirror.dart';
 import 'wenku8/models.dart';
 
 abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
@@ -27,9 +34,20 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   AnyhowException dco_decode_AnyhowException(dynamic raw);
 
+  @protected
+  RustStreamSink<String> dco_decode_StreamSink_String_Sse(dynamic raw);
+
+  @protected
+  RustStreamSink<DownloadEvent> dco_decode_StreamSink_download_event_Sse(
+    dynamic raw,
+  );
+
   @protected
   String dco_decode_String(dynamic raw);
 
+  @protected
+  Account dco_decode_account(dynamic raw);
+
   @protected
   Bookcase dco_decode_bookcase(dynamic raw);
 
@@ -45,18 +63,51 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   bool dco_decode_bool(dynamic raw);
 
+  @protected
+  Account dco_decode_box_autoadd_account(dynamic raw);
+
   @protected
   ExistsDownload dco_decode_box_autoadd_exists_download(dynamic raw);
 
+  @protected
+  int dco_decode_box_autoadd_i_32(dynamic raw);
+
+  @protected
+  ProxySettings dco_decode_box_autoadd_proxy_settings(dynamic raw);
+
   @protected
   ReadingHistory dco_decode_box_autoadd_reading_history(dynamic raw);
 
+  @protected
+  Wenku8ErrorInfo dco_decode_box_autoadd_wenku_8_error_info(dynamic raw);
+
   @protected
   Chapter dco_decode_chapter(dynamic raw);
 
+  @protected
+  ChapterDocument dco_decode_chapter_document(dynamic raw);
+
+  @protected
+  ChapterSegment dco_decode_chapter_segment(dynamic raw);
+
+  @protected
+  ChapterSegmentKind dco_decode_chapter_segment_kind(dynamic raw);
+
+  @protected
+  CookieFileFormat dco_decode_cookie_file_format(dynamic raw);
+
+  @protected
+  DownloadEvent dco_decode_download_event(dynamic raw);
+
+  @protected
+  DownloadEventKind dco_decode_download_event_kind(dynamic raw);
+
   @protected
   ExistsDownload dco_decode_exists_download(dynamic raw);
 
+  @protected
+  double dco_decode_f_64(dynamic raw);
+
   @protected
   HomeBlock dco_decode_home_block(dynamic raw);
 
@@ -69,6 +120,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<String> dco_decode_list_String(dynamic raw);
 
+  @protected
+  List<Account> dco_decode_list_account(dynamic raw);
+
   @protected
   List<Bookcase> dco_decode_list_bookcase(dynamic raw);
 
@@ -81,9 +135,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<Chapter> dco_decode_list_chapter(dynamic raw);
 
+  @protected
+  List<ChapterSegment> dco_decode_list_chapter_segment(dynamic raw);
+
   @protected
   List<HomeBlock> dco_decode_list_home_block(dynamic raw);
 
+  @protected
+  List<Message> dco_decode_list_message(dynamic raw);
+
+  @protected
+  List<MirrorProbe> dco_decode_list_mirror_probe(dynamic raw);
+
   @protected
   List<NovelCover> dco_decode_list_novel_cover(dynamic raw);
 
@@ -98,15 +161,33 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<NovelDownloadVolume> dco_decode_list_novel_download_volume(dynamic raw);
 
+  @protected
+  List<NovelSourceInfo> dco_decode_list_novel_source_info(dynamic raw);
+
+  @protected
+  List<NovelUpdate> dco_decode_list_novel_update(dynamic raw);
+
+  @protected
+  Int64List dco_decode_list_prim_i_64_strict(dynamic raw);
+
   @protected
   Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
 
   @protected
   List<ReadingHistory> dco_decode_list_reading_history(dynamic raw);
 
+  @protected
+  List<RecommendLog> dco_decode_list_recommend_log(dynamic raw);
+
   @protected
   List<Review> dco_decode_list_review(dynamic raw);
 
+  @protected
+  List<ReviewBlock> dco_decode_list_review_block(dynamic raw);
+
+  @protected
+  List<ReviewPost> dco_decode_list_review_post(dynamic raw);
+
   @protected
   List<SearchHistory> dco_decode_list_search_history(dynamic raw);
 
@@ -116,6 +197,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<Volume> dco_decode_list_volume(dynamic raw);
 
+  @protected
+  Message dco_decode_message(dynamic raw);
+
+  @protected
+  MessageDetail dco_decode_message_detail(dynamic raw);
+
+  @protected
+  MirrorProbe dco_decode_mirror_probe(dynamic raw);
+
   @protected
   Novel dco_decode_novel(dynamic raw);
 
@@ -134,24 +224,66 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   NovelInfo dco_decode_novel_info(dynamic raw);
 
+  @protected
+  NovelSourceInfo dco_decode_novel_source_info(dynamic raw);
+
+  @protected
+  NovelUpdate dco_decode_novel_update(dynamic raw);
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw);
+
+  @protected
+  Account? dco_decode_opt_box_autoadd_account(dynamic raw);
+
   @protected
   ExistsDownload? dco_decode_opt_box_autoadd_exists_download(dynamic raw);
 
+  @protected
+  int? dco_decode_opt_box_autoadd_i_32(dynamic raw);
+
   @protected
   ReadingHistory? dco_decode_opt_box_autoadd_reading_history(dynamic raw);
 
+  @protected
+  Wenku8ErrorInfo? dco_decode_opt_box_autoadd_wenku_8_error_info(dynamic raw);
+
+  @protected
+  PageStatsMessage dco_decode_page_stats_message(dynamic raw);
+
   @protected
   PageStatsNovelCover dco_decode_page_stats_novel_cover(dynamic raw);
 
   @protected
   PageStatsReviews dco_decode_page_stats_reviews(dynamic raw);
 
+  @protected
+  ProxySettings dco_decode_proxy_settings(dynamic raw);
+
+  @protected
+  ProxyTestResult dco_decode_proxy_test_result(dynamic raw);
+
   @protected
   ReadingHistory dco_decode_reading_history(dynamic raw);
 
+  @protected
+  RecommendLog dco_decode_recommend_log(dynamic raw);
+
   @protected
   Review dco_decode_review(dynamic raw);
 
+  @protected
+  ReviewBlock dco_decode_review_block(dynamic raw);
+
+  @protected
+  ReviewBlockKind dco_decode_review_block_kind(dynamic raw);
+
+  @protected
+  ReviewDetail dco_decode_review_detail(dynamic raw);
+
+  @protected
+  ReviewPost dco_decode_review_post(dynamic raw);
+
   @protected
   SearchHistory dco_decode_search_history(dynamic raw);
 
@@ -167,15 +299,37 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   UserDetail dco_decode_user_detail(dynamic raw);
 
+  @protected
+  VaultState dco_decode_vault_state(dynamic raw);
+
   @protected
   Volume dco_decode_volume(dynamic raw);
 
+  @protected
+  Wenku8ErrorInfo dco_decode_wenku_8_error_info(dynamic raw);
+
+  @protected
+  Wenku8ErrorKind dco_decode_wenku_8_error_kind(dynamic raw);
+
   @protected
   AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
 
+  @protected
+  RustStreamSink<String> sse_decode_StreamSink_String_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<DownloadEvent> sse_decode_StreamSink_download_event_Sse(
+    SseDeserializer deserializer,
+  );
+
   @protected
   String sse_decode_String(SseDeserializer deserializer);
 
+  @protected
+  Account sse_decode_account(SseDeserializer deserializer);
+
   @protected
   Bookcase sse_decode_bookcase(SseDeserializer deserializer);
 
@@ -191,22 +345,63 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   bool sse_decode_bool(SseDeserializer deserializer);
 
+  @protected
+  Account sse_decode_box_autoadd_account(SseDeserializer deserializer);
+
   @protected
   ExistsDownload sse_decode_box_autoadd_exists_download(
     SseDeserializer deserializer,
   );
 
+  @protected
+  int sse_decode_box_autoadd_i_32(SseDeserializer deserializer);
+
+  @protected
+  ProxySettings sse_decode_box_autoadd_proxy_settings(
+    SseDeserializer deserializer,
+  );
+
   @protected
   ReadingHistory sse_decode_box_autoadd_reading_history(
     SseDeserializer deserializer,
   );
 
+  @protected
+  Wenku8ErrorInfo sse_decode_box_autoadd_wenku_8_error_info(
+    SseDeserializer deserializer,
+  );
+
   @protected
   Chapter sse_decode_chapter(SseDeserializer deserializer);
 
+  @protected
+  ChapterDocument sse_decode_chapter_document(SseDeserializer deserializer);
+
+  @protected
+  ChapterSegment sse_decode_chapter_segment(SseDeserializer deserializer);
+
+  @protected
+  ChapterSegmentKind sse_decode_chapter_segment_kind(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  CookieFileFormat sse_decode_cookie_file_format(SseDeserializer deserializer);
+
+  @protected
+  DownloadEvent sse_decode_download_event(SseDeserializer deserializer);
+
+  @protected
+  DownloadEventKind sse_decode_download_event_kind(
+    SseDeserializer deserializer,
+  );
+
   @protected
   ExistsDownload sse_decode_exists_download(SseDeserializer deserializer);
 
+  @protected
+  double sse_decode_f_64(SseDeserializer deserializer);
+
   @protected
   HomeBlock sse_decode_home_block(SseDeserializer deserializer);
 
@@ -219,6 +414,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<String> sse_decode_list_String(SseDeserializer deserializer);
 
+  @protected
+  List<Account> sse_decode_list_account(SseDeserializer deserializer);
+
   @protected
   List<Bookcase> sse_decode_list_bookcase(SseDeserializer deserializer);
 
@@ -235,9 +433,20 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<Chapter> sse_decode_list_chapter(SseDeserializer deserializer);
 
+  @protected
+  List<ChapterSegment> sse_decode_list_chapter_segment(
+    SseDeserializer deserializer,
+  );
+
   @protected
   List<HomeBlock> sse_decode_list_home_block(SseDeserializer deserializer);
 
+  @protected
+  List<Message> sse_decode_list_message(SseDeserializer deserializer);
+
+  @protected
+  List<MirrorProbe> sse_decode_list_mirror_probe(SseDeserializer deserializer);
+
   @protected
   List<NovelCover> sse_decode_list_novel_cover(SseDeserializer deserializer);
 
@@ -256,6 +465,17 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  List<NovelSourceInfo> sse_decode_list_novel_source_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<NovelUpdate> sse_decode_list_novel_update(SseDeserializer deserializer);
+
+  @protected
+  Int64List sse_decode_list_prim_i_64_strict(SseDeserializer deserializer);
+
   @protected
   Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
 
@@ -264,9 +484,20 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  List<RecommendLog> sse_decode_list_recommend_log(
+    SseDeserializer deserializer,
+  );
+
   @protected
   List<Review> sse_decode_list_review(SseDeserializer deserializer);
 
+  @protected
+  List<ReviewBlock> sse_decode_list_review_block(SseDeserializer deserializer);
+
+  @protected
+  List<ReviewPost> sse_decode_list_review_post(SseDeserializer deserializer);
+
   @protected
   List<SearchHistory> sse_decode_list_search_history(
     SseDeserializer deserializer,
@@ -278,6 +509,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<Volume> sse_decode_list_volume(SseDeserializer deserializer);
 
+  @protected
+  Message sse_decode_message(SseDeserializer deserializer);
+
+  @protected
+  MessageDetail sse_decode_message_detail(SseDeserializer deserializer);
+
+  @protected
+  MirrorProbe sse_decode_mirror_probe(SseDeserializer deserializer);
+
   @protected
   Novel sse_decode_novel(SseDeserializer deserializer);
 
@@ -300,16 +540,39 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   NovelInfo sse_decode_novel_info(SseDeserializer deserializer);
 
+  @protected
+  NovelSourceInfo sse_decode_novel_source_info(SseDeserializer deserializer);
+
+  @protected
+  NovelUpdate sse_decode_novel_update(SseDeserializer deserializer);
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer);
+
+  @protected
+  Account? sse_decode_opt_box_autoadd_account(SseDeserializer deserializer);
+
   @protected
   ExistsDownload? sse_decode_opt_box_autoadd_exists_download(
     SseDeserializer deserializer,
   );
 
+  @protected
+  int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer);
+
   @protected
   ReadingHistory? sse_decode_opt_box_autoadd_reading_history(
     SseDeserializer deserializer,
   );
 
+  @protected
+  Wenku8ErrorInfo? sse_decode_opt_box_autoadd_wenku_8_error_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  PageStatsMessage sse_decode_page_stats_message(SseDeserializer deserializer);
+
   @protected
   PageStatsNovelCover sse_decode_page_stats_novel_cover(
     SseDeserializer deserializer,
@@ -318,12 +581,33 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   PageStatsReviews sse_decode_page_stats_reviews(SseDeserializer deserializer);
 
+  @protected
+  ProxySettings sse_decode_proxy_settings(SseDeserializer deserializer);
+
+  @protected
+  ProxyTestResult sse_decode_proxy_test_result(SseDeserializer deserializer);
+
   @protected
   ReadingHistory sse_decode_reading_history(SseDeserializer deserializer);
 
+  @protected
+  RecommendLog sse_decode_recommend_log(SseDeserializer deserializer);
+
   @protected
   Review sse_decode_review(SseDeserializer deserializer);
 
+  @protected
+  ReviewBlock sse_decode_review_block(SseDeserializer deserializer);
+
+  @protected
+  ReviewBlockKind sse_decode_review_block_kind(SseDeserializer deserializer);
+
+  @protected
+  ReviewDetail sse_decode_review_detail(SseDeserializer deserializer);
+
+  @protected
+  ReviewPost sse_decode_review_post(SseDeserializer deserializer);
+
   @protected
   SearchHistory sse_decode_search_history(SseDeserializer deserializer);
 
@@ -339,18 +623,42 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   UserDetail sse_decode_user_detail(SseDeserializer deserializer);
 
+  @protected
+  VaultState sse_decode_vault_state(SseDeserializer deserializer);
+
   @protected
   Volume sse_decode_volume(SseDeserializer deserializer);
 
+  @protected
+  Wenku8ErrorInfo sse_decode_wenku_8_error_info(SseDeserializer deserializer);
+
+  @protected
+  Wenku8ErrorKind sse_decode_wenku_8_error_kind(SseDeserializer deserializer);
+
   @protected
   void sse_encode_AnyhowException(
     AnyhowException self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_StreamSink_String_Sse(
+    RustStreamSink<String> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_download_event_Sse(
+    RustStreamSink<DownloadEvent> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_String(String self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_account(Account self, SseSerializer serializer);
+
   @protected
   void sse_encode_bookcase(Bookcase self, SseSerializer serializer);
 
@@ -366,27 +674,81 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_bool(bool self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_account(Account self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_exists_download(
     ExistsDownload self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_proxy_settings(
+    ProxySettings self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_box_autoadd_reading_history(
     ReadingHistory self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_box_autoadd_wenku_8_error_info(
+    Wenku8ErrorInfo self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_chapter(Chapter self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_chapter_document(
+    ChapterDocument self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_chapter_segment(
+    ChapterSegment self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_chapter_segment_kind(
+    ChapterSegmentKind self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_cookie_file_format(
+    CookieFileFormat self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_download_event(DownloadEvent self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_download_event_kind(
+    DownloadEventKind self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_exists_download(
     ExistsDownload self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_f_64(double self, SseSerializer serializer);
+
   @protected
   void sse_encode_home_block(HomeBlock self, SseSerializer serializer);
 
@@ -399,6 +761,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_list_String(List<String> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_account(List<Account> self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_bookcase(List<Bookcase> self, SseSerializer serializer);
 
@@ -417,12 +782,27 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_list_chapter(List<Chapter> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_chapter_segment(
+    List<ChapterSegment> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_home_block(
     List<HomeBlock> self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_message(List<Message> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_mirror_probe(
+    List<MirrorProbe> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_novel_cover(
     List<NovelCover> self,
@@ -447,6 +827,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_novel_source_info(
+    List<NovelSourceInfo> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_novel_update(
+    List<NovelUpdate> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_prim_i_64_strict(
+    Int64List self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_prim_u_8_strict(
     Uint8List self,
@@ -459,9 +857,27 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_recommend_log(
+    List<RecommendLog> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_review(List<Review> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_review_block(
+    List<ReviewBlock> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_review_post(
+    List<ReviewPost> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_search_history(
     List<SearchHistory> self,
@@ -474,6 +890,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_list_volume(List<Volume> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_message(Message self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_message_detail(MessageDetail self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_mirror_probe(MirrorProbe self, SseSerializer serializer);
+
   @protected
   void sse_encode_novel(Novel self, SseSerializer serializer);
 
@@ -498,18 +923,51 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_novel_info(NovelInfo self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_novel_source_info(
+    NovelSourceInfo self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_novel_update(NovelUpdate self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_String(String? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_account(
+    Account? self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_opt_box_autoadd_exists_download(
     ExistsDownload? self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_box_autoadd_reading_history(
     ReadingHistory? self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_opt_box_autoadd_wenku_8_error_info(
+    Wenku8ErrorInfo? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_page_stats_message(
+    PageStatsMessage self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_page_stats_novel_cover(
     PageStatsNovelCover self,
@@ -522,15 +980,42 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_proxy_settings(ProxySettings self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_proxy_test_result(
+    ProxyTestResult self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_reading_history(
     ReadingHistory self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_recommend_log(RecommendLog self, SseSerializer serializer);
+
   @protected
   void sse_encode_review(Review self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_review_block(ReviewBlock self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_review_block_kind(
+    ReviewBlockKind self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_review_detail(ReviewDetail self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_review_post(ReviewPost self, SseSerializer serializer);
+
   @protected
   void sse_encode_search_history(SearchHistory self, SseSerializer serializer);
 
@@ -546,8 +1031,23 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_user_detail(UserDetail self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_vault_state(VaultState self, SseSerializer serializer);
+
   @protected
   void sse_encode_volume(Volume self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_wenku_8_error_info(
+    Wenku8ErrorInfo self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_wenku_8_error_kind(
+    Wenku8ErrorKind self,
+    SseSerializer serializer,
+  );
 }
 
 // Section: wire_class
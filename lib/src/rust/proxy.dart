@@ -0,0 +1,69 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// 代理设置，url 支持 http、https、socks5、socks5h
+/// bypass 为不走代理的域名或 IP，例如 localhost、*.example.com、192.168.0.0/16
+class ProxySettings {
+  final bool enabled;
+  final String url;
+  final String username;
+  final String password;
+  final List<String> bypass;
+
+  const ProxySettings({
+    required this.enabled,
+    required this.url,
+    required this.username,
+    required this.password,
+    required this.bypass,
+  });
+
+  @override
+  int get hashCode =>
+      enabled.hashCode ^
+      url.hashCode ^
+      username.hashCode ^
+      password.hashCode ^
+      bypass.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ProxySettings &&
+          runtimeType == other.runtimeType &&
+          enabled == other.enabled &&
+          url == other.url &&
+          username == other.username &&
+          password == other.password &&
+          bypass == other.bypass;
+}
+
+/// 代理测试结果，latency_ms 在失败时为 -1
+class ProxyTestResult {
+  final bool success;
+  final PlatformInt64 latencyMs;
+  final String error;
+
+  const ProxyTestResult({
+    required this.success,
+    required this.latencyMs,
+    required this.error,
+  });
+
+  @override
+  int get hashCode => success.hashCode ^ latencyMs.hashCode ^ error.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ProxyTestResult &&
+          runtimeType == other.runtimeType &&
+          success == other.success &&
+          latencyMs == other.latencyMs &&
+          error == other.error;
+}
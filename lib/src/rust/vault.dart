@@ -0,0 +1,25 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+class VaultState {
+  final bool enabled;
+  final bool unlocked;
+
+  const VaultState({required this.enabled, required this.unlocked});
+
+  @override
+  int get hashCode => enabled.hashCode ^ unlocked.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is VaultState &&
+          runtimeType == other.runtimeType &&
+          enabled == other.enabled &&
+          unlocked == other.unlocked;
+}
@@ -0,0 +1,36 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// 镜像测速结果，latency_ms 在不可用时为 -1
+class MirrorProbe {
+  final String host;
+  final bool reachable;
+  final PlatformInt64 latencyMs;
+  final String error;
+
+  const MirrorProbe({
+    required this.host,
+    required this.reachable,
+    required this.latencyMs,
+    required this.error,
+  });
+
+  @override
+  int get hashCode =>
+      host.hashCode ^ reachable.hashCode ^ latencyMs.hashCode ^ error.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MirrorProbe &&
+          runtimeType == other.runtimeType &&
+          host == other.host &&
+          reachable == other.reachable &&
+          latencyMs == other.latencyMs &&
+          error == other.error;
+}
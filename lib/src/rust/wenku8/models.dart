@@ -145,6 +145,85 @@ class HomeBlock {
           list == other.list;
 }
 
+/// 收件箱或发件箱中的一条消息，user 为对方（收件箱是发件人，发件箱是收件人）
+class Message {
+  final String id;
+  final String title;
+  final String userId;
+  final String username;
+  final String time;
+  final bool unread;
+
+  const Message({
+    required this.id,
+    required this.title,
+    required this.userId,
+    required this.username,
+    required this.time,
+    required this.unread,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      title.hashCode ^
+      userId.hashCode ^
+      username.hashCode ^
+      time.hashCode ^
+      unread.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Message &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          title == other.title &&
+          userId == other.userId &&
+          username == other.username &&
+          time == other.time &&
+          unread == other.unread;
+}
+
+class MessageDetail {
+  final String id;
+  final String title;
+  final String sender;
+  final String receiver;
+  final String time;
+  final String content;
+
+  const MessageDetail({
+    required this.id,
+    required this.title,
+    required this.sender,
+    required this.receiver,
+    required this.time,
+    required this.content,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      title.hashCode ^
+      sender.hashCode ^
+      receiver.hashCode ^
+      time.hashCode ^
+      content.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MessageDetail &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          title == other.title &&
+          sender == other.sender &&
+          receiver == other.receiver &&
+          time == other.time &&
+          content == other.content;
+}
+
 class Novel {
   final String id;
   final String title;
@@ -306,6 +385,111 @@ class Review {
           time == other.time;
 }
 
+/// 帖子正文按块拆分，不返回 HTML；Text 和 Quote 只有 text，Image 只有 url，Link 两者都有
+class ReviewBlock {
+  final ReviewBlockKind kind;
+  final String text;
+  final String url;
+
+  const ReviewBlock({
+    required this.kind,
+    required this.text,
+    required this.url,
+  });
+
+  @override
+  int get hashCode => kind.hashCode ^ text.hashCode ^ url.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ReviewBlock &&
+          runtimeType == other.runtimeType &&
+          kind == other.kind &&
+          text == other.text &&
+          url == other.url;
+}
+
+enum ReviewBlockKind {
+  text,
+  quote,
+  image,
+  link,
+  ;
+}
+
+/// 书评帖子的一页
+class ReviewDetail {
+  final String rid;
+  final String title;
+  final int currentPage;
+  final int maxPage;
+  final List<ReviewPost> posts;
+
+  const ReviewDetail({
+    required this.rid,
+    required this.title,
+    required this.currentPage,
+    required this.maxPage,
+    required this.posts,
+  });
+
+  @override
+  int get hashCode =>
+      rid.hashCode ^
+      title.hashCode ^
+      currentPage.hashCode ^
+      maxPage.hashCode ^
+      posts.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ReviewDetail &&
+          runtimeType == other.runtimeType &&
+          rid == other.rid &&
+          title == other.title &&
+          currentPage == other.currentPage &&
+          maxPage == other.maxPage &&
+          posts == other.posts;
+}
+
+/// 书评中的一楼
+class ReviewPost {
+  final String uid;
+  final String uname;
+  final String time;
+  final int floor;
+  final List<ReviewBlock> blocks;
+
+  const ReviewPost({
+    required this.uid,
+    required this.uname,
+    required this.time,
+    required this.floor,
+    required this.blocks,
+  });
+
+  @override
+  int get hashCode =>
+      uid.hashCode ^
+      uname.hashCode ^
+      time.hashCode ^
+      floor.hashCode ^
+      blocks.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ReviewPost &&
+          runtimeType == other.runtimeType &&
+          uid == other.uid &&
+          uname == other.uname &&
+          time == other.time &&
+          floor == other.floor &&
+          blocks == other.blocks;
+}
+
 class TagGroup {
   final String title;
   final List<String> tags;
@@ -0,0 +1,244 @@
+//! 多账号：每个账号的 cookie 分开保存，请求时使用当前账号的 cookie
+//! 账号 0 为未绑定账号，游客和旧版本登录的 cookie 都在 0 下，登录成功后转到对应的账号
+
+use crate::api::database::{load_property, save_property};
+use crate::database::entities::{Account, AccountEntity, Cookie};
+use crate::wenku8::mirror::normalize_host;
+use crate::wenku8::{UserDetail, Wenku8Error};
+use crate::{Result, CLIENT, COOKIE_STORE};
+use anyhow::anyhow;
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, AtomicI64, Ordering};
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tokio::time::Instant;
+use tracing::{info, warn};
+
+/// 当前账号，cookie store 在同步代码中读取，所以用原子变量
+static ACTIVE_ACCOUNT: AtomicI64 = AtomicI64::new(0);
+/// 阅读记录和签到记录是否按账号区分
+static SCOPED_HISTORY: AtomicBool = AtomicBool::new(false);
+
+pub(crate) fn active_account_id() -> i64 {
+    ACTIVE_ACCOUNT.load(Ordering::Relaxed)
+}
+
+/// 按账号区分记录时返回当前账号，否则返回 None 表示所有账号
+pub(crate) fn history_account_id() -> Option<i64> {
+    SCOPED_HISTORY
+        .load(Ordering::Relaxed)
+        .then(active_account_id)
+}
+
+pub(crate) fn is_scoped_history() -> bool {
+    SCOPED_HISTORY.load(Ordering::Relaxed)
+}
+
+pub(crate) async fn init_account() -> Result<()> {
+    let id = load_property("active_account".to_string())
+        .await?
+        .parse::<i64>()
+        .unwrap_or(0);
+    // 账号可能已经被删除
+    let id = match AccountEntity::find_account(id).await? {
+        Some(_) => id,
+        None => 0,
+    };
+    ACTIVE_ACCOUNT.store(id, Ordering::Relaxed);
+    let scoped = load_property("account_scoped_history".to_string()).await?;
+    SCOPED_HISTORY.store(scoped == "true", Ordering::Relaxed);
+    Ok(())
+}
+
+async fn set_active_account(id: i64) -> Result<()> {
+    save_property("active_account".to_string(), id.to_string()).await?;
+    ACTIVE_ACCOUNT.store(id, Ordering::Relaxed);
+    info!(account_id = id, "Active account changed");
+    Ok(())
+}
+
+pub(crate) async fn set_scoped_history(scoped: bool) -> Result<()> {
+    save_property("account_scoped_history".to_string(), scoped.to_string()).await?;
+    SCOPED_HISTORY.store(scoped, Ordering::Relaxed);
+    Ok(())
+}
+
+pub(crate) async fn list_accounts() -> Result<Vec<Account>> {
+    AccountEntity::list_all().await
+}
+
+/// 未绑定账号时返回 None
+pub(crate) async fn active_account() -> Result<Option<Account>> {
+    AccountEntity::find_account(active_account_id()).await
+}
+
+pub(crate) async fn switch_account(id: i64) -> Result<()> {
+    if AccountEntity::find_account(id).await?.is_none() {
+        return Err(anyhow!("Account not found: {}", id));
+    }
+    set_active_account(id).await
+}
+
+/// 切换到空的未绑定账号，之后登录的账号会被添加
+/// 未绑定账号下原有的 cookie 会被清除
+pub(crate) async fn add_account() -> Result<()> {
+    COOKIE_STORE.delete_account(0);
+    COOKIE_STORE.flush().await?;
+    set_active_account(0).await
+}
+
+/// 删除账号和它的 cookie 以及保存的密码，删除当前账号时切换到未绑定账号
+pub(crate) async fn remove_account(id: i64) -> Result<()> {
+    if let Some(account) = AccountEntity::find_account(id).await? {
+        crate::vault::forget(&account.username).await?;
+    }
+    COOKIE_STORE.delete_account(id);
+    COOKIE_STORE.flush().await?;
+    AccountEntity::delete_account(id).await?;
+    if id == active_account_id() {
+        set_active_account(0).await?;
+    }
+    Ok(())
+}
+
+/// 登录成功后调用，把这次登录得到的 cookie 归到 username 的账号并切换过去
+/// 同一个账号再次登录时替换掉它原来的 cookie
+pub(crate) async fn on_login(username: &str) -> Result<()> {
+    let account = AccountEntity::login(username.trim()).await?;
+    let current = active_account_id();
+    if account.id != current {
+        COOKIE_STORE.move_account(current, account.id);
+        COOKIE_STORE.flush().await?;
+        set_active_account(account.id).await?;
+    }
+    Ok(())
+}
+
+/// 接口地址和所有镜像的域名
+async fn wenku8_hosts() -> Vec<String> {
+    let mut hosts = CLIENT.load_mirror_hosts().await;
+    hosts.push(CLIENT.load_api_host().await);
+    hosts
+        .iter()
+        .filter_map(|host| {
+            let url = reqwest::Url::parse(&normalize_host(host)).ok()?;
+            Some(url.host_str()?.to_ascii_lowercase())
+        })
+        .collect()
+}
+
+/// 导入的 cookie 先放到清空的未绑定账号 (0) 中，读取用户信息验证通过后再归到对应的账号
+/// 验证失败时恢复未绑定账号原来的 cookie 并切换回原来的账号，原来账号的 cookie 不受影响
+pub(crate) async fn import_cookies(cookies: Vec<Cookie>) -> Result<UserDetail> {
+    let previous = active_account_id();
+    let backup = COOKIE_STORE.export_cookies(0);
+    // 沿用原来账号的 Cloudflare 验证，导入的同名 cookie 会覆盖它
+    let clearance = COOKIE_STORE
+        .export_cookies(previous)
+        .into_iter()
+        .filter(|cookie| cookie.name == "cf_clearance")
+        .map(|cookie| Cookie {
+            account_id: 0,
+            ..cookie
+        })
+        .collect::<Vec<_>>();
+    COOKIE_STORE.delete_account(0);
+    COOKIE_STORE.restore_cookies(clearance);
+    if COOKIE_STORE.import_cookies(0, &wenku8_hosts().await, cookies) == 0 {
+        rollback_import(previous, backup).await?;
+        return Err(anyhow!("No wenku8 cookies found"));
+    }
+    set_active_account(0).await?;
+    let user_detail = match CLIENT.userdetail().await {
+        Ok(user_detail) if !user_detail.username.trim().is_empty() => user_detail,
+        Ok(_) => {
+            rollback_import(previous, backup).await?;
+            return Err(Wenku8Error::NotLoggedIn.into());
+        }
+        Err(e) => {
+            rollback_import(previous, backup).await?;
+            return Err(e);
+        }
+    };
+    on_login(&user_detail.username).await?;
+    // cookie 已经转到登录的账号，账号 0 原来的 cookie 放回去
+    COOKIE_STORE.restore_cookies(backup);
+    COOKIE_STORE.flush().await?;
+    Ok(user_detail)
+}
+
+async fn rollback_import(previous: i64, backup: Vec<Cookie>) -> Result<()> {
+    COOKIE_STORE.delete_account(0);
+    COOKIE_STORE.restore_cookies(backup);
+    COOKIE_STORE.flush().await?;
+    if active_account_id() != previous {
+        set_active_account(previous).await?;
+    }
+    Ok(())
+}
+
+pub(crate) async fn is_logged_in() -> Result<bool> {
+    Ok(COOKIE_STORE.has_cookie(active_account_id(), "jieqiUserInfo"))
+}
+
+/// 退出当前账号，只清除它的 cookie，账号保留在列表中
+pub(crate) async fn logout() -> Result<()> {
+    COOKIE_STORE.delete_account(active_account_id());
+    COOKIE_STORE.flush().await
+}
+
+/// 并发的请求同时发现登录过期时只重新登录一次，这段时间内登录过的账号直接重试
+const RELOGIN_INTERVAL: Duration = Duration::from_secs(30);
+/// 账号 id -> 上次重新登录的时间
+static LAST_RELOGIN: Lazy<Mutex<HashMap<i64, Instant>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// 用保险箱中的密码重新登录当前账号，没有保存密码时返回 false
+/// 服务器要求校验码时返回 Wenku8Error::CaptchaRequired
+async fn relogin() -> Result<bool> {
+    let mut last = LAST_RELOGIN.lock().await;
+    let Some(account) = active_account().await? else {
+        return Ok(false);
+    };
+    if last
+        .get(&account.id)
+        .is_some_and(|last| last.elapsed() < RELOGIN_INTERVAL)
+    {
+        return Ok(true);
+    }
+    let Some(password) = crate::vault::password(&account.username).await? else {
+        return Ok(false);
+    };
+    info!(username = %account.username, "Session expired, logging in again");
+    match CLIENT.login(&account.username, &password, "").await {
+        Ok(()) => {
+            on_login(&account.username).await?;
+            last.insert(account.id, Instant::now());
+            Ok(true)
+        }
+        Err(e) if Wenku8Error::of(&e) == Some(Wenku8Error::CaptchaWrong) => {
+            warn!(username = %account.username, "Login again requires captcha");
+            Err(Wenku8Error::CaptchaRequired.into())
+        }
+        Err(e) => Err(e),
+    }
+}
+
+/// 执行需要登录的请求，登录过期时自动重新登录后重试一次
+pub(crate) async fn with_relogin<T, F, Fut>(f: F) -> Result<T>
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    match f().await {
+        Err(e) if Wenku8Error::of(&e) == Some(Wenku8Error::NotLoggedIn) => {
+            if relogin().await? {
+                f().await
+            } else {
+                Err(e)
+            }
+        }
+        result => result,
+    }
+}
@@ -1,4 +1,5 @@
 pub mod database;
 pub mod simple;
+pub mod source;
 pub mod system;
 pub mod wenku8;
@@ -0,0 +1,357 @@
+use super::wenku8::{PageStatsNovelCover, ReadingHistory};
+use crate::chapter_document::ChapterDocument;
+use crate::database::entities::{
+    active::{
+        novel_download, novel_download_chapter, novel_download_volume, reading_history,
+        DOWNLOAD_STATUS_NOT_DOWNLOAD,
+    },
+    ReadingHistoryEntity,
+};
+use crate::downloading::RESTART_FLAG;
+use crate::source::{all_sources, source, source_key};
+use crate::wenku8::{BookshelfItem, HomeBlock, NovelInfo, Volume};
+use crate::zh_convert::to_display;
+use crate::CLIENT;
+use std::time::Duration;
+
+pub struct NovelSourceInfo {
+    pub id: String,
+    pub name: String,
+}
+
+pub fn list_sources() -> Vec<NovelSourceInfo> {
+    all_sources()
+        .iter()
+        .map(|source| NovelSourceInfo {
+            id: source.id().to_string(),
+            name: source.name().to_string(),
+        })
+        .collect()
+}
+
+pub async fn source_index(source_id: String) -> anyhow::Result<Vec<HomeBlock>> {
+    let key = CLIENT.language_key(&source_key(&source_id, "INDEX_DATA")).await;
+    crate::cache_first(
+        key,
+        Duration::from_secs(60 * 10),
+        Box::pin(async move { source(&source_id)?.index().await }),
+    )
+    .await
+}
+
+pub async fn source_download_image(source_id: String, url: String) -> anyhow::Result<String> {
+    crate::get_cached_image(&source_id, url).await
+}
+
+pub async fn source_chapter_content(
+    source_id: String,
+    aid: String,
+    cid: String,
+) -> anyhow::Result<String> {
+    let content = crate::get_chapter_content(&source_id, &aid, &cid).await?;
+
+    // 处理内容中的空白字符
+    let processed_content = content
+        .lines() // 按行分割
+        .filter(|line| !line.trim().is_empty()) // 移除空行
+        .collect::<Vec<&str>>()
+        .join("\n") // 重新组合，每行之间用单个换行符连接
+        .replace(|c: char| c.is_whitespace() && c != '\n', " "); // 将其他空白字符替换为空格
+
+    // 将连续的两个以上换行符替换为两个换行符
+    let mut result = String::new();
+    let mut newline_count = 0;
+
+    for c in processed_content.chars() {
+        if c == '\n' {
+            newline_count += 1;
+            if newline_count <= 2 {
+                result.push(c);
+            }
+        } else {
+            if newline_count > 2 {
+                // 如果之前有超过两个换行符，添加两个换行符
+                result.push('\n');
+                result.push('\n');
+            }
+            newline_count = 0;
+            result.push(c);
+        }
+    }
+
+    Ok(result)
+}
+
+pub async fn source_chapter_document(
+    source_id: String,
+    aid: String,
+    cid: String,
+) -> anyhow::Result<ChapterDocument> {
+    let content = crate::get_chapter_content(&source_id, &aid, &cid).await?;
+    crate::chapter_document::build_document(&aid, &cid, &content).await
+}
+
+/// 小说详情，标题和简介按阅读设置转换简繁
+pub async fn source_novel_info(source_id: String, aid: String) -> anyhow::Result<NovelInfo> {
+    let mut novel_info = cached_novel_info(source_id, aid).await?;
+    let language = CLIENT.load_language().await;
+    novel_info.title = to_display(novel_info.title, language).await;
+    novel_info.introduce = to_display(novel_info.introduce, language).await;
+    Ok(novel_info)
+}
+
+// 服务器返回的原文，下载记录保存原文
+async fn cached_novel_info(source_id: String, aid: String) -> anyhow::Result<NovelInfo> {
+    let key = source_key(&source_id, &format!("NOVEL_INFO${}", aid));
+    let key = CLIENT.language_key(&key).await;
+    crate::cache_first(
+        key,
+        Duration::from_secs(60 * 60),
+        Box::pin(async move { source(&source_id)?.novel_info(&aid).await }),
+    )
+    .await
+}
+
+pub async fn source_novel_reader(source_id: String, aid: String) -> anyhow::Result<Vec<Volume>> {
+    let key = source_key(&source_id, &format!("NOVEL_READER${}", aid));
+    let key = CLIENT.language_key(&key).await;
+    crate::cache_first(
+        key,
+        Duration::from_secs(60 * 60),
+        Box::pin(async move { source(&source_id)?.novel_reader(&aid).await }),
+    )
+    .await
+}
+
+pub async fn source_bookshelf(source_id: String) -> anyhow::Result<Vec<BookshelfItem>> {
+    source(&source_id)?.bookshelf().await
+}
+
+/// 保存阅读进度，last_read_at 忽略，使用保存时的时间
+pub async fn source_update_history(history: ReadingHistory) -> anyhow::Result<()> {
+    ReadingHistoryEntity::upsert(reading_history::Model {
+        account_id: crate::account::active_account_id(),
+        novel_id: history.novel_id,
+        novel_name: history.novel_name,
+        volume_id: history.volume_id,
+        volume_name: history.volume_name,
+        chapter_id: history.chapter_id,
+        chapter_title: history.chapter_title,
+        last_read_at: history.last_read_at,
+        progress: history.progress,
+        progress_page: history.progress_page,
+        cover: history.cover,
+        author: history.author,
+        source_id: history.source_id,
+    })
+    .await?;
+    ReadingHistoryEntity::delete_old_records().await?;
+    Ok(())
+}
+
+pub async fn source_search(
+    source_id: String,
+    search_type: String,
+    search_key: String,
+    page: i32,
+) -> anyhow::Result<PageStatsNovelCover> {
+    crate::database::entities::active::search_history::Entity::save_or_update(
+        search_type.clone(),
+        search_key.clone(),
+    )
+    .await?;
+    crate::database::entities::active::search_history::Entity::delete_old_records().await?;
+    let key = CLIENT
+        .language_key(&source_key(
+            &source_id,
+            &format!("SEARCH${}${}${}", search_type, search_key, page),
+        ))
+        .await;
+    let data = crate::cache_first(
+        key,
+        Duration::from_secs(60 * 60),
+        Box::pin(async move {
+            source(&source_id)?
+                .search(&search_type, &search_key, page)
+                .await
+        }),
+    )
+    .await?;
+    Ok(PageStatsNovelCover {
+        current_page: data.current_page,
+        max_page: data.max_page,
+        records: data.records,
+    })
+}
+
+pub async fn source_download_novel(
+    source_id: String,
+    aid: String,
+    cid_list: Vec<String>,
+) -> anyhow::Result<()> {
+    let novel_detail = cached_novel_info(source_id.clone(), aid.clone()).await?;
+    let volumes = source_novel_reader(source_id.clone(), aid.clone()).await?;
+    // 按当前语言下载，简繁两个版本分开保存
+    let language = CLIENT.load_language().await.code();
+    let mut cid_list = cid_list;
+    if cid_list.is_empty() {
+        cid_list = volumes
+            .iter()
+            .flat_map(|v| v.chapters.iter().map(|c| c.cid.clone()))
+            .collect();
+    }
+
+    // 1. 先处理章节信息
+    for volume in &volumes {
+        let volume_id = volume.id.clone();
+        for chapter in &volume.chapters {
+            let chapter_id = chapter.cid.clone();
+
+            // 检查章节是否在下载列表中
+            if cid_list.contains(&chapter_id) {
+                // 检查章节是否已存在，已存在则跳过
+                if novel_download_chapter::Entity::find_by_id(&source_id, language, &chapter_id)
+                    .await?
+                    .is_some()
+                {
+                    continue;
+                }
+
+                // 章节不存在，插入新章节
+                let chapter_title = chapter.title.clone();
+                let chapter_url = chapter.url.clone();
+                let chapter_idx = volume
+                    .chapters
+                    .iter()
+                    .position(|c| c.cid == chapter.cid)
+                    .unwrap_or(0) as i32;
+
+                novel_download_chapter::Entity::upsert(
+                    &source_id,
+                    language,
+                    &chapter_id,
+                    &chapter_title,
+                    &chapter_url,
+                    &aid,
+                    &volume_id,
+                    DOWNLOAD_STATUS_NOT_DOWNLOAD,
+                    0, // 总图片数初始为0
+                    chapter_idx,
+                )
+                .await?;
+            }
+        }
+    }
+
+    // 2. 处理卷信息
+    for (volume_idx, volume) in volumes.iter().enumerate() {
+        let volume_id = volume.id.clone();
+
+        // 检查卷是否已存在
+        if novel_download_volume::Entity::find_by_id(&source_id, language, &volume_id)
+            .await?
+            .is_some()
+        {
+            // 如果卷已存在，重置下载状态
+            novel_download_volume::Entity::update_download_status(
+                &source_id,
+                language,
+                &volume_id,
+                DOWNLOAD_STATUS_NOT_DOWNLOAD,
+            )
+            .await?;
+        } else {
+            // 卷不存在，插入新卷
+            let volume_title = volume.title.clone();
+            novel_download_volume::Entity::upsert(
+                &source_id,
+                language,
+                &volume_id,
+                &aid,
+                volume_idx as i32,
+                &volume_title,
+                DOWNLOAD_STATUS_NOT_DOWNLOAD,
+            )
+            .await?;
+        }
+    }
+
+    // 3. 最后处理小说本体
+    let novel_id = aid.clone();
+
+    // 检查小说是否已存在
+    if novel_download::Entity::find_by_novel_id(&source_id, language, &novel_id)
+        .await?
+        .is_some()
+    {
+        // 如果小说已存在，更新信息并重置下载状态
+        let novel_name = novel_detail.title.clone();
+        let cover_url = novel_detail.img_url.clone();
+        let author = novel_detail.author.clone();
+        let tags = novel_detail.tags.join(",");
+        let introduce = novel_detail.introduce.clone();
+        let trending = novel_detail.trending.clone();
+        let is_animated = novel_detail.is_animated;
+        let fin_update = novel_detail.fin_update.clone();
+        let status = novel_detail.status.clone();
+        let choose_chapter_count = cid_list.len() as i32;
+
+        novel_download::Entity::upsert(
+            &source_id,
+            language,
+            &novel_id,
+            &novel_name,
+            DOWNLOAD_STATUS_NOT_DOWNLOAD,
+            &cover_url,
+            DOWNLOAD_STATUS_NOT_DOWNLOAD,
+            &author,
+            &tags,
+            choose_chapter_count,
+            0, // 已下载章节数重置为0
+            &introduce,
+            &trending,
+            is_animated,
+            &fin_update,
+            &status,
+        )
+        .await?;
+    } else {
+        // 小说不存在，插入新小说
+        let novel_name = novel_detail.title.clone();
+        let cover_url = novel_detail.img_url.clone();
+        let author = novel_detail.author.clone();
+        let tags = novel_detail.tags.join(",");
+        let introduce = novel_detail.introduce.clone();
+        let trending = novel_detail.trending.clone();
+        let is_animated = novel_detail.is_animated;
+        let fin_update = novel_detail.fin_update.clone();
+        let status = novel_detail.status.clone();
+        let choose_chapter_count = cid_list.len() as i32;
+
+        novel_download::Entity::upsert(
+            &source_id,
+            language,
+            &novel_id,
+            &novel_name,
+            DOWNLOAD_STATUS_NOT_DOWNLOAD,
+            &cover_url,
+            DOWNLOAD_STATUS_NOT_DOWNLOAD,
+            &author,
+            &tags,
+            choose_chapter_count,
+            0,
+            &introduce,
+            &trending,
+            is_animated,
+            &fin_update,
+            &status,
+        )
+        .await?;
+    }
+
+    // 设置重启标志
+    let mut restart_flag = RESTART_FLAG.lock().await;
+    *restart_flag = true;
+
+    Ok(())
+}
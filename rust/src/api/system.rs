@@ -49,3 +49,11 @@ pub async fn init(root: String) -> Result<()> {
     crate::init(root).await?;
     Ok(())
 }
+
+/// 初始化并用平台提供的密钥（例如 Android Keystore 中保存的随机值）解锁密码保险箱
+/// 保险箱未开启时与 init 相同
+pub async fn init_with_secret(root: String, secret: String) -> Result<()> {
+    crate::init(root).await?;
+    crate::vault::unlock_vault(&secret).await?;
+    Ok(())
+}
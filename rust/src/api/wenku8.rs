@@ -1,7 +1,14 @@
-use crate::downloading::RESTART_FLAG;
+use crate::account::with_relogin;
+use crate::chapter_document::ChapterDocument;
+use crate::cookie_file::CookieFileFormat;
+use crate::downloading::{DownloadEvent, RESTART_FLAG};
+use crate::frb_generated::StreamSink;
+use crate::proxy::{ProxySettings, ProxyTestResult};
+use crate::vault::VaultState;
+use crate::source::DEFAULT_SOURCE_ID;
 use crate::wenku8::{
-    Bookcase, BookcaseItem, BookshelfItem, HomeBlock, Novel, NovelCover, NovelInfo, PageStats,
-    TagGroup, UserDetail, Volume,
+    Bookcase, BookcaseItem, BookshelfItem, HomeBlock, Message, MessageDetail, MirrorProbe, Novel,
+    NovelCover, NovelInfo, TagGroup, UserDetail, Volume, Wenku8Error,
 };
 use crate::Result;
 use crate::CLIENT;
@@ -9,12 +16,12 @@ use crate::{
     database::entities::{
         active::{
             novel_download, novel_download_chapter, novel_download_volume,
-            DOWNLOAD_STATUS_DELETING, DOWNLOAD_STATUS_NOT_DOWNLOAD,
+            DOWNLOAD_STATUS_DELETING,
         },
-        CookieEntity, ReadingHistoryEntity, SignLogEntity,
+        NovelUpdateEntity, ReadingHistoryEntity, RecommendLogEntity, SignLogEntity,
     },
     downloading,
-    wenku8::{BookcaseDto, Review},
+    wenku8::{BookcaseDto, Review, ReviewDetail},
 };
 use anyhow::Ok;
 use sea_orm::{ColumnTrait, EntityTrait, QueryOrder};
@@ -23,99 +30,154 @@ use std::time::Duration;
 
 #[flutter_rust_bridge::frb]
 pub async fn wenku8_login(username: String, password: String, checkcode: String) -> Result<()> {
-    CLIENT.login(&username, &password, &checkcode).await
+    CLIENT.login(&username, &password, &checkcode).await?;
+    crate::account::on_login(&username).await?;
+    crate::vault::remember(&username, &password).await
 }
 
 #[flutter_rust_bridge::frb]
 pub async fn wenku8_get_bookshelf() -> Result<Vec<BookshelfItem>> {
-    CLIENT.get_bookshelf().await
+    with_relogin(|| CLIENT.get_bookshelf()).await
 }
 
+/// 通过 app 接口登录，不需要验证码
+pub async fn app_login(username: String, password: String) -> Result<()> {
+    CLIENT.app_login(&username, &password).await?;
+    crate::account::on_login(&username).await?;
+    crate::vault::remember(&username, &password).await
+}
+
+/// 当前账号是否已登录
 pub async fn pre_login_state() -> Result<bool> {
-    let logged = crate::database::entities::CookieEntity::exists("jieqiUserInfo").await?;
-    Ok(logged)
+    crate::account::is_logged_in().await
 }
 
+/// 退出当前账号
 pub async fn logout() -> Result<()> {
-    CookieEntity::delete_all().await?;
+    crate::account::logout().await
+}
+
+/// 应用退到后台或退出前调用，把还没写入的 cookie 写入数据库
+pub async fn flush_cookies() -> Result<()> {
+    crate::COOKIE_STORE.flush().await
+}
+
+/// 导入浏览器导出的 cookie（只保留文库的），读取用户信息验证登录状态
+/// 验证成功后 cookie 归到该用户的账号并切换过去，失败时保持原来的账号不变
+pub async fn import_cookies(format: CookieFileFormat, content: String) -> Result<UserDetail> {
+    let cookies = crate::cookie_file::parse_cookies(format, &content)?;
+    crate::account::import_cookies(cookies).await
+}
+
+/// 导出当前账号的 cookie
+pub async fn export_cookies(format: CookieFileFormat) -> Result<String> {
+    let cookies = crate::COOKIE_STORE.export_cookies(crate::account::active_account_id());
+    crate::cookie_file::format_cookies(format, &cookies)
+}
+
+pub struct Account {
+    pub id: i64,
+    pub username: String,
+    pub create_time: i64,
+    pub last_login_time: i64,
+}
+
+fn account_of(model: crate::database::entities::Account) -> Account {
+    Account {
+        id: model.id,
+        username: model.username,
+        create_time: model.create_time,
+        last_login_time: model.last_login_time,
+    }
+}
+
+pub async fn list_accounts() -> Result<Vec<Account>> {
+    let accounts = crate::account::list_accounts().await?;
+    Ok(accounts.into_iter().map(account_of).collect())
+}
+
+/// 当前账号，未绑定账号时为 None
+pub async fn active_account() -> Result<Option<Account>> {
+    Ok(crate::account::active_account().await?.map(account_of))
+}
+
+/// 准备登录新账号，登录成功后自动添加并切换到该账号
+pub async fn add_account() -> Result<()> {
+    crate::account::add_account().await
+}
+
+pub async fn switch_account(account_id: i64) -> Result<()> {
+    crate::account::switch_account(account_id).await
+}
+
+pub async fn remove_account(account_id: i64) -> Result<()> {
+    crate::account::remove_account(account_id).await
+}
+
+pub async fn vault_state() -> Result<VaultState> {
+    crate::vault::vault_state().await
+}
+
+/// 开启密码保险箱，secret 为用户输入的口令或平台提供的密钥
+/// 开启后登录成功的密码会加密保存，登录过期时自动重新登录
+pub async fn enable_vault(secret: String) -> Result<()> {
+    crate::vault::enable_vault(&secret).await
+}
+
+pub async fn unlock_vault(secret: String) -> Result<()> {
+    crate::vault::unlock_vault(&secret).await
+}
+
+pub async fn lock_vault() -> Result<()> {
+    crate::vault::lock_vault().await;
     Ok(())
 }
 
+/// 关闭密码保险箱并删除保存的密码
+pub async fn disable_vault() -> Result<()> {
+    crate::vault::disable_vault().await
+}
+
+pub async fn get_account_scoped_history() -> bool {
+    crate::account::is_scoped_history()
+}
+
+/// 开启后阅读记录和签到记录只显示当前账号的
+pub async fn set_account_scoped_history(scoped: bool) -> Result<()> {
+    crate::account::set_scoped_history(scoped).await
+}
+
 pub async fn download_checkcode() -> Result<Vec<u8>> {
     CLIENT.checkcode().await
 }
 
 pub async fn user_detail() -> Result<UserDetail> {
-    CLIENT.userdetail().await
+    with_relogin(|| CLIENT.userdetail()).await
 }
 
 pub async fn index() -> anyhow::Result<Vec<HomeBlock>> {
-    let key = "INDEX_DATA".to_string();
-    crate::cache_first(
-        key,
-        Duration::from_secs(60 * 10),
-        Box::pin(async move { CLIENT.index().await }),
-    )
-    .await
+    super::source::source_index(DEFAULT_SOURCE_ID.to_string()).await
 }
 
 pub async fn download_image(url: String) -> anyhow::Result<String> {
-    crate::get_cached_image(url).await
+    super::source::source_download_image(DEFAULT_SOURCE_ID.to_string(), url).await
 }
 
 pub async fn chapter_content(aid: String, cid: String) -> anyhow::Result<String> {
-    let content = crate::get_chapter_content(&aid, &cid).await?;
-
-    // 处理内容中的空白字符
-    let processed_content = content
-        .lines() // 按行分割
-        .filter(|line| !line.trim().is_empty()) // 移除空行
-        .collect::<Vec<&str>>()
-        .join("\n") // 重新组合，每行之间用单个换行符连接
-        .replace(|c: char| c.is_whitespace() && c != '\n', " "); // 将其他空白字符替换为空格
-
-    // 将连续的两个以上换行符替换为两个换行符
-    let mut result = String::new();
-    let mut newline_count = 0;
-
-    for c in processed_content.chars() {
-        if c == '\n' {
-            newline_count += 1;
-            if newline_count <= 2 {
-                result.push(c);
-            }
-        } else {
-            if newline_count > 2 {
-                // 如果之前有超过两个换行符，添加两个换行符
-                result.push('\n');
-                result.push('\n');
-            }
-            newline_count = 0;
-            result.push(c);
-        }
-    }
+    super::source::source_chapter_content(DEFAULT_SOURCE_ID.to_string(), aid, cid).await
+}
 
-    Ok(result)
+/// 结构化的章节内容，插图带有本地路径和尺寸（如果已缓存或已下载）
+pub async fn chapter_document(aid: String, cid: String) -> anyhow::Result<ChapterDocument> {
+    super::source::source_chapter_document(DEFAULT_SOURCE_ID.to_string(), aid, cid).await
 }
 
 pub async fn novel_info(aid: String) -> anyhow::Result<NovelInfo> {
-    let key = format!("NOVEL_INFO${}", aid);
-    crate::cache_first(
-        key,
-        Duration::from_secs(60 * 60),
-        Box::pin(async move { CLIENT.novel_info(&aid).await }),
-    )
-    .await
+    super::source::source_novel_info(DEFAULT_SOURCE_ID.to_string(), aid).await
 }
 
 pub async fn novel_reader(aid: String) -> anyhow::Result<Vec<Volume>> {
-    let key = format!("NOVEL_READER${}", aid);
-    crate::cache_first(
-        key,
-        Duration::from_secs(60 * 60),
-        Box::pin(async move { CLIENT.novel_reader(&aid).await }),
-    )
-    .await
+    super::source::source_novel_reader(DEFAULT_SOURCE_ID.to_string(), aid).await
 }
 
 pub async fn update_history(
@@ -130,30 +192,34 @@ pub async fn update_history(
     cover: &str,
     author: &str,
 ) -> anyhow::Result<()> {
-    ReadingHistoryEntity::upsert(
-        novel_id,
-        novel_name,
-        volume_id,
-        volume_name,
-        chapter_id,
-        chapter_title,
+    super::source::source_update_history(ReadingHistory {
+        novel_id: novel_id.to_string(),
+        novel_name: novel_name.to_string(),
+        volume_id: volume_id.to_string(),
+        volume_name: volume_name.to_string(),
+        chapter_id: chapter_id.to_string(),
+        chapter_title: chapter_title.to_string(),
+        last_read_at: 0,
         progress,
         progress_page,
-        cover,
-        author,
-    )
-    .await?;
-    ReadingHistoryEntity::delete_old_records().await?;
-    Ok(())
+        cover: cover.to_string(),
+        author: author.to_string(),
+        source_id: DEFAULT_SOURCE_ID.to_string(),
+    })
+    .await
 }
 
 pub async fn delete_all_history() -> anyhow::Result<()> {
-    ReadingHistoryEntity::delete_all().await?;
+    ReadingHistoryEntity::delete_all(crate::account::history_account_id()).await?;
     Ok(())
 }
 
 pub async fn delete_history_by_novel_id(novel_id: String) -> anyhow::Result<()> {
-    ReadingHistoryEntity::delete_by_novel_id(novel_id.as_str()).await?;
+    ReadingHistoryEntity::delete_by_novel_id(
+        crate::account::history_account_id(),
+        novel_id.as_str(),
+    )
+    .await?;
     Ok(())
 }
 
@@ -169,28 +235,36 @@ pub struct ReadingHistory {
     pub progress_page: i32, // 阅读进度页码
     pub cover: String,
     pub author: String,
+    pub source_id: String,
 }
 
 pub async fn novel_history_by_id(novel_id: &str) -> anyhow::Result<Option<ReadingHistory>> {
-    Ok(ReadingHistoryEntity::find_latest_by_novel_id(novel_id)
-        .await?
-        .map(|history| ReadingHistory {
-            novel_id: history.novel_id,
-            novel_name: history.novel_name,
-            volume_id: history.volume_id,
-            volume_name: history.volume_name,
-            chapter_id: history.chapter_id,
-            chapter_title: history.chapter_title,
-            last_read_at: history.last_read_at,
-            progress: history.progress,
-            progress_page: history.progress_page,
-            cover: history.cover,
-            author: history.author,
-        }))
+    let history =
+        ReadingHistoryEntity::find_latest_by_novel_id(crate::account::history_account_id(), novel_id)
+            .await?;
+    Ok(history.map(|history| ReadingHistory {
+        novel_id: history.novel_id,
+        novel_name: history.novel_name,
+        volume_id: history.volume_id,
+        volume_name: history.volume_name,
+        chapter_id: history.chapter_id,
+        chapter_title: history.chapter_title,
+        last_read_at: history.last_read_at,
+        progress: history.progress,
+        progress_page: history.progress_page,
+        cover: history.cover,
+        author: history.author,
+        source_id: history.source_id,
+    }))
 }
 
 pub async fn list_reading_history(offset: i32, limit: i32) -> crate::Result<Vec<ReadingHistory>> {
-    let histories = ReadingHistoryEntity::list_reading_history(offset, limit).await?;
+    let histories = ReadingHistoryEntity::list_reading_history(
+        crate::account::history_account_id(),
+        offset,
+        limit,
+    )
+    .await?;
     Ok(histories
         .into_iter()
         .map(|history| ReadingHistory {
@@ -205,12 +279,13 @@ pub async fn list_reading_history(offset: i32, limit: i32) -> crate::Result<Vec<
             progress_page: history.progress_page,
             cover: history.cover,
             author: history.author,
+            source_id: history.source_id,
         })
         .collect())
 }
 
 pub async fn tags() -> crate::Result<Vec<TagGroup>> {
-    let key = "TAGS".to_string();
+    let key = CLIENT.language_key("TAGS").await;
     crate::cache_first(
         key,
         Duration::from_secs(60 * 60),
@@ -231,7 +306,9 @@ pub async fn tag_page(
     v: String,
     page_number: i32,
 ) -> anyhow::Result<PageStatsNovelCover> {
-    let key = format!("TAG_PAGE${}${}${}", tag, v, page_number);
+    let key = CLIENT
+        .language_key(&format!("TAG_PAGE${}${}${}", tag, v, page_number))
+        .await;
     let data = crate::cache_first(
         key,
         Duration::from_secs(60 * 60),
@@ -246,7 +323,7 @@ pub async fn tag_page(
 }
 
 pub async fn toplist(sort: String, page: i32) -> anyhow::Result<PageStatsNovelCover> {
-    let key = format!("TOPLIST${}${}", sort, page);
+    let key = CLIENT.language_key(&format!("TOPLIST${}${}", sort, page)).await;
     let data = crate::cache_first(
         key,
         Duration::from_secs(60 * 60),
@@ -260,8 +337,24 @@ pub async fn toplist(sort: String, page: i32) -> anyhow::Result<PageStatsNovelCo
     })
 }
 
+/// 使用 app 接口获取排行榜，网页被拦截时可用
+pub async fn app_toplist(sort: String, page: i32) -> anyhow::Result<PageStatsNovelCover> {
+    let key = CLIENT.language_key(&format!("APP_TOPLIST${}${}", sort, page)).await;
+    let data = crate::cache_first(
+        key,
+        Duration::from_secs(60 * 60),
+        Box::pin(async move { CLIENT.app_toplist(&sort, page).await }),
+    )
+    .await?;
+    Ok(PageStatsNovelCover {
+        current_page: data.current_page,
+        max_page: data.max_page,
+        records: data.records,
+    })
+}
+
 pub async fn articlelist(fullflag: i32, page: i32) -> anyhow::Result<PageStatsNovelCover> {
-    let key = format!("ARTICLELIST${}${}", fullflag, page);
+    let key = CLIENT.language_key(&format!("ARTICLELIST${}${}", fullflag, page)).await;
     let data = crate::cache_first(
         key,
         Duration::from_secs(60 * 60),
@@ -276,19 +369,19 @@ pub async fn articlelist(fullflag: i32, page: i32) -> anyhow::Result<PageStatsNo
 }
 
 pub async fn add_bookshelf(aid: String) -> anyhow::Result<()> {
-    CLIENT.add_bookshelf(&aid).await
+    with_relogin(|| CLIENT.add_bookshelf(&aid)).await
 }
 
 pub async fn delete_bookcase(bid: String) -> anyhow::Result<()> {
-    CLIENT.delete_bookcase(&bid).await
+    with_relogin(|| CLIENT.delete_bookcase(&bid)).await
 }
 
 pub async fn bookcase_list() -> anyhow::Result<Vec<Bookcase>> {
-    CLIENT.bookcase_list().await
+    with_relogin(|| CLIENT.bookcase_list()).await
 }
 
 pub async fn book_in_case(case_id: String) -> anyhow::Result<BookcaseDto> {
-    CLIENT.book_in_case(&case_id).await
+    with_relogin(|| CLIENT.book_in_case(&case_id)).await
 }
 
 pub async fn move_bookcase(
@@ -296,9 +389,14 @@ pub async fn move_bookcase(
     from_bookcase_id: String,
     to_bookcase_id: String,
 ) -> anyhow::Result<()> {
-    CLIENT
-        .move_bookcase(bid_list, from_bookcase_id, to_bookcase_id)
-        .await
+    with_relogin(|| {
+        CLIENT.move_bookcase(
+            bid_list.clone(),
+            from_bookcase_id.clone(),
+            to_bookcase_id.clone(),
+        )
+    })
+    .await
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -324,30 +422,13 @@ pub async fn search(
     search_key: String,
     page: i32,
 ) -> anyhow::Result<PageStatsNovelCover> {
-    crate::database::entities::active::search_history::Entity::save_or_update(
-        search_type.clone(),
-        search_key.clone(),
-    )
-    .await?;
-    crate::database::entities::active::search_history::Entity::delete_old_records().await?;
-    let key = format!("SEARCH${}${}${}", search_type, search_key, page);
-    let data = crate::cache_first(
-        key,
-        Duration::from_secs(60 * 60),
-        Box::pin(async move { CLIENT.search(&search_type, &search_key, page).await }),
-    )
-    .await?;
-    Ok(PageStatsNovelCover {
-        current_page: data.current_page,
-        max_page: data.max_page,
-        records: data.records,
-    })
+    super::source::source_search(DEFAULT_SOURCE_ID.to_string(), search_type, search_key, page).await
 }
 
 pub async fn auto_sign() -> anyhow::Result<bool> {
-    if !SignLogEntity::is_signed_today().await? {
+    if !SignLogEntity::is_signed_today(Some(crate::account::active_account_id())).await? {
         CLIENT.sign().await?;
-        SignLogEntity::sign().await?;
+        SignLogEntity::sign(crate::account::active_account_id()).await?;
         SignLogEntity::delete_old_records().await?;
         Ok(true)
     } else {
@@ -355,155 +436,51 @@ pub async fn auto_sign() -> anyhow::Result<bool> {
     }
 }
 
-pub async fn download_novel(aid: String, cid_list: Vec<String>) -> anyhow::Result<()> {
-    let novel_detail = novel_info(aid.clone()).await?;
-    let volumes = novel_reader(aid.clone()).await?;
-    let mut cid_list = cid_list;
-    if cid_list.is_empty() {
-        cid_list = volumes
-            .iter()
-            .flat_map(|v| v.chapters.iter().map(|c| c.cid.clone()))
-            .collect();
-    }
+/// 推荐小说，成功后记录到本地的推荐记录
+pub async fn recommend(aid: String) -> anyhow::Result<()> {
+    with_relogin(|| CLIENT.recommend(&aid)).await?;
+    RecommendLogEntity::record(crate::account::active_account_id(), &aid).await?;
+    RecommendLogEntity::delete_old_records().await?;
+    Ok(())
+}
 
-    // 1. 先处理章节信息
-    for volume in &volumes {
-        let volume_id = volume.id.clone();
-        for chapter in &volume.chapters {
-            let chapter_id = chapter.cid.clone();
-
-            // 检查章节是否在下载列表中
-            if cid_list.contains(&chapter_id) {
-                // 检查章节是否已存在
-                if let Some(existing_chapter) =
-                    novel_download_chapter::Entity::find_by_id(&chapter_id).await?
-                {
-                    // 如果章节已存在，跳过
-                    continue;
-                }
+/// 今天剩余的推荐次数，按用户信息中的每天允许推荐次数减去今天的本地记录
+pub async fn recommend_remaining() -> anyhow::Result<i64> {
+    let user_detail = with_relogin(|| CLIENT.userdetail()).await?;
+    let daily = user_detail.quantity_of_recommend_daily.trim();
+    let daily = daily
+        .parse::<i64>()
+        .map_err(|_| anyhow::anyhow!("Unknown daily recommend quantity: {:?}", daily))?;
+    let used = RecommendLogEntity::count_today(crate::account::active_account_id()).await?;
+    Ok((daily - used).max(0))
+}
 
-                // 章节不存在，插入新章节
-                let chapter_title = chapter.title.clone();
-                let chapter_url = chapter.url.clone();
-                let chapter_idx = volume
-                    .chapters
-                    .iter()
-                    .position(|c| c.cid == chapter.cid)
-                    .unwrap_or(0) as i32;
-
-                novel_download_chapter::Entity::upsert(
-                    &chapter_id,
-                    &chapter_title,
-                    &chapter_url,
-                    &aid,
-                    &volume_id,
-                    DOWNLOAD_STATUS_NOT_DOWNLOAD,
-                    0, // 总图片数初始为0
-                    chapter_idx,
-                )
-                .await?;
-            }
-        }
-    }
+pub struct RecommendLog {
+    pub id: i64,
+    pub account_id: i64,
+    pub date: String, // 格式：YYYY-MM-DD
+    pub novel_id: String,
+    pub create_time: i64,
+}
 
-    // 2. 处理卷信息
-    for (volume_idx, volume) in volumes.iter().enumerate() {
-        let volume_id = volume.id.clone();
-
-        // 检查卷是否已存在
-        if let Some(existing_volume) = novel_download_volume::Entity::find_by_id(&volume_id).await?
-        {
-            // 如果卷已存在，重置下载状态
-            novel_download_volume::Entity::update_download_status(
-                &volume_id,
-                DOWNLOAD_STATUS_NOT_DOWNLOAD,
-            )
-            .await?;
-        } else {
-            // 卷不存在，插入新卷
-            let volume_title = volume.title.clone();
-            novel_download_volume::Entity::upsert(
-                &volume_id,
-                &aid,
-                volume_idx as i32,
-                &volume_title,
-                DOWNLOAD_STATUS_NOT_DOWNLOAD,
-            )
+pub async fn recommend_log_list(offset: i32, limit: i32) -> anyhow::Result<Vec<RecommendLog>> {
+    let logs =
+        RecommendLogEntity::list_recommend_log(crate::account::active_account_id(), offset, limit)
             .await?;
-        }
-    }
-
-    // 3. 最后处理小说本体
-    let novel_id = aid.clone();
-
-    // 检查小说是否已存在
-    if let Some(existing_novel) = novel_download::Entity::find_by_novel_id(&novel_id).await? {
-        // 如果小说已存在，更新信息并重置下载状态
-        let novel_name = novel_detail.title.clone();
-        let cover_url = novel_detail.img_url.clone();
-        let author = novel_detail.author.clone();
-        let tags = novel_detail.tags.join(",");
-        let introduce = novel_detail.introduce.clone();
-        let trending = novel_detail.trending.clone();
-        let is_animated = novel_detail.is_animated;
-        let fin_update = novel_detail.fin_update.clone();
-        let status = novel_detail.status.clone();
-        let choose_chapter_count = cid_list.len() as i32;
-
-        novel_download::Entity::upsert(
-            &novel_id,
-            &novel_name,
-            DOWNLOAD_STATUS_NOT_DOWNLOAD,
-            &cover_url,
-            DOWNLOAD_STATUS_NOT_DOWNLOAD,
-            &author,
-            &tags,
-            choose_chapter_count,
-            0, // 已下载章节数重置为0
-            &introduce,
-            &trending,
-            is_animated,
-            &fin_update,
-            &status,
-        )
-        .await?;
-    } else {
-        // 小说不存在，插入新小说
-        let novel_name = novel_detail.title.clone();
-        let cover_url = novel_detail.img_url.clone();
-        let author = novel_detail.author.clone();
-        let tags = novel_detail.tags.join(",");
-        let introduce = novel_detail.introduce.clone();
-        let trending = novel_detail.trending.clone();
-        let is_animated = novel_detail.is_animated;
-        let fin_update = novel_detail.fin_update.clone();
-        let status = novel_detail.status.clone();
-        let choose_chapter_count = cid_list.len() as i32;
-
-        novel_download::Entity::upsert(
-            &novel_id,
-            &novel_name,
-            DOWNLOAD_STATUS_NOT_DOWNLOAD,
-            &cover_url,
-            DOWNLOAD_STATUS_NOT_DOWNLOAD,
-            &author,
-            &tags,
-            choose_chapter_count,
-            0,
-            &introduce,
-            &trending,
-            is_animated,
-            &fin_update,
-            &status,
-        )
-        .await?;
-    }
-
-    // 设置重启标志
-    let mut restart_flag = RESTART_FLAG.lock().await;
-    *restart_flag = true;
+    Ok(logs
+        .into_iter()
+        .map(|log| RecommendLog {
+            id: log.id,
+            account_id: log.account_id,
+            date: log.date,
+            novel_id: log.novel_id,
+            create_time: log.create_time,
+        })
+        .collect())
+}
 
-    Ok(())
+pub async fn download_novel(aid: String, cid_list: Vec<String>) -> anyhow::Result<()> {
+    super::source::source_download_novel(DEFAULT_SOURCE_ID.to_string(), aid, cid_list).await
 }
 
 pub async fn all_downloads() -> anyhow::Result<Vec<NovelDownload>> {
@@ -533,19 +510,30 @@ pub async fn all_downloads() -> anyhow::Result<Vec<NovelDownload>> {
             is_animated: model.is_animated,
             fin_update: model.fin_update,
             status: model.status,
+            priority: model.priority,
+            source_id: model.source_id,
+            language: model.language,
         })
         .collect())
 }
 
+/// 当前语言的下载记录
 pub async fn exists_download(novel_id: String) -> anyhow::Result<Option<ExistsDownload>> {
+    let language = CLIENT.load_language().await.code();
     // 1. 获取小说本体信息
-    let novel = match novel_download::Entity::find_by_novel_id(&novel_id).await? {
+    let novel = match novel_download::Entity::find_by_novel_id(
+        DEFAULT_SOURCE_ID,
+        language,
+        &novel_id,
+    )
+    .await?
+    {
         Some(novel) => novel,
         None => return Ok(None),
     };
 
     // 2. 获取卷信息
-    let volumes = novel_download_volume::Entity::find_by_novel_id(&novel_id)
+    let volumes = novel_download_volume::Entity::find_by_novel_id(DEFAULT_SOURCE_ID, language, &novel_id)
         .await?
         .into_iter()
         .map(|model| NovelDownloadVolume {
@@ -559,7 +547,7 @@ pub async fn exists_download(novel_id: String) -> anyhow::Result<Option<ExistsDo
         .collect();
 
     // 3. 获取章节信息
-    let chapters = novel_download_chapter::Entity::find_by_novel_id(&novel_id)
+    let chapters = novel_download_chapter::Entity::find_by_novel_id(DEFAULT_SOURCE_ID, language, &novel_id)
         .await?
         .into_iter()
         .map(|model| NovelDownloadChapter {
@@ -593,6 +581,9 @@ pub async fn exists_download(novel_id: String) -> anyhow::Result<Option<ExistsDo
             is_animated: novel.is_animated,
             fin_update: novel.fin_update,
             status: novel.status,
+            priority: novel.priority,
+            source_id: novel.source_id,
+            language: novel.language,
         },
         novel_download_volume: volumes,
         novel_download_chapter: chapters,
@@ -624,6 +615,10 @@ pub struct NovelDownload {
     pub is_animated: bool,
     pub fin_update: String,
     pub status: String,
+    pub priority: i32,
+    pub source_id: String,
+    /// 下载时的语言，简繁两个版本分开下载
+    pub language: String,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -656,9 +651,164 @@ pub async fn set_api_host(api_host: String) -> anyhow::Result<()> {
     crate::set_api_host(api_host).await
 }
 
+/// WebView 通过 Cloudflare 验证后调用，写入 cf_clearance 并固定使用 WebView 的 UA
+pub async fn set_cf_clearance(
+    url: String,
+    cf_clearance: String,
+    user_agent: String,
+) -> anyhow::Result<()> {
+    crate::apply_cf_clearance(url, cf_clearance, user_agent).await
+}
+
+pub async fn unpin_user_agent() -> anyhow::Result<()> {
+    crate::unpin_user_agent().await
+}
+
+pub async fn get_user_agent() -> anyhow::Result<String> {
+    Ok(CLIENT.load_user_agent().await)
+}
+
+/// 内容语言，zh-Hans 为简体，zh-Hant 为繁体
+pub async fn get_language() -> anyhow::Result<String> {
+    Ok(CLIENT.load_language().await.code().to_string())
+}
+
+pub async fn set_language(language: String) -> anyhow::Result<()> {
+    crate::set_language(crate::wenku8::Language::from_code(&language)).await
+}
+
+/// 阅读和导出时转换成的文字，zh-Hans 或 zh-Hant，空字符串为不转换
+pub async fn get_display_language() -> anyhow::Result<String> {
+    let language = crate::zh_convert::load_display_language().await;
+    Ok(language.map(|l| l.code().to_string()).unwrap_or_default())
+}
+
+pub async fn set_display_language(language: String) -> anyhow::Result<()> {
+    let language = (!language.is_empty()).then(|| crate::wenku8::Language::from_code(&language));
+    crate::zh_convert::set_display_language(language).await
+}
+
+pub async fn load_proxy_settings() -> anyhow::Result<ProxySettings> {
+    crate::proxy::load_proxy_settings().await
+}
+
+/// 保存并立即应用代理设置
+pub async fn save_proxy_settings(settings: ProxySettings) -> anyhow::Result<()> {
+    crate::proxy::save_proxy_settings(settings).await
+}
+
+/// 使用给定的代理访问当前镜像，不会保存设置
+pub async fn test_proxy(settings: ProxySettings) -> anyhow::Result<ProxyTestResult> {
+    Ok(crate::proxy::test_proxy(settings).await)
+}
+
+pub async fn load_mirror_hosts() -> anyhow::Result<Vec<String>> {
+    Ok(CLIENT.load_mirror_hosts().await)
+}
+
+pub async fn set_mirror_hosts(hosts: Vec<String>) -> anyhow::Result<()> {
+    crate::set_mirror_hosts(hosts).await
+}
+
+/// 测试所有镜像的可用性和延迟
+pub async fn probe_mirrors() -> anyhow::Result<Vec<MirrorProbe>> {
+    Ok(CLIENT.probe_mirrors().await)
+}
+
+/// 切换到最快的可用镜像，返回切换后的镜像，都不可用时返回 None
+pub async fn select_fastest_mirror() -> anyhow::Result<Option<String>> {
+    Ok(CLIENT.select_fastest_mirror().await)
+}
+
+/// 自动切换镜像时推送新的镜像地址
+pub async fn api_host_changes(sink: StreamSink<String>) -> anyhow::Result<()> {
+    let mut receiver = CLIENT.subscribe_host_changes();
+    tokio::spawn(async move {
+        loop {
+            match receiver.recv().await {
+                std::result::Result::Ok(host) => {
+                    if sink.add(host).is_err() {
+                        break;
+                    }
+                }
+                Err(tokio::sync::broadcast::error::RecvError::Lagged(_)) => {}
+                Err(tokio::sync::broadcast::error::RecvError::Closed) => break,
+            }
+        }
+    });
+    Ok(())
+}
+
+/// 暂停单本小说的下载
+pub async fn pause_download(novel_id: String) -> anyhow::Result<()> {
+    let language = CLIENT.load_language().await.code();
+    downloading::pause_download(DEFAULT_SOURCE_ID, language, &novel_id).await
+}
+
+pub async fn resume_download(novel_id: String) -> anyhow::Result<()> {
+    let language = CLIENT.load_language().await.code();
+    downloading::resume_download(DEFAULT_SOURCE_ID, language, &novel_id).await
+}
+
+/// 暂停全部下载，重启应用后仍然保持
+pub async fn pause_all_downloads() -> anyhow::Result<()> {
+    downloading::set_downloads_paused(true).await
+}
+
+pub async fn resume_all_downloads() -> anyhow::Result<()> {
+    downloading::set_downloads_paused(false).await
+}
+
+pub async fn downloads_paused() -> anyhow::Result<bool> {
+    Ok(downloading::downloads_paused().await)
+}
+
+/// 优先下载这本小说
+pub async fn move_download_to_front(novel_id: String) -> anyhow::Result<()> {
+    let language = CLIENT.load_language().await.code();
+    downloading::move_download_to_front(DEFAULT_SOURCE_ID, language, &novel_id).await
+}
+
+/// 按 novel_ids 的顺序重排下载队列
+pub async fn reorder_downloads(novel_ids: Vec<String>) -> anyhow::Result<()> {
+    let language = CLIENT.load_language().await.code();
+    downloading::reorder_downloads(DEFAULT_SOURCE_ID, language, &novel_ids).await
+}
+
+pub async fn load_download_concurrency() -> anyhow::Result<i32> {
+    Ok(downloading::download_concurrency().await? as i32)
+}
+
+/// 设置同时下载的章节和图片数，下一本小说开始时生效
+pub async fn set_download_concurrency(concurrency: i32) -> anyhow::Result<()> {
+    downloading::set_download_concurrency(concurrency.max(1) as u64).await
+}
+
+pub async fn load_text_rate_limit() -> anyhow::Result<f64> {
+    Ok(CLIENT.text_limiter.rate().await)
+}
+
+pub async fn set_text_rate_limit(rate: f64) -> anyhow::Result<()> {
+    crate::set_text_rate_limit(rate).await
+}
+
+pub async fn load_image_rate_limit() -> anyhow::Result<f64> {
+    Ok(CLIENT.image_limiter.rate().await)
+}
+
+pub async fn set_image_rate_limit(rate: f64) -> anyhow::Result<()> {
+    crate::set_image_rate_limit(rate).await
+}
+
 pub async fn delete_download(novel_id: String) -> anyhow::Result<()> {
     // 设置小说下载状态为删除中
-    novel_download::Entity::update_status(&novel_id, DOWNLOAD_STATUS_DELETING).await?;
+    novel_download::Entity::update_status(
+        DEFAULT_SOURCE_ID,
+        CLIENT.load_language().await.code(),
+        &novel_id,
+        DOWNLOAD_STATUS_DELETING,
+    )
+    .await?;
 
     // 设置重启标志
     let mut restart_flag = RESTART_FLAG.lock().await;
@@ -667,6 +817,110 @@ pub async fn delete_download(novel_id: String) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// 导出已下载的小说为 EPUB3
+/// volume_ids 为空时导出全部卷；split_volumes 为 true 时每卷一个文件，out_path 为目录
+pub async fn export_epub(
+    novel_id: String,
+    volume_ids: Vec<String>,
+    out_path: String,
+    split_volumes: bool,
+) -> anyhow::Result<Vec<String>> {
+    crate::exporting::export_epub(
+        DEFAULT_SOURCE_ID,
+        CLIENT.load_language().await.code(),
+        &novel_id,
+        volume_ids,
+        &out_path,
+        split_volumes,
+    )
+    .await
+}
+
+/// 订阅下载进度事件，Flutter 端取消订阅后自动结束
+pub async fn download_events(sink: StreamSink<DownloadEvent>) -> anyhow::Result<()> {
+    let mut receiver = downloading::subscribe_events();
+    tokio::spawn(async move {
+        loop {
+            match receiver.recv().await {
+                std::result::Result::Ok(event) => {
+                    if sink.add(event).is_err() {
+                        break;
+                    }
+                }
+                Err(tokio::sync::broadcast::error::RecvError::Lagged(skipped)) => {
+                    tracing::warn!(skipped, "Download event subscriber lagged");
+                }
+                Err(tokio::sync::broadcast::error::RecvError::Closed) => break,
+            }
+        }
+    });
+    Ok(())
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NovelUpdate {
+    pub id: i64,
+    pub novel_id: String,
+    pub novel_name: String,
+    pub volume_id: String,
+    pub volume_title: String,
+    pub chapter_id: String,
+    pub chapter_title: String,
+    pub create_time: i64,
+}
+
+/// 未读的新章节
+pub async fn unseen_novel_updates(offset: i32, limit: i32) -> anyhow::Result<Vec<NovelUpdate>> {
+    let updates = NovelUpdateEntity::list_unseen(offset, limit).await?;
+    Ok(updates
+        .into_iter()
+        .map(|update| NovelUpdate {
+            id: update.id,
+            novel_id: update.novel_id,
+            novel_name: update.novel_name,
+            volume_id: update.volume_id,
+            volume_title: update.volume_title,
+            chapter_id: update.chapter_id,
+            chapter_title: update.chapter_title,
+            create_time: update.create_time,
+        })
+        .collect())
+}
+
+pub async fn mark_novel_updates_seen(ids: Vec<i64>) -> anyhow::Result<()> {
+    NovelUpdateEntity::mark_seen_by_ids(ids).await
+}
+
+pub async fn mark_novel_updates_seen_by_novel(novel_id: String) -> anyhow::Result<()> {
+    NovelUpdateEntity::mark_seen_by_novel_id(&novel_id).await
+}
+
+pub async fn mark_all_novel_updates_seen() -> anyhow::Result<()> {
+    NovelUpdateEntity::mark_all_seen().await
+}
+
+/// 立即检查更新，返回新章节数
+pub async fn check_novel_updates() -> anyhow::Result<i32> {
+    crate::update_checker::check_updates().await
+}
+
+pub async fn load_update_check_interval() -> anyhow::Result<i64> {
+    crate::update_checker::check_interval_hours().await
+}
+
+/// 设置自动检查更新的间隔（小时），0 为关闭
+pub async fn set_update_check_interval(hours: i64) -> anyhow::Result<()> {
+    crate::update_checker::set_check_interval_hours(hours).await
+}
+
+pub async fn load_auto_download_updates() -> anyhow::Result<bool> {
+    crate::update_checker::auto_download_updates().await
+}
+
+pub async fn set_auto_download_updates(enabled: bool) -> anyhow::Result<()> {
+    crate::update_checker::set_auto_download_updates(enabled).await
+}
+
 pub async fn clean_all_web_cache() -> anyhow::Result<()> {
     crate::cache_manager::clean_all_web_cache().await?;
     Ok(())
@@ -685,16 +939,151 @@ pub struct PageStatsReviews {
 }
 
 pub async fn reviews(aid: String, page_number: i32) -> Result<PageStatsReviews> {
-    let key = format!("reviews${}${}", aid, page_number);
+    let key = CLIENT
+        .language_key(&format!("reviews${}${}", aid, page_number))
+        .await;
     let data = crate::cache_first(
         key,
         Duration::from_secs(60 * 60),
         Box::pin(async move { CLIENT.reviews(aid.as_str(), page_number).await }),
     )
     .await?;
+    // 评论内容按阅读设置转换简繁
+    let language = CLIENT.load_language().await;
+    let display_language = crate::zh_convert::load_display_language().await;
+    let records = data
+        .records
+        .into_iter()
+        .map(|review| Review {
+            content: crate::zh_convert::convert_from(review.content, language, display_language),
+            ..review
+        })
+        .collect();
     Ok(PageStatsReviews {
+        current_page: data.current_page,
+        max_page: data.max_page,
+        records,
+    })
+}
+
+pub async fn review_detail(rid: String, page: i32) -> Result<ReviewDetail> {
+    let key = CLIENT
+        .language_key(&format!("review_detail${}${}", rid, page))
+        .await;
+    let mut detail = crate::cache_first(
+        key,
+        Duration::from_secs(60 * 10),
+        Box::pin(async move { CLIENT.review_detail(rid.as_str(), page).await }),
+    )
+    .await?;
+    // 文字按阅读设置转换简繁
+    let language = CLIENT.load_language().await;
+    let display_language = crate::zh_convert::load_display_language().await;
+    let convert = |text: String| crate::zh_convert::convert_from(text, language, display_language);
+    detail.title = convert(detail.title);
+    for post in detail.posts.iter_mut() {
+        for block in post.blocks.iter_mut() {
+            block.text = convert(std::mem::take(&mut block.text));
+        }
+    }
+    Ok(detail)
+}
+
+/// 发表书评，成功后清理这本小说的书评列表缓存
+pub async fn post_review(aid: String, title: String, content: String) -> Result<()> {
+    with_relogin(|| CLIENT.post_review(&aid, &title, &content)).await?;
+    crate::cache_manager::clean_review_cache(&aid, None).await
+}
+
+/// 回复书评，aid 用于清理书评列表缓存（回复数会变化）
+pub async fn reply_review(aid: String, rid: String, content: String) -> Result<()> {
+    with_relogin(|| CLIENT.reply_review(&rid, &content)).await?;
+    crate::cache_manager::clean_review_cache(&aid, Some(&rid)).await
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PageStatsMessage {
+    pub current_page: i32,
+    pub max_page: i32,
+    pub records: Vec<Message>,
+}
+
+/// 短消息列表，box_name 为 inbox（收件箱）或 outbox（发件箱）
+pub async fn messages(box_name: String, page: i32) -> Result<PageStatsMessage> {
+    let data = with_relogin(|| CLIENT.messages(&box_name, page)).await?;
+    Ok(PageStatsMessage {
         current_page: data.current_page,
         max_page: data.max_page,
         records: data.records,
     })
 }
+
+pub async fn message_detail(id: String) -> Result<MessageDetail> {
+    with_relogin(|| CLIENT.message_detail(&id)).await
+}
+
+/// receiver 为对方的用户名
+pub async fn send_message(receiver: String, title: String, content: String) -> Result<()> {
+    with_relogin(|| CLIENT.send_message(&receiver, &title, &content)).await
+}
+
+pub async fn delete_messages(box_name: String, ids: Vec<String>) -> Result<()> {
+    with_relogin(|| CLIENT.delete_messages(&box_name, &ids)).await
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Wenku8ErrorKind {
+    CloudflareChallenge,
+    NotLoggedIn,
+    CaptchaWrong,
+    CaptchaRequired,
+    LoginFailed,
+    RateLimited,
+    NotFound,
+    CopyrightRestricted,
+    PermissionDenied,
+    VotesExhausted,
+    Decode,
+    Network,
+    Http,
+    Server,
+}
+
+/// http_status 只在 kind 为 Http 时有值，message 为服务器提示或网络错误信息
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Wenku8ErrorInfo {
+    pub kind: Wenku8ErrorKind,
+    pub http_status: i32,
+    pub message: String,
+}
+
+/// 解析接口抛出的异常信息，不是文库错误时返回 None
+#[flutter_rust_bridge::frb(sync)]
+pub fn parse_wenku8_error(message: String) -> Option<Wenku8ErrorInfo> {
+    let error = Wenku8Error::parse(&message)?;
+    let (kind, http_status, message) = match error {
+        Wenku8Error::CloudflareChallenge => {
+            (Wenku8ErrorKind::CloudflareChallenge, 0, String::new())
+        }
+        Wenku8Error::NotLoggedIn => (Wenku8ErrorKind::NotLoggedIn, 0, String::new()),
+        Wenku8Error::CaptchaWrong => (Wenku8ErrorKind::CaptchaWrong, 0, String::new()),
+        Wenku8Error::CaptchaRequired => (Wenku8ErrorKind::CaptchaRequired, 0, String::new()),
+        Wenku8Error::LoginFailed(message) => (Wenku8ErrorKind::LoginFailed, 0, message),
+        Wenku8Error::RateLimited => (Wenku8ErrorKind::RateLimited, 0, String::new()),
+        Wenku8Error::NotFound => (Wenku8ErrorKind::NotFound, 0, String::new()),
+        Wenku8Error::CopyrightRestricted => {
+            (Wenku8ErrorKind::CopyrightRestricted, 0, String::new())
+        }
+        Wenku8Error::PermissionDenied => (Wenku8ErrorKind::PermissionDenied, 0, String::new()),
+        Wenku8Error::VotesExhausted => (Wenku8ErrorKind::VotesExhausted, 0, String::new()),
+        Wenku8Error::Decode => (Wenku8ErrorKind::Decode, 0, String::new()),
+        Wenku8Error::Network(message) => (Wenku8ErrorKind::Network, 0, message),
+        Wenku8Error::Http(status) => (Wenku8ErrorKind::Http, status as i32, String::new()),
+        Wenku8Error::Server(message) => (Wenku8ErrorKind::Server, 0, message),
+    };
+    Some(Wenku8ErrorInfo {
+        kind,
+        http_status,
+        message,
+    })
+}
@@ -1,6 +1,10 @@
 use crate::database::entities::active::{chapter_cache, image_cache, novel_download, novel_download_chapter, novel_download_picture, web_cache};
 use crate::database::entities::WebCacheEntity;
-use crate::{get_image_cache_dir, CLIENT, DOWNLOAD_FOLDER, IMAGE_LOCKS};
+use crate::downloading::novel_dir;
+use crate::source::{source, source_key};
+use crate::wenku8::Language;
+use crate::zh_convert::{load_display_language, to_display};
+use crate::{get_image_cache_dir, CLIENT, IMAGE_LOCKS};
 use chrono::Utc;
 use image::io::Reader as ImageReader;
 use image::GenericImageView;
@@ -32,7 +36,7 @@ pub async fn cleanup_image_cache() -> crate::Result<()> {
     Ok(())
 }
 
-pub async fn get_cached_image(img_url: String) -> crate::Result<String> {
+pub async fn get_cached_image(source_id: &str, img_url: String) -> crate::Result<String> {
     let image_cache_dir = get_image_cache_dir();
     let url_md5 = md5::compute(img_url.as_bytes()).0;
     let url_md5 = hex::encode(url_md5);
@@ -44,8 +48,7 @@ pub async fn get_cached_image(img_url: String) -> crate::Result<String> {
 
     if let Some(a) = novel_download::Entity::find_by_image_url(img_url.as_str()).await? {
         if a.cover_download_status == 1 {
-            let novel_dir = Path::new(DOWNLOAD_FOLDER.get().unwrap()).join(&a.novel_id);
-            let picture_file_path = novel_dir.join("cover");
+            let picture_file_path = novel_dir(&a.source_id, &a.language, &a.novel_id)?.join("cover");
             let path = picture_file_path.to_str().unwrap().to_string(); 
             return Ok(path);
         }
@@ -53,8 +56,8 @@ pub async fn get_cached_image(img_url: String) -> crate::Result<String> {
 
     if let Some(a) = novel_download_picture::Entity::find_by_url(img_url.as_str()).await? {
         if a.download_status == 1 {
-            let novel_dir = Path::new(DOWNLOAD_FOLDER.get().unwrap()).join(&a.aid);
-            let picture_file_path = novel_dir.join(format!("picture_{}", a.url_md5));
+            let picture_file_path =
+                novel_dir(&a.source_id, &a.language, &a.aid)?.join(format!("picture_{}", a.url_md5));
             let path = picture_file_path.to_str().unwrap().to_string();
             return Ok(path);
         }
@@ -69,7 +72,7 @@ pub async fn get_cached_image(img_url: String) -> crate::Result<String> {
     }
 
     // 缓存未命中，下载图片
-    let buff = CLIENT.download_image(img_url.as_str()).await?;
+    let buff = source(source_id)?.download_image(img_url.as_str()).await?;
 
     // 获取图片尺寸
     let img = ImageReader::new(std::io::Cursor::new(&buff))
@@ -94,36 +97,70 @@ pub async fn get_cached_image(img_url: String) -> crate::Result<String> {
     Ok(file_path)
 }
 
-pub(crate) async fn get_chapter_content(aid: &str, cid: &str) -> anyhow::Result<String> {
+/// 章节内容，按阅读设置转换简繁
+pub(crate) async fn get_chapter_content(
+    source_id: &str,
+    aid: &str,
+    cid: &str,
+) -> anyhow::Result<String> {
+    let (content, language) = load_chapter_content(source_id, aid, cid).await?;
+    Ok(to_display(content, language).await)
+}
+
+// 返回章节内容和内容的语言
+async fn load_chapter_content(
+    source_id: &str,
+    aid: &str,
+    cid: &str,
+) -> anyhow::Result<(String, Language)> {
     // 根据MD5最后一位选择锁
-    let url_md5 = md5::compute(format!("{aid}:{cid}").as_bytes()).0;
+    let url_md5 = md5::compute(format!("{source_id}:{aid}:{cid}").as_bytes()).0;
     let url_md5 = hex::encode(url_md5);
     let lock_index = (url_md5.as_bytes()[url_md5.len() - 1] % 64) as usize;
     let _guard = IMAGE_LOCKS[lock_index].lock().await;
 
+    let language = CLIENT.load_language().await;
+
     // 如果章节已下载，则直接从本地文件读取
-    if let Some(a) = novel_download_chapter::Entity::find_by_id(cid).await? {
-        if a.download_status == 1 {
-            let novel_dir = Path::new(DOWNLOAD_FOLDER.get().unwrap()).join(&a.aid);
-            let chapter_file_path = novel_dir.join(format!("chapter_{}", cid));
-            let content = tokio::fs::read_to_string(chapter_file_path).await?;
-            return Ok(content);
+    // 优先使用当前语言的版本，开启了简繁转换时也可以使用另一个语言的版本，否则重新获取
+    let mut downloaded_languages = vec![language];
+    if load_display_language().await.is_some() {
+        downloaded_languages.extend(
+            Language::ALL
+                .into_iter()
+                .filter(|other| *other != language),
+        );
+    }
+    for downloaded_language in downloaded_languages {
+        if let Some(a) =
+            novel_download_chapter::Entity::find_by_id(source_id, downloaded_language.code(), cid)
+                .await?
+        {
+            if a.download_status == 1 && a.aid == aid {
+                let chapter_file_path = novel_dir(source_id, &a.language, &a.aid)?
+                    .join(format!("chapter_{}", cid));
+                let content = tokio::fs::read_to_string(chapter_file_path).await?;
+                return Ok((content, downloaded_language));
+            }
         }
     }
 
-    // 先尝试从缓存获取
-    if let Some(cache) = chapter_cache::Entity::get_chapter_content(aid, cid).await? {
-        return Ok(cache.content);
+    // 先尝试从缓存获取，其他书源的小说ID带书源前缀，繁体再带语言后缀
+    let cache_aid = language.cache_key(&source_key(source_id, aid));
+    if let Some(cache) = chapter_cache::Entity::get_chapter_content(&cache_aid, cid).await? {
+        return Ok((cache.content, language));
     }
 
     // 下载章节内容
-    let content = CLIENT.c_content(aid, cid).await?;
+    let content = source(source_id)?
+        .chapter_content_in(aid, cid, language)
+        .await?;
 
     // 保存到缓存
-    chapter_cache::Entity::save_chapter_content(aid.to_string(), cid.to_string(), content.clone())
+    chapter_cache::Entity::save_chapter_content(cache_aid, cid.to_string(), content.clone())
         .await?;
 
-    Ok(content)
+    Ok((content, language))
 }
 
 // 清理过期的章节缓存
@@ -142,6 +179,15 @@ pub(crate) async fn cleanup_expired_web_cache() -> anyhow::Result<()> {
     Ok(())
 }
 
+/// 发表书评或回复后清理这本小说的书评列表，rid 不为空时同时清理帖子
+pub(crate) async fn clean_review_cache(aid: &str, rid: Option<&str>) -> anyhow::Result<()> {
+    WebCacheEntity::delete_by_prefix(&format!("reviews${aid}$")).await?;
+    if let Some(rid) = rid {
+        WebCacheEntity::delete_by_prefix(&format!("review_detail${rid}$")).await?;
+    }
+    Ok(())
+}
+
 pub(crate) async fn clean_all_web_cache() -> anyhow::Result<()> {
     web_cache::Entity::delete_all().await?;
     Ok(())
@@ -0,0 +1,161 @@
+use crate::database::entities::active::{
+    image_cache, novel_download_picture, DOWNLOAD_STATUS_SUCCESS,
+};
+use crate::downloading::novel_dir;
+use crate::{get_image_cache_dir, Result};
+use anyhow::anyhow;
+use image::io::Reader as ImageReader;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// 结构化的章节内容，阅读器、导出和搜索共用
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChapterDocument {
+    pub aid: String,
+    pub cid: String,
+    pub segments: Vec<ChapterSegment>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ChapterSegmentKind {
+    Paragraph,
+    Image,
+    Separator,
+}
+
+/// 章节中的一段内容，Paragraph 只有 text，Image 只有 url、本地路径和尺寸
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChapterSegment {
+    pub kind: ChapterSegmentKind,
+    pub text: String,
+    pub url: String,
+    pub local_path: Option<String>,
+    pub width: Option<i32>,
+    pub height: Option<i32>,
+}
+
+impl ChapterSegment {
+    fn new(kind: ChapterSegmentKind, text: String, url: String) -> Self {
+        Self {
+            kind,
+            text,
+            url,
+            local_path: None,
+            width: None,
+            height: None,
+        }
+    }
+
+    pub(crate) fn paragraph(text: String) -> Self {
+        Self::new(ChapterSegmentKind::Paragraph, text, String::new())
+    }
+
+    pub(crate) fn image(url: String) -> Self {
+        Self::new(ChapterSegmentKind::Image, String::new(), url)
+    }
+
+    pub(crate) fn separator() -> Self {
+        Self::new(ChapterSegmentKind::Separator, String::new(), String::new())
+    }
+}
+
+// 分隔线可能由这些字符组成，例如 ＊＊＊ 或 ◇◇◇
+const SEPARATOR_CHARS: &[char] = &[
+    '*', '＊', '-', '—', '─', '=', '＝', '◇', '◆', '☆', '★', '○', '●', '□', '■', '~', '～', '#',
+    '＃',
+];
+
+static IMAGE_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"<!--image-->([^<]+)<!--image-->").unwrap());
+
+/// 解析 c_content 返回的正文，插图格式为 <!--image-->URL<!--image-->
+pub(crate) fn parse_segments(content: &str) -> Vec<ChapterSegment> {
+    let mut segments = Vec::new();
+    for line in content.lines() {
+        let mut last = 0;
+        for caps in IMAGE_REGEX.captures_iter(line) {
+            let m = caps.get(0).unwrap();
+            push_text(&mut segments, &line[last..m.start()]);
+            let url = caps[1].trim();
+            if !url.is_empty() {
+                segments.push(ChapterSegment::image(url.to_string()));
+            }
+            last = m.end();
+        }
+        push_text(&mut segments, &line[last..]);
+    }
+    segments
+}
+
+fn push_text(segments: &mut Vec<ChapterSegment>, text: &str) {
+    let text = text
+        .trim()
+        .replace(|c: char| c.is_whitespace() && c != '\n', " ");
+    if text.is_empty() {
+        return;
+    }
+    if is_separator(&text) {
+        segments.push(ChapterSegment::separator());
+    } else {
+        segments.push(ChapterSegment::paragraph(text));
+    }
+}
+
+fn is_separator(text: &str) -> bool {
+    let chars = text.chars().filter(|c| *c != ' ').collect::<Vec<_>>();
+    chars.len() >= 3 && chars.iter().all(|c| SEPARATOR_CHARS.contains(c))
+}
+
+/// 生成章节文档，已缓存或已下载的插图会带上本地路径和尺寸
+pub(crate) async fn build_document(aid: &str, cid: &str, content: &str) -> Result<ChapterDocument> {
+    let mut segments = parse_segments(content);
+    for segment in segments.iter_mut() {
+        if segment.kind != ChapterSegmentKind::Image {
+            continue;
+        }
+        if let Some((path, width, height)) = resolve_image(&segment.url).await? {
+            segment.local_path = Some(path);
+            segment.width = width;
+            segment.height = height;
+        }
+    }
+    Ok(ChapterDocument {
+        aid: aid.to_string(),
+        cid: cid.to_string(),
+        segments,
+    })
+}
+
+async fn resolve_image(url: &str) -> Result<Option<(String, Option<i32>, Option<i32>)>> {
+    if let Some(cache) = image_cache::Entity::find_by_url(url).await? {
+        let file_path = format!("{}/{}", get_image_cache_dir(), cache.url_md5);
+        if Path::new(&file_path).exists() {
+            return Ok(Some((file_path, Some(cache.width), Some(cache.height))));
+        }
+    }
+    if let Some(picture) = novel_download_picture::Entity::find_by_url(url).await? {
+        if picture.download_status == DOWNLOAD_STATUS_SUCCESS {
+            let file_path = novel_dir(&picture.source_id, &picture.language, &picture.aid)?
+                .join(format!("picture_{}", picture.url_md5));
+            let path = file_path
+                .to_str()
+                .ok_or_else(|| anyhow!("Invalid picture path: {}", file_path.display()))?
+                .to_string();
+            if !file_path.exists() {
+                return Ok(None);
+            }
+            // 下载的插图没有记录尺寸，只读取文件头
+            let (width, height) = tokio::task::spawn_blocking(move || {
+                ImageReader::open(&file_path)?
+                    .with_guessed_format()?
+                    .into_dimensions()
+                    .map_err(anyhow::Error::from)
+            })
+            .await??;
+            return Ok(Some((path, Some(width as i32), Some(height as i32))));
+        }
+    }
+    Ok(None)
+}
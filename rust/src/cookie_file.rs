@@ -0,0 +1,169 @@
+//! cookie 的导入导出，支持 Netscape 的 cookies.txt 和浏览器扩展（Cookie-Editor 等）导出的 JSON
+//! JSON 也可以是 Playwright 的 {"cookies": [...]}
+
+use crate::database::entities::Cookie;
+use crate::Result;
+use anyhow::anyhow;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CookieFileFormat {
+    Netscape,
+    Json,
+}
+
+const NETSCAPE_HEADER: &str = "# Netscape HTTP Cookie File";
+const HTTP_ONLY_PREFIX: &str = "#HttpOnly_";
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonCookie {
+    domain: String,
+    name: String,
+    value: String,
+    #[serde(default = "root_path")]
+    path: String,
+    #[serde(default)]
+    secure: bool,
+    #[serde(default)]
+    http_only: bool,
+    /// 没有时按 domain 是否以 . 开头判断
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    host_only: Option<bool>,
+    /// 秒，可以带小数，Playwright 使用 expires 且会话 cookie 为 -1
+    #[serde(default, alias = "expires", skip_serializing_if = "Option::is_none")]
+    expiration_date: Option<f64>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum JsonCookies {
+    List(Vec<JsonCookie>),
+    Wrapped { cookies: Vec<JsonCookie> },
+}
+
+fn root_path() -> String {
+    "/".to_string()
+}
+
+/// 解析出的 cookie 的 account_id 为 0，由导入时设置
+pub(crate) fn parse_cookies(format: CookieFileFormat, text: &str) -> Result<Vec<Cookie>> {
+    match format {
+        CookieFileFormat::Netscape => parse_netscape(text),
+        CookieFileFormat::Json => parse_json(text),
+    }
+}
+
+pub(crate) fn format_cookies(format: CookieFileFormat, cookies: &[Cookie]) -> Result<String> {
+    match format {
+        CookieFileFormat::Netscape => Ok(to_netscape(cookies)),
+        CookieFileFormat::Json => to_json(cookies),
+    }
+}
+
+// 每行为 domain, include_subdomains, path, secure, expires, name, value，以 tab 分隔
+// HttpOnly 的 cookie 在 domain 前加 #HttpOnly_，其他 # 开头的行为注释
+fn parse_netscape(text: &str) -> Result<Vec<Cookie>> {
+    let mut cookies = vec![];
+    for (index, line) in text.lines().enumerate() {
+        let line = line.trim_end_matches('\r');
+        let (line, http_only) = match line.strip_prefix(HTTP_ONLY_PREFIX) {
+            Some(line) => (line, true),
+            None => (line, false),
+        };
+        if line.trim().is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let fields = line.splitn(7, '\t').collect::<Vec<_>>();
+        let [domain, include_subdomains, path, secure, expires, name, value] = fields[..] else {
+            return Err(anyhow!("Invalid cookie at line {}", index + 1));
+        };
+        let expires = expires
+            .trim()
+            .parse::<i64>()
+            .map_err(|_| anyhow!("Invalid cookie expires at line {}", index + 1))?;
+        cookies.push(Cookie {
+            account_id: 0,
+            domain: domain.trim().to_string(),
+            name: name.to_string(),
+            value: value.to_string(),
+            path: path.to_string(),
+            expires: (expires > 0).then_some(expires),
+            secure: Some(secure.eq_ignore_ascii_case("TRUE")),
+            http_only: Some(http_only),
+            host_only: Some(!include_subdomains.eq_ignore_ascii_case("TRUE")),
+        });
+    }
+    Ok(cookies)
+}
+
+fn parse_json(text: &str) -> Result<Vec<Cookie>> {
+    let cookies = match serde_json::from_str(text.trim())? {
+        JsonCookies::List(cookies) => cookies,
+        JsonCookies::Wrapped { cookies } => cookies,
+    };
+    Ok(cookies
+        .into_iter()
+        .map(|cookie| Cookie {
+            account_id: 0,
+            host_only: Some(cookie.host_only.unwrap_or(!cookie.domain.starts_with('.'))),
+            domain: cookie.domain,
+            name: cookie.name,
+            value: cookie.value,
+            path: cookie.path,
+            expires: cookie
+                .expiration_date
+                .filter(|expires| *expires > 0.0)
+                .map(|expires| expires as i64),
+            secure: Some(cookie.secure),
+            http_only: Some(cookie.http_only),
+        })
+        .collect())
+}
+
+fn to_netscape(cookies: &[Cookie]) -> String {
+    let mut text = format!("{NETSCAPE_HEADER}\n\n");
+    for cookie in cookies {
+        let prefix = if cookie.http_only.unwrap_or(false) {
+            HTTP_ONLY_PREFIX
+        } else {
+            ""
+        };
+        let bool_text = |value: bool| if value { "TRUE" } else { "FALSE" };
+        // 域名 cookie 按惯例在 domain 前加 .
+        let host_only = cookie.host_only.unwrap_or(false);
+        let dot = if host_only || cookie.domain.starts_with('.') {
+            ""
+        } else {
+            "."
+        };
+        text.push_str(&format!(
+            "{prefix}{dot}{}\t{}\t{}\t{}\t{}\t{}\t{}\n",
+            cookie.domain,
+            bool_text(!host_only),
+            cookie.path,
+            bool_text(cookie.secure.unwrap_or(false)),
+            cookie.expires.unwrap_or(0),
+            cookie.name,
+            cookie.value,
+        ));
+    }
+    text
+}
+
+fn to_json(cookies: &[Cookie]) -> Result<String> {
+    let cookies = cookies
+        .iter()
+        .map(|cookie| JsonCookie {
+            domain: cookie.domain.clone(),
+            name: cookie.name.clone(),
+            value: cookie.value.clone(),
+            path: cookie.path.clone(),
+            secure: cookie.secure.unwrap_or(false),
+            http_only: cookie.http_only.unwrap_or(false),
+            host_only: Some(cookie.host_only.unwrap_or(false)),
+            expiration_date: cookie.expires.map(|expires| expires as f64),
+        })
+        .collect::<Vec<_>>();
+    Ok(serde_json::to_string_pretty(&cookies)?)
+}
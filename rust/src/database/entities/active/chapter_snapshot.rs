@@ -0,0 +1,91 @@
+use sea_orm::{prelude::*, Set};
+use serde::{Deserialize, Serialize};
+use std::ops::Deref;
+
+/// 更新检查时记录的已知章节，用于和最新目录比较
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "chapter_snapshot")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub novel_id: String,
+    pub chapter_ids: String, // JSON 数组
+    pub check_time: i64,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+pub(super) mod migrations {
+    pub(crate) mod m000001_create_table_chapter_snapshot {
+        use sea_orm::{ConnectionTrait, Schema};
+        use sea_orm_migration::{MigrationName, MigrationTrait, SchemaManager};
+
+        pub struct Migration;
+
+        impl MigrationName for Migration {
+            fn name(&self) -> &str {
+                "m000001_create_table_chapter_snapshot"
+            }
+        }
+
+        #[async_trait::async_trait]
+        impl MigrationTrait for Migration {
+            async fn up(
+                &self,
+                manager: &SchemaManager,
+            ) -> std::result::Result<(), sea_orm_migration::DbErr> {
+                let db = manager.get_connection();
+                let backend = db.get_database_backend();
+                let schema = Schema::new(backend);
+                manager
+                    .create_table(
+                        schema
+                            .create_table_from_entity(super::super::Entity)
+                            .if_not_exists()
+                            .to_owned(),
+                    )
+                    .await?;
+                Ok(())
+            }
+
+            async fn down(
+                &self,
+                _manager: &SchemaManager,
+            ) -> std::result::Result<(), sea_orm_migration::DbErr> {
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Entity {
+    /// 获取已知章节，没有记录时返回 None
+    pub async fn find_chapter_ids(novel_id: &str) -> crate::Result<Option<Vec<String>>> {
+        let db = super::get_connect().await;
+        let snapshot = Entity::find_by_id(novel_id).one(db.deref()).await?;
+        match snapshot {
+            Some(snapshot) => Ok(Some(serde_json::from_str(&snapshot.chapter_ids)?)),
+            None => Ok(None),
+        }
+    }
+
+    pub async fn save_chapter_ids(novel_id: &str, chapter_ids: &[String]) -> crate::Result<()> {
+        let db = super::get_connect().await;
+        let model = ActiveModel {
+            novel_id: Set(novel_id.to_string()),
+            chapter_ids: Set(serde_json::to_string(chapter_ids)?),
+            check_time: Set(chrono::Utc::now().timestamp()),
+        };
+        Entity::insert(model)
+            .on_conflict(
+                sea_orm::sea_query::OnConflict::column(Column::NovelId)
+                    .update_columns([Column::ChapterIds, Column::CheckTime])
+                    .to_owned(),
+            )
+            .exec(db.deref())
+            .await?;
+        Ok(())
+    }
+}
@@ -4,12 +4,15 @@ use sea_orm_migration::{MigrationTrait, MigratorTrait};
 use std::ops::Deref;
 
 pub mod chapter_cache;
+pub mod chapter_snapshot;
 pub mod image_cache;
 pub mod novel_download;
 pub mod novel_download_chapter;
 pub mod novel_download_picture;
 pub mod novel_download_volume;
+pub mod novel_update;
 pub mod reading_history;
+pub mod recommend_log;
 pub mod search_history;
 pub mod sign_log;
 pub mod web_cache;
@@ -29,17 +32,31 @@ pub const DOWNLOAD_STATUS_NOT_DOWNLOAD: i32 = 0;
 pub const DOWNLOAD_STATUS_SUCCESS: i32 = 1;
 pub const DOWNLOAD_STATUS_FAILED: i32 = 2;
 pub const DOWNLOAD_STATUS_DELETING: i32 = 3;
+pub const DOWNLOAD_STATUS_PAUSED: i32 = 4;
 
 async fn get_connect() -> tokio::sync::MutexGuard<'static, DatabaseConnection> {
     ACTIVE_DB_CONNECT.get().unwrap().lock().await
 }
 
-pub(crate) async fn remove_download_data(novel_id: &str) -> crate::Result<()> {
+pub(crate) async fn remove_download_data(
+    source_id: &str,
+    language: &str,
+    novel_id: &str,
+) -> crate::Result<()> {
     let db = get_connect().await;
-    novel_download::Entity::delete_by_id(novel_id).exec(db.deref()).await?;
-    novel_download_volume::Entity::delete_by_novel_id(db.deref(), novel_id).await?;
-    novel_download_chapter::Entity::delete_by_novel_id(db.deref(),novel_id).await?;
-    novel_download_picture::Entity::delete_by_novel_id(db.deref(),novel_id).await?;
+    novel_download::Entity::delete_by_id((
+        novel_id.to_string(),
+        source_id.to_string(),
+        language.to_string(),
+    ))
+    .exec(db.deref())
+    .await?;
+    novel_download_volume::Entity::delete_by_novel_id(db.deref(), source_id, language, novel_id)
+        .await?;
+    novel_download_chapter::Entity::delete_by_novel_id(db.deref(), source_id, language, novel_id)
+        .await?;
+    novel_download_picture::Entity::delete_by_novel_id(db.deref(), source_id, language, novel_id)
+        .await?;
     Ok(())
 }
 
@@ -111,6 +128,9 @@ impl MigratorTrait for Migrator {
             Box::new(
                 novel_download::migrations::M000005IdxCoverUrlNovelDownload,
             ),
+            Box::new(
+                novel_download::migrations::M000006AddPriorityNovelDownload,
+            ),
             Box::new(
                 novel_download_volume::migrations::M000001CreateTableNovelDownloadVolume,
             ),
@@ -147,6 +167,72 @@ impl MigratorTrait for Migrator {
             Box::new(
                 novel_download_picture::migrations::M000005AddUrlMd5NovelDownloadPicture,
             ),
+            Box::new(
+                novel_update::migrations::m000001_create_table_novel_updates::Migration,
+            ),
+            Box::new(
+                novel_update::migrations::m000002_idx_novel_updates_novel_chapter::Migration,
+            ),
+            Box::new(
+                chapter_snapshot::migrations::m000001_create_table_chapter_snapshot::Migration,
+            ),
+            Box::new(
+                novel_download::migrations::M000007AddSourceIdNovelDownload,
+            ),
+            Box::new(
+                reading_history::migrations::m000005_add_source_id::Migration,
+            ),
+            Box::new(
+                novel_update::migrations::m000003_add_source_id::Migration,
+            ),
+            Box::new(
+                novel_download::migrations::M000008AddLanguageNovelDownload,
+            ),
+            Box::new(
+                recommend_log::migrations::m000001_create_table_recommend_log::Migration,
+            ),
+            Box::new(
+                recommend_log::migrations::m000002_idx_recommend_log_date::Migration,
+            ),
+            Box::new(
+                reading_history::migrations::m000006_add_account_id::Migration,
+            ),
+            Box::new(
+                sign_log::migrations::m000002_add_account_id::Migration,
+            ),
+            Box::new(
+                recommend_log::migrations::m000003_add_account_id::Migration,
+            ),
+            Box::new(
+                novel_update::migrations::m000004_idx_novel_updates_source_chapter::Migration,
+            ),
+            Box::new(
+                reading_history::migrations::m000007_account_primary_key::Migration,
+            ),
+            Box::new(
+                novel_download_volume::migrations::m000004_source_primary_key::Migration,
+            ),
+            Box::new(
+                novel_download_chapter::migrations::m000005_source_primary_key::Migration,
+            ),
+            Box::new(
+                novel_download_picture::migrations::m000006_source_primary_key::Migration,
+            ),
+            Box::new(
+                novel_download::migrations::m000009_source_primary_key::Migration,
+            ),
+            Box::new(
+                novel_download::migrations::m000010_language_primary_key::Migration,
+            ),
+            Box::new(
+                novel_download_volume::migrations::m000005_language_primary_key::Migration,
+            ),
+            Box::new(
+                novel_download_chapter::migrations::m000006_language_primary_key::Migration,
+            ),
+            Box::new(
+                novel_download_picture::migrations::m000007_language_primary_key::Migration,
+            ),
         ]
     }
 }
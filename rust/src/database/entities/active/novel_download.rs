@@ -29,6 +29,13 @@ pub struct Model {
     pub is_animated: bool,
     pub fin_update: String,
     pub status: String,
+    // 下载优先级，数值越大越先下载
+    pub priority: i32,
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub source_id: String,
+    // 下载时的内容语言，见 Language::code，同一本小说的简繁两个版本分开下载
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub language: String,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -47,10 +54,32 @@ impl Entity {
         Ok(())
     }
 
-    pub async fn add_one_download_chapter_count(novel_id: &str) -> Result<(), DbErr> {
+    pub async fn add_one_download_chapter_count(
+        source_id: &str,
+        language: &str,
+        novel_id: &str,
+    ) -> Result<(), DbErr> {
         Entity::update_many()
             .col_expr(Column::DownloadChapterCount, Expr::col(Column::DownloadChapterCount).add(1))
+            .filter(Column::SourceId.eq(source_id))
+            .filter(Column::NovelId.eq(novel_id))
+            .filter(Column::Language.eq(language))
+            .exec(get_connect().await.deref())
+            .await?;
+        Ok(())
+    }
+
+    pub async fn add_choose_chapter_count(
+        source_id: &str,
+        language: &str,
+        novel_id: &str,
+        count: i32,
+    ) -> Result<(), DbErr> {
+        Entity::update_many()
+            .col_expr(Column::ChooseChapterCount, Expr::col(Column::ChooseChapterCount).add(count))
+            .filter(Column::SourceId.eq(source_id))
             .filter(Column::NovelId.eq(novel_id))
+            .filter(Column::Language.eq(language))
             .exec(get_connect().await.deref())
             .await?;
         Ok(())
@@ -64,14 +93,22 @@ impl Entity {
             .await
     }
 
-    pub async fn find_by_novel_id(novel_id: &str) -> Result<Option<Model>, DbErr> {
+    pub async fn find_by_novel_id(
+        source_id: &str,
+        language: &str,
+        novel_id: &str,
+    ) -> Result<Option<Model>, DbErr> {
         Entity::find()
+            .filter(Column::SourceId.eq(source_id))
             .filter(Column::NovelId.eq(novel_id))
+            .filter(Column::Language.eq(language))
             .one(get_connect().await.deref())
             .await
     }
 
     pub async fn upsert(
+        source_id: &str,
+        language: &str,
         novel_id: &str,
         novel_name: &str,
         download_status: i32,
@@ -105,11 +142,18 @@ impl Entity {
             is_animated: Set(is_animated),
             fin_update: Set(fin_update.to_string()),
             status: Set(status.to_string()),
+            priority: Set(0),
+            source_id: Set(source_id.to_string()),
+            language: Set(language.to_string()),
         };
 
         Entity::insert(model)
             .on_conflict(
-                sea_orm::sea_query::OnConflict::column(Column::NovelId)
+                sea_orm::sea_query::OnConflict::columns([
+                    Column::SourceId,
+                    Column::NovelId,
+                    Column::Language,
+                ])
                     .update_columns([
                         Column::NovelName,
                         Column::DownloadStatus,
@@ -152,17 +196,23 @@ impl Entity {
     }
 
     pub async fn update_cover_download_status(
+        source_id: &str,
+        language: &str,
         novel_id: &str,
         cover_download_status: i32,
     ) -> Result<(), DbErr> {
         let model = ActiveModel {
+            source_id: Set(source_id.to_string()),
             novel_id: Set(novel_id.to_string()),
+            language: Set(language.to_string()),
             cover_download_status: Set(cover_download_status),
             ..Default::default()
         };
 
         Entity::update(model)
+            .filter(Column::SourceId.eq(source_id))
             .filter(Column::NovelId.eq(novel_id))
+            .filter(Column::Language.eq(language))
             .exec(get_connect().await.deref())
             .await?;
 
@@ -186,6 +236,10 @@ impl Entity {
         Ok(())
     }
 
+    pub async fn find_all() -> Result<Vec<Model>, DbErr> {
+        Entity::find().all(get_connect().await.deref()).await
+    }
+
     pub async fn find_all_ordered_by_create_time(
         db: &DatabaseConnection,
     ) -> crate::Result<Vec<Model>> {
@@ -205,13 +259,71 @@ impl Entity {
     pub async fn find_first_not_started() -> Result<Option<Model>, DbErr> {
         Entity::find()
             .filter(Column::DownloadStatus.eq(0))
+            .order_by(Column::Priority, Order::Desc)
+            .order_by(Column::CreateTime, Order::Asc)
             .one(get_connect().await.deref())
             .await
     }
 
-    pub async fn update_status(novel_id: &str, status: i32) -> Result<(), DbErr> {
+    pub async fn max_priority() -> Result<i32, DbErr> {
+        let top = Entity::find()
+            .order_by(Column::Priority, Order::Desc)
+            .one(get_connect().await.deref())
+            .await?;
+        Ok(top.map(|model| model.priority).unwrap_or(0))
+    }
+
+    pub async fn update_priority(
+        source_id: &str,
+        language: &str,
+        novel_id: &str,
+        priority: i32,
+    ) -> Result<(), DbErr> {
+        Entity::update_many()
+            .filter(Column::SourceId.eq(source_id))
+            .filter(Column::NovelId.eq(novel_id))
+            .filter(Column::Language.eq(language))
+            .set(ActiveModel {
+                priority: Set(priority),
+                ..Default::default()
+            })
+            .exec(get_connect().await.deref())
+            .await?;
+        Ok(())
+    }
+
+    /// 仅当状态为 from_status 时修改为 to_status，返回是否修改成功
+    pub async fn update_status_from(
+        source_id: &str,
+        language: &str,
+        novel_id: &str,
+        from_status: i32,
+        to_status: i32,
+    ) -> Result<bool, DbErr> {
+        let result = Entity::update_many()
+            .filter(Column::SourceId.eq(source_id))
+            .filter(Column::NovelId.eq(novel_id))
+            .filter(Column::Language.eq(language))
+            .filter(Column::DownloadStatus.eq(from_status))
+            .set(ActiveModel {
+                download_status: Set(to_status),
+                ..Default::default()
+            })
+            .exec(get_connect().await.deref())
+            .await?;
+        Ok(result.rows_affected > 0)
+    }
+
+    pub async fn update_status(
+        source_id: &str,
+        language: &str,
+        novel_id: &str,
+        status: i32,
+    ) -> Result<(), DbErr> {
         Entity::update_many()
+            .filter(Column::SourceId.eq(source_id))
             .filter(Column::NovelId.eq(novel_id))
+            .filter(Column::Language.eq(language))
             .set(ActiveModel {
                 download_status: Set(status),
                 ..Default::default()
@@ -221,14 +333,23 @@ impl Entity {
         Ok(())
     }
 
-    pub async fn update_download_chapter_count(novel_id: &str, download_chapter_count: i32) -> Result<(), DbErr> {
+    pub async fn update_download_chapter_count(
+        source_id: &str,
+        language: &str,
+        novel_id: &str,
+        download_chapter_count: i32,
+    ) -> Result<(), DbErr> {
         let model = ActiveModel {
+            source_id: Set(source_id.to_string()),
             novel_id: Set(novel_id.to_string()),
+            language: Set(language.to_string()),
             download_chapter_count: Set(download_chapter_count),
             ..Default::default()
         };
         Entity::update(model)
+            .filter(Column::SourceId.eq(source_id))
             .filter(Column::NovelId.eq(novel_id))
+            .filter(Column::Language.eq(language))
             .exec(get_connect().await.deref())
             .await?;
         Ok(())
@@ -238,6 +359,8 @@ impl Entity {
 pub mod migrations {
     use super::Column;
     use super::Entity;
+    use sea_orm::EntityName;
+    use sea_orm::IdenStatic;
     use sea_orm_migration::prelude::*;
 
     pub struct M000001CreateTableNovelDownload;
@@ -460,4 +583,401 @@ pub mod migrations {
             Ok(())
         }
     }
+
+    pub struct M000006AddPriorityNovelDownload;
+
+    impl MigrationName for M000006AddPriorityNovelDownload {
+        fn name(&self) -> &str {
+            "m000006_add_priority_novel_download"
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl MigrationTrait for M000006AddPriorityNovelDownload {
+        async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+            // 添加 priority 列
+            if !manager
+                .has_column(Entity.table_name(), Column::Priority.as_str())
+                .await?
+            {
+                manager
+                    .alter_table(
+                        Table::alter()
+                            .table(Entity)
+                            .add_column(
+                                ColumnDef::new(Column::Priority)
+                                    .integer()
+                                    .not_null()
+                                    .default(0),
+                            )
+                            .to_owned(),
+                    )
+                    .await?;
+            }
+
+            manager
+                .create_index(
+                    Index::create()
+                        .name("idx_novel_download_priority")
+                        .table(Entity)
+                        .if_not_exists()
+                        .col(Column::Priority)
+                        .to_owned(),
+                )
+                .await?;
+
+            Ok(())
+        }
+
+        async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+            manager
+                .drop_index(
+                    Index::drop()
+                        .name("idx_novel_download_priority")
+                        .to_owned(),
+                )
+                .await?;
+
+            Ok(())
+        }
+    }
+
+    pub struct M000007AddSourceIdNovelDownload;
+
+    impl MigrationName for M000007AddSourceIdNovelDownload {
+        fn name(&self) -> &str {
+            "m000007_add_source_id_novel_download"
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl MigrationTrait for M000007AddSourceIdNovelDownload {
+        async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+            // 添加 source_id 列，旧数据都属于默认书源
+            if !manager
+                .has_column(Entity.table_name(), Column::SourceId.as_str())
+                .await?
+            {
+                manager
+                    .alter_table(
+                        Table::alter()
+                            .table(Entity)
+                            .add_column(
+                                ColumnDef::new(Column::SourceId)
+                                    .string()
+                                    .not_null()
+                                    .default(crate::source::DEFAULT_SOURCE_ID),
+                            )
+                            .to_owned(),
+                    )
+                    .await?;
+            }
+
+            Ok(())
+        }
+
+        async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
+            Ok(())
+        }
+    }
+
+    pub struct M000008AddLanguageNovelDownload;
+
+    impl MigrationName for M000008AddLanguageNovelDownload {
+        fn name(&self) -> &str {
+            "m000008_add_language_novel_download"
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl MigrationTrait for M000008AddLanguageNovelDownload {
+        async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+            // 添加 language 列，旧数据都是简体
+            if !manager
+                .has_column(Entity.table_name(), Column::Language.as_str())
+                .await?
+            {
+                manager
+                    .alter_table(
+                        Table::alter()
+                            .table(Entity)
+                            .add_column(
+                                ColumnDef::new(Column::Language)
+                                    .string()
+                                    .not_null()
+                                    .default("zh-Hans"),
+                            )
+                            .to_owned(),
+                    )
+                    .await?;
+            }
+
+            Ok(())
+        }
+
+        async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
+            Ok(())
+        }
+    }
+
+    pub(crate) mod m000009_source_primary_key {
+        use sea_orm::sea_query::{Alias, ColumnDef, Index, Table};
+        use sea_orm::ConnectionTrait;
+        use sea_orm_migration::{MigrationName, MigrationTrait, SchemaManager};
+
+        pub struct Migration;
+
+        impl MigrationName for Migration {
+            fn name(&self) -> &str {
+                "m000009_source_primary_key_novel_download"
+            }
+        }
+
+        #[async_trait::async_trait]
+        impl MigrationTrait for Migration {
+            async fn up(
+                &self,
+                manager: &SchemaManager,
+            ) -> std::result::Result<(), sea_orm_migration::DbErr> {
+                let string_col = |name: &str| {
+                    ColumnDef::new(Alias::new(name))
+                        .string()
+                        .not_null()
+                        .to_owned()
+                };
+                let int_col = |name: &str| {
+                    ColumnDef::new(Alias::new(name))
+                        .integer()
+                        .not_null()
+                        .to_owned()
+                };
+                let big_int_col = |name: &str| {
+                    ColumnDef::new(Alias::new(name))
+                        .big_integer()
+                        .not_null()
+                        .to_owned()
+                };
+                // 不同书源的小说ID可能相同，主键改为 (source_id, novel_id)
+                manager
+                    .create_table(
+                        Table::create()
+                            .table(Alias::new("novel_download_new"))
+                            .col(string_col("novel_id"))
+                            .col(string_col("novel_name"))
+                            .col(int_col("download_status"))
+                            .col(string_col("cover_url"))
+                            .col(int_col("cover_download_status"))
+                            .col(string_col("author"))
+                            .col(string_col("tags"))
+                            .col(int_col("choose_chapter_count"))
+                            .col(int_col("download_chapter_count"))
+                            .col(big_int_col("create_time"))
+                            .col(big_int_col("download_time"))
+                            .col(string_col("introduce"))
+                            .col(string_col("trending"))
+                            .col(
+                                ColumnDef::new(Alias::new("is_animated"))
+                                    .boolean()
+                                    .not_null(),
+                            )
+                            .col(string_col("fin_update"))
+                            .col(string_col("status"))
+                            .col(int_col("priority").default(0))
+                            .col(string_col("source_id"))
+                            .col(string_col("language"))
+                            .primary_key(
+                                Index::create()
+                                    .col(Alias::new("source_id"))
+                                    .col(Alias::new("novel_id")),
+                            )
+                            .to_owned(),
+                    )
+                    .await?;
+                manager
+                    .get_connection()
+                    .execute_unprepared(
+                        "INSERT INTO novel_download_new (novel_id, novel_name, download_status, \
+                        cover_url, cover_download_status, author, tags, choose_chapter_count, \
+                        download_chapter_count, create_time, download_time, introduce, trending, \
+                        is_animated, fin_update, status, priority, source_id, language) \
+                        SELECT novel_id, novel_name, download_status, cover_url, \
+                        cover_download_status, author, tags, choose_chapter_count, \
+                        download_chapter_count, create_time, download_time, introduce, trending, \
+                        is_animated, fin_update, status, priority, source_id, language \
+                        FROM novel_download",
+                    )
+                    .await?;
+                manager
+                    .drop_table(Table::drop().table(Alias::new("novel_download")).to_owned())
+                    .await?;
+                manager
+                    .rename_table(
+                        Table::rename()
+                            .table(
+                                Alias::new("novel_download_new"),
+                                Alias::new("novel_download"),
+                            )
+                            .to_owned(),
+                    )
+                    .await?;
+                // 旧表上的索引随表删除，重新创建
+                for (name, col) in [
+                    ("idx_novel_download_cover_url", "cover_url"),
+                    ("idx_novel_download_create_time", "create_time"),
+                    ("idx_novel_download_download_time", "download_time"),
+                    ("idx_novel_download_priority", "priority"),
+                ] {
+                    manager
+                        .create_index(
+                            Index::create()
+                                .if_not_exists()
+                                .name(name)
+                                .table(Alias::new("novel_download"))
+                                .col(Alias::new(col))
+                                .to_owned(),
+                        )
+                        .await?;
+                }
+                Ok(())
+            }
+
+            async fn down(
+                &self,
+                _manager: &SchemaManager,
+            ) -> std::result::Result<(), sea_orm_migration::DbErr> {
+                Ok(())
+            }
+        }
+    }
+
+    pub(crate) mod m000010_language_primary_key {
+        use sea_orm::sea_query::{Alias, ColumnDef, Index, Table};
+        use sea_orm::ConnectionTrait;
+        use sea_orm_migration::{MigrationName, MigrationTrait, SchemaManager};
+
+        pub struct Migration;
+
+        impl MigrationName for Migration {
+            fn name(&self) -> &str {
+                "m000010_language_primary_key_novel_download"
+            }
+        }
+
+        #[async_trait::async_trait]
+        impl MigrationTrait for Migration {
+            async fn up(
+                &self,
+                manager: &SchemaManager,
+            ) -> std::result::Result<(), sea_orm_migration::DbErr> {
+                let string_col = |name: &str| {
+                    ColumnDef::new(Alias::new(name))
+                        .string()
+                        .not_null()
+                        .to_owned()
+                };
+                let int_col = |name: &str| {
+                    ColumnDef::new(Alias::new(name))
+                        .integer()
+                        .not_null()
+                        .to_owned()
+                };
+                let big_int_col = |name: &str| {
+                    ColumnDef::new(Alias::new(name))
+                        .big_integer()
+                        .not_null()
+                        .to_owned()
+                };
+                // 同一本小说的简繁版本分开下载，主键加上 language
+                manager
+                    .create_table(
+                        Table::create()
+                            .table(Alias::new("novel_download_new"))
+                            .col(string_col("novel_id"))
+                            .col(string_col("novel_name"))
+                            .col(int_col("download_status"))
+                            .col(string_col("cover_url"))
+                            .col(int_col("cover_download_status"))
+                            .col(string_col("author"))
+                            .col(string_col("tags"))
+                            .col(int_col("choose_chapter_count"))
+                            .col(int_col("download_chapter_count"))
+                            .col(big_int_col("create_time"))
+                            .col(big_int_col("download_time"))
+                            .col(string_col("introduce"))
+                            .col(string_col("trending"))
+                            .col(
+                                ColumnDef::new(Alias::new("is_animated"))
+                                    .boolean()
+                                    .not_null(),
+                            )
+                            .col(string_col("fin_update"))
+                            .col(string_col("status"))
+                            .col(int_col("priority").default(0))
+                            .col(string_col("source_id"))
+                            .col(string_col("language"))
+                            .primary_key(
+                                Index::create()
+                                    .col(Alias::new("source_id"))
+                                    .col(Alias::new("novel_id"))
+                                    .col(Alias::new("language")),
+                            )
+                            .to_owned(),
+                    )
+                    .await?;
+                manager
+                    .get_connection()
+                    .execute_unprepared(
+                        "INSERT INTO novel_download_new (novel_id, novel_name, download_status, \
+                        cover_url, cover_download_status, author, tags, choose_chapter_count, \
+                        download_chapter_count, create_time, download_time, introduce, trending, \
+                        is_animated, fin_update, status, priority, source_id, language) \
+                        SELECT novel_id, novel_name, download_status, cover_url, \
+                        cover_download_status, author, tags, choose_chapter_count, \
+                        download_chapter_count, create_time, download_time, introduce, trending, \
+                        is_animated, fin_update, status, priority, source_id, language \
+                        FROM novel_download",
+                    )
+                    .await?;
+                manager
+                    .drop_table(Table::drop().table(Alias::new("novel_download")).to_owned())
+                    .await?;
+                manager
+                    .rename_table(
+                        Table::rename()
+                            .table(
+                                Alias::new("novel_download_new"),
+                                Alias::new("novel_download"),
+                            )
+                            .to_owned(),
+                    )
+                    .await?;
+                // 旧表上的索引随表删除，重新创建
+                for (name, col) in [
+                    ("idx_novel_download_cover_url", "cover_url"),
+                    ("idx_novel_download_create_time", "create_time"),
+                    ("idx_novel_download_download_time", "download_time"),
+                    ("idx_novel_download_priority", "priority"),
+                ] {
+                    manager
+                        .create_index(
+                            Index::create()
+                                .if_not_exists()
+                                .name(name)
+                                .table(Alias::new("novel_download"))
+                                .col(Alias::new(col))
+                                .to_owned(),
+                        )
+                        .await?;
+                }
+                Ok(())
+            }
+
+            async fn down(
+                &self,
+                _manager: &SchemaManager,
+            ) -> std::result::Result<(), sea_orm_migration::DbErr> {
+                Ok(())
+            }
+        }
+    }
 }
@@ -16,6 +16,8 @@ use super::get_connect;
 /// - download_status: 下载状态（0: 未开始, 1: 下载中, 2: 已完成, 3: 错误）
 /// - total_picture: 章节总图片数
 /// - chapter_idx: 章节序号，用于排序
+/// - source_id: 书源ID，和 language、id 一起作为主键
+/// - language: 下载时的语言，简繁版本分开保存
 #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
 #[sea_orm(table_name = "novel_download_chapter")]
 pub struct Model {
@@ -28,6 +30,10 @@ pub struct Model {
     pub download_status: i32,
     pub total_picture: i32,
     pub chapter_idx: i32,
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub source_id: String,
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub language: String,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -46,16 +52,29 @@ impl Entity {
         Ok(())
     }
 
-    pub async fn delete_by_novel_id(conn: &impl ConnectionTrait, novel_id: &str) -> Result<(), DbErr> {
+    pub async fn delete_by_novel_id(
+        conn: &impl ConnectionTrait,
+        source_id: &str,
+        language: &str,
+        novel_id: &str,
+    ) -> Result<(), DbErr> {
         Entity::delete_many()
+            .filter(Column::SourceId.eq(source_id))
+            .filter(Column::Language.eq(language))
             .filter(Column::Aid.eq(novel_id))
             .exec(conn)
             .await?;
         Ok(())
     }
     /// 根据章节ID查找
-    pub async fn find_by_id(id: &str) -> Result<Option<Model>, DbErr> {
+    pub async fn find_by_id(
+        source_id: &str,
+        language: &str,
+        id: &str,
+    ) -> Result<Option<Model>, DbErr> {
         Entity::find()
+            .filter(Column::SourceId.eq(source_id))
+            .filter(Column::Language.eq(language))
             .filter(Column::Id.eq(id))
             .one(get_connect().await.deref())
             .await
@@ -72,8 +91,14 @@ impl Entity {
     }
 
     /// 根据卷ID查找所有章节，按章节序号排序
-    pub async fn find_by_volume_id(volume_id: &str) -> Result<Vec<Model>, DbErr> {
+    pub async fn find_by_volume_id(
+        source_id: &str,
+        language: &str,
+        volume_id: &str,
+    ) -> Result<Vec<Model>, DbErr> {
         Entity::find()
+            .filter(Column::SourceId.eq(source_id))
+            .filter(Column::Language.eq(language))
             .filter(Column::VolumeId.eq(volume_id))
             .order_by(Column::ChapterIdx, Order::Asc)
             .all(get_connect().await.deref())
@@ -82,6 +107,8 @@ impl Entity {
 
     /// 插入或更新章节信息
     pub async fn upsert(
+        source_id: &str,
+        language: &str,
         id: &str,
         title: &str,
         url: &str,
@@ -100,11 +127,17 @@ impl Entity {
             download_status: Set(download_status),
             total_picture: Set(total_picture),
             chapter_idx: Set(chapter_idx),
+            source_id: Set(source_id.to_string()),
+            language: Set(language.to_string()),
         };
 
         Entity::insert(model)
             .on_conflict(
-                sea_orm::sea_query::OnConflict::column(Column::Id)
+                sea_orm::sea_query::OnConflict::columns([
+                    Column::SourceId,
+                    Column::Language,
+                    Column::Id,
+                ])
                     .update_columns([
                         Column::Title,
                         Column::Url,
@@ -139,6 +172,27 @@ impl Entity {
         Ok(())
     }
 
+    /// 更新章节图片数
+    pub async fn update_total_picture(
+        source_id: &str,
+        language: &str,
+        id: &str,
+        total_picture: i32,
+    ) -> Result<(), DbErr> {
+        Entity::update_many()
+            .filter(Column::SourceId.eq(source_id))
+            .filter(Column::Language.eq(language))
+            .filter(Column::Id.eq(id))
+            .set(ActiveModel {
+                total_picture: Set(total_picture),
+                ..Default::default()
+            })
+            .exec(get_connect().await.deref())
+            .await?;
+
+        Ok(())
+    }
+
     /// 根据小说ID删除所有章节
     pub async fn delete_by_aid(aid: &str) -> Result<(), DbErr> {
         Entity::delete_many()
@@ -178,16 +232,29 @@ impl Entity {
         Ok(())
     }
 
-    pub async fn find_by_novel_id(novel_id: &str) -> crate::Result<Vec<Model>> {
+    pub async fn find_by_novel_id(
+        source_id: &str,
+        language: &str,
+        novel_id: &str,
+    ) -> crate::Result<Vec<Model>> {
         let db = get_connect().await;
         Ok(Self::find()
+            .filter(Column::SourceId.eq(source_id))
+            .filter(Column::Language.eq(language))
             .filter(Column::Aid.eq(novel_id))
             .all(db.deref())
             .await?)
     }
 
-    pub async fn find_incomplete_by_volume(novel_id: &str, volume_id: &str) -> Result<Option<Model>, DbErr> {
+    pub async fn find_incomplete_by_volume(
+        source_id: &str,
+        language: &str,
+        novel_id: &str,
+        volume_id: &str,
+    ) -> Result<Option<Model>, DbErr> {
         Entity::find()
+            .filter(Column::SourceId.eq(source_id))
+            .filter(Column::Language.eq(language))
             .filter(Column::Aid.eq(novel_id))
             .filter(Column::VolumeId.eq(volume_id))
             .filter(Column::DownloadStatus.eq(0))
@@ -197,8 +264,36 @@ impl Entity {
             .await
     }
 
-    pub async fn update_status(novel_id: &str, volume_id: &str, chapter_id: &str, status: i32) -> Result<(), DbErr> {
+    pub async fn find_incomplete_batch_by_volume(
+        source_id: &str,
+        language: &str,
+        novel_id: &str,
+        volume_id: &str,
+        limit: u64,
+    ) -> Result<Vec<Model>, DbErr> {
+        Entity::find()
+            .filter(Column::SourceId.eq(source_id))
+            .filter(Column::Language.eq(language))
+            .filter(Column::Aid.eq(novel_id))
+            .filter(Column::VolumeId.eq(volume_id))
+            .filter(Column::DownloadStatus.eq(0))
+            .order_by(Column::ChapterIdx, Order::Asc)
+            .limit(limit)
+            .all(get_connect().await.deref())
+            .await
+    }
+
+    pub async fn update_status(
+        source_id: &str,
+        language: &str,
+        novel_id: &str,
+        volume_id: &str,
+        chapter_id: &str,
+        status: i32,
+    ) -> Result<(), DbErr> {
         Entity::update_many()
+            .filter(Column::SourceId.eq(source_id))
+            .filter(Column::Language.eq(language))
             .filter(Column::Aid.eq(novel_id))
             .filter(Column::VolumeId.eq(volume_id))
             .filter(Column::Id.eq(chapter_id))
@@ -367,4 +462,254 @@ pub mod migrations {
             Ok(())
         }
     }
-} 
\ No newline at end of file
+
+    pub(crate) mod m000005_source_primary_key {
+        use sea_orm::sea_query::{Alias, ColumnDef, Index, Table};
+        use sea_orm::ConnectionTrait;
+        use sea_orm_migration::{MigrationName, MigrationTrait, SchemaManager};
+
+        pub struct Migration;
+
+        impl MigrationName for Migration {
+            fn name(&self) -> &str {
+                "m000005_source_primary_key_novel_download_chapter"
+            }
+        }
+
+        #[async_trait::async_trait]
+        impl MigrationTrait for Migration {
+            async fn up(
+                &self,
+                manager: &SchemaManager,
+            ) -> std::result::Result<(), sea_orm_migration::DbErr> {
+                let string_col = |name: &str| {
+                    ColumnDef::new(Alias::new(name))
+                        .string()
+                        .not_null()
+                        .to_owned()
+                };
+                let int_col = |name: &str| {
+                    ColumnDef::new(Alias::new(name))
+                        .integer()
+                        .not_null()
+                        .to_owned()
+                };
+                // 章节ID只在书源内唯一，加上 source_id，旧数据都属于默认书源
+                manager
+                    .create_table(
+                        Table::create()
+                            .table(Alias::new("novel_download_chapter_new"))
+                            .col(string_col("id"))
+                            .col(string_col("title"))
+                            .col(string_col("url"))
+                            .col(string_col("aid"))
+                            .col(string_col("volume_id"))
+                            .col(int_col("download_status"))
+                            .col(int_col("total_picture"))
+                            .col(int_col("chapter_idx"))
+                            .col(string_col("source_id"))
+                            .primary_key(
+                                Index::create()
+                                    .col(Alias::new("source_id"))
+                                    .col(Alias::new("id")),
+                            )
+                            .to_owned(),
+                    )
+                    .await?;
+                manager
+                    .get_connection()
+                    .execute_unprepared(
+                        "INSERT INTO novel_download_chapter_new (id, title, url, aid, volume_id, \
+                        download_status, total_picture, chapter_idx, source_id) \
+                        SELECT c.id, c.title, c.url, c.aid, c.volume_id, c.download_status, \
+                        c.total_picture, c.chapter_idx, IFNULL(n.source_id, 'wenku8') \
+                        FROM novel_download_chapter c \
+                        LEFT JOIN novel_download n ON n.novel_id = c.aid",
+                    )
+                    .await?;
+                manager
+                    .drop_table(
+                        Table::drop()
+                            .table(Alias::new("novel_download_chapter"))
+                            .to_owned(),
+                    )
+                    .await?;
+                manager
+                    .rename_table(
+                        Table::rename()
+                            .table(
+                                Alias::new("novel_download_chapter_new"),
+                                Alias::new("novel_download_chapter"),
+                            )
+                            .to_owned(),
+                    )
+                    .await?;
+                // 旧表上的索引随表删除，重新创建
+                for (name, col) in [
+                    ("idx_novel_download_chapter_aid", "aid"),
+                    ("idx_novel_download_chapter_volume_id", "volume_id"),
+                ] {
+                    manager
+                        .create_index(
+                            Index::create()
+                                .if_not_exists()
+                                .name(name)
+                                .table(Alias::new("novel_download_chapter"))
+                                .col(Alias::new(col))
+                                .to_owned(),
+                        )
+                        .await?;
+                }
+                manager
+                    .create_index(
+                        Index::create()
+                            .if_not_exists()
+                            .name("idx_novel_download_chapter_aid_volume_id_chapter_idx")
+                            .table(Alias::new("novel_download_chapter"))
+                            .col(Alias::new("source_id"))
+                            .col(Alias::new("aid"))
+                            .col(Alias::new("volume_id"))
+                            .col(Alias::new("chapter_idx"))
+                            .unique()
+                            .to_owned(),
+                    )
+                    .await?;
+                Ok(())
+            }
+
+            async fn down(
+                &self,
+                _manager: &SchemaManager,
+            ) -> std::result::Result<(), sea_orm_migration::DbErr> {
+                Ok(())
+            }
+        }
+    }
+
+    pub(crate) mod m000006_language_primary_key {
+        use sea_orm::sea_query::{Alias, ColumnDef, Index, Table};
+        use sea_orm::ConnectionTrait;
+        use sea_orm_migration::{MigrationName, MigrationTrait, SchemaManager};
+
+        pub struct Migration;
+
+        impl MigrationName for Migration {
+            fn name(&self) -> &str {
+                "m000006_language_primary_key_novel_download_chapter"
+            }
+        }
+
+        #[async_trait::async_trait]
+        impl MigrationTrait for Migration {
+            async fn up(
+                &self,
+                manager: &SchemaManager,
+            ) -> std::result::Result<(), sea_orm_migration::DbErr> {
+                let string_col = |name: &str| {
+                    ColumnDef::new(Alias::new(name))
+                        .string()
+                        .not_null()
+                        .to_owned()
+                };
+                let int_col = |name: &str| {
+                    ColumnDef::new(Alias::new(name))
+                        .integer()
+                        .not_null()
+                        .to_owned()
+                };
+                // 简繁版本的章节分开保存，加上 language，旧数据沿用小说的语言
+                manager
+                    .create_table(
+                        Table::create()
+                            .table(Alias::new("novel_download_chapter_new"))
+                            .col(string_col("id"))
+                            .col(string_col("title"))
+                            .col(string_col("url"))
+                            .col(string_col("aid"))
+                            .col(string_col("volume_id"))
+                            .col(int_col("download_status"))
+                            .col(int_col("total_picture"))
+                            .col(int_col("chapter_idx"))
+                            .col(string_col("source_id"))
+                            .col(string_col("language"))
+                            .primary_key(
+                                Index::create()
+                                    .col(Alias::new("source_id"))
+                                    .col(Alias::new("language"))
+                                    .col(Alias::new("id")),
+                            )
+                            .to_owned(),
+                    )
+                    .await?;
+                manager
+                    .get_connection()
+                    .execute_unprepared(
+                        "INSERT INTO novel_download_chapter_new (id, title, url, aid, volume_id, \
+                        download_status, total_picture, chapter_idx, source_id, language) \
+                        SELECT c.id, c.title, c.url, c.aid, c.volume_id, c.download_status, \
+                        c.total_picture, c.chapter_idx, c.source_id, IFNULL(n.language, 'zh-Hans') \
+                        FROM novel_download_chapter c \
+                        LEFT JOIN novel_download n \
+                        ON n.source_id = c.source_id AND n.novel_id = c.aid",
+                    )
+                    .await?;
+                manager
+                    .drop_table(
+                        Table::drop()
+                            .table(Alias::new("novel_download_chapter"))
+                            .to_owned(),
+                    )
+                    .await?;
+                manager
+                    .rename_table(
+                        Table::rename()
+                            .table(
+                                Alias::new("novel_download_chapter_new"),
+                                Alias::new("novel_download_chapter"),
+                            )
+                            .to_owned(),
+                    )
+                    .await?;
+                // 旧表上的索引随表删除，重新创建
+                for (name, col) in [
+                    ("idx_novel_download_chapter_aid", "aid"),
+                    ("idx_novel_download_chapter_volume_id", "volume_id"),
+                ] {
+                    manager
+                        .create_index(
+                            Index::create()
+                                .if_not_exists()
+                                .name(name)
+                                .table(Alias::new("novel_download_chapter"))
+                                .col(Alias::new(col))
+                                .to_owned(),
+                        )
+                        .await?;
+                }
+                manager
+                    .create_index(
+                        Index::create()
+                            .if_not_exists()
+                            .name("idx_novel_download_chapter_aid_volume_id_chapter_idx")
+                            .table(Alias::new("novel_download_chapter"))
+                            .col(Alias::new("source_id"))
+                            .col(Alias::new("language"))
+                            .col(Alias::new("aid"))
+                            .col(Alias::new("volume_id"))
+                            .col(Alias::new("chapter_idx"))
+                            .unique()
+                            .to_owned(),
+                    )
+                    .await?;
+                Ok(())
+            }
+
+            async fn down(
+                &self,
+                _manager: &SchemaManager,
+            ) -> std::result::Result<(), sea_orm_migration::DbErr> {
+                Ok(())
+            }
+        }
+    }
+}
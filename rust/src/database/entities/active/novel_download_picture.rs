@@ -18,6 +18,8 @@ use super::get_connect;
 /// - url: 图片URL
 /// - url_md5: 图片URL的MD5值，用于去重和缓存
 /// - download_status: 下载状态（0: 未开始, 1: 下载中, 2: 已完成, 3: 错误）
+/// - source_id: 书源ID，和语言、小说、卷、章节ID一起作为主键
+/// - language: 下载时的语言，简繁版本分开保存
 #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
 #[sea_orm(table_name = "novel_download_picture")]
 pub struct Model {
@@ -32,6 +34,10 @@ pub struct Model {
     pub url: String,
     pub url_md5: String,
     pub download_status: i32,
+    #[sea_orm(primary_key)]
+    pub source_id: String,
+    #[sea_orm(primary_key)]
+    pub language: String,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -58,19 +64,46 @@ impl Entity {
             .await
     }
 
+    pub async fn find_incomplete_batch_by_novel(
+        source_id: &str,
+        language: &str,
+        novel_id: &str,
+        limit: u64,
+    ) -> Result<Vec<Model>, DbErr> {
+        Entity::find()
+            .filter(Column::SourceId.eq(source_id))
+            .filter(Column::Language.eq(language))
+            .filter(Column::Aid.eq(novel_id))
+            .filter(Column::DownloadStatus.eq(0))
+            .order_by(Column::PictureIdx, Order::Asc)
+            .limit(limit)
+            .all(get_connect().await.deref())
+            .await
+    }
+
     pub async fn delete_by_novel_id(
         conn: &impl ConnectionTrait,
+        source_id: &str,
+        language: &str,
         novel_id: &str,
     ) -> Result<(), DbErr> {
         Entity::delete_many()
+            .filter(Column::SourceId.eq(source_id))
+            .filter(Column::Language.eq(language))
             .filter(Column::Aid.eq(novel_id))
             .exec(conn)
             .await?;
         Ok(())
     }
 
-    pub async fn find_by_novel_id(novel_id: &str) -> Result<Vec<Model>, DbErr> {
+    pub async fn find_by_novel_id(
+        source_id: &str,
+        language: &str,
+        novel_id: &str,
+    ) -> Result<Vec<Model>, DbErr> {
         Entity::find()
+            .filter(Column::SourceId.eq(source_id))
+            .filter(Column::Language.eq(language))
             .filter(Column::Aid.eq(novel_id))
             .all(get_connect().await.deref())
             .await
@@ -103,6 +136,8 @@ impl Entity {
 
     /// 插入或更新图片信息
     pub async fn upsert(
+        source_id: &str,
+        language: &str,
         aid: &str,
         volume_id: &str,
         chapter_id: &str,
@@ -119,17 +154,30 @@ impl Entity {
             url: Set(url.to_string()),
             url_md5: Set(url_md5.to_string()),
             download_status: Set(download_status),
+            source_id: Set(source_id.to_string()),
+            language: Set(language.to_string()),
         };
 
         Entity::insert(model)
             .on_conflict(
                 sea_orm::sea_query::OnConflict::columns([
+                    Column::SourceId,
+                    Column::Language,
                     Column::Aid,
                     Column::VolumeId,
                     Column::ChapterId,
                     Column::PictureIdx,
                 ])
-                .update_columns([Column::Url, Column::UrlMd5, Column::DownloadStatus])
+                .update_columns([Column::Url, Column::UrlMd5])
+                // 地址没变时保留已有的下载状态，避免重复下载已完成的图片
+                .value(
+                    Column::DownloadStatus,
+                    Expr::cust(
+                        "CASE WHEN novel_download_picture.url_md5 = excluded.url_md5 \
+                         THEN novel_download_picture.download_status \
+                         ELSE excluded.download_status END",
+                    ),
+                )
                 .to_owned(),
             )
             .exec(get_connect().await.deref())
@@ -140,6 +188,8 @@ impl Entity {
 
     /// 更新图片下载状态
     pub async fn update_download_status(
+        source_id: &str,
+        language: &str,
         aid: &str,
         volume_id: &str,
         chapter_id: &str,
@@ -147,16 +197,19 @@ impl Entity {
         download_status: i32,
     ) -> Result<(), DbErr> {
         let model = ActiveModel {
+            source_id: Set(source_id.to_string()),
+            language: Set(language.to_string()),
             aid: Set(aid.to_string()),
             volume_id: Set(volume_id.to_string()),
             chapter_id: Set(chapter_id.to_string()),
             picture_idx: Set(picture_idx),
-            url_md5: Set(String::new()), // 保持原有值
             download_status: Set(download_status),
             ..Default::default()
         };
 
         Entity::update(model)
+            .filter(Column::SourceId.eq(source_id))
+            .filter(Column::Language.eq(language))
             .filter(Column::Aid.eq(aid))
             .filter(Column::VolumeId.eq(volume_id))
             .filter(Column::ChapterId.eq(chapter_id))
@@ -167,6 +220,39 @@ impl Entity {
         Ok(())
     }
 
+    /// 删除章节中序号不小于 picture_idx 的图片，返回被删除的记录
+    pub async fn delete_from_idx(
+        source_id: &str,
+        language: &str,
+        aid: &str,
+        volume_id: &str,
+        chapter_id: &str,
+        picture_idx: i32,
+    ) -> Result<Vec<Model>, DbErr> {
+        let db = get_connect().await;
+        let removed = Entity::find()
+            .filter(Column::SourceId.eq(source_id))
+            .filter(Column::Language.eq(language))
+            .filter(Column::Aid.eq(aid))
+            .filter(Column::VolumeId.eq(volume_id))
+            .filter(Column::ChapterId.eq(chapter_id))
+            .filter(Column::PictureIdx.gte(picture_idx))
+            .all(db.deref())
+            .await?;
+        if !removed.is_empty() {
+            Entity::delete_many()
+                .filter(Column::SourceId.eq(source_id))
+                .filter(Column::Language.eq(language))
+                .filter(Column::Aid.eq(aid))
+                .filter(Column::VolumeId.eq(volume_id))
+                .filter(Column::ChapterId.eq(chapter_id))
+                .filter(Column::PictureIdx.gte(picture_idx))
+                .exec(db.deref())
+                .await?;
+        }
+        Ok(removed)
+    }
+
     /// 根据小说ID删除所有图片
     pub async fn delete_by_aid(aid: &str) -> Result<(), DbErr> {
         Entity::delete_many()
@@ -460,4 +546,237 @@ pub mod migrations {
             Ok(())
         }
     }
+
+    pub(crate) mod m000006_source_primary_key {
+        use sea_orm::sea_query::{Alias, ColumnDef, Index, Table};
+        use sea_orm::ConnectionTrait;
+        use sea_orm_migration::{MigrationName, MigrationTrait, SchemaManager};
+
+        pub struct Migration;
+
+        impl MigrationName for Migration {
+            fn name(&self) -> &str {
+                "m000006_source_primary_key_novel_download_picture"
+            }
+        }
+
+        #[async_trait::async_trait]
+        impl MigrationTrait for Migration {
+            async fn up(
+                &self,
+                manager: &SchemaManager,
+            ) -> std::result::Result<(), sea_orm_migration::DbErr> {
+                let string_col = |name: &str| {
+                    ColumnDef::new(Alias::new(name))
+                        .string()
+                        .not_null()
+                        .to_owned()
+                };
+                let int_col = |name: &str| {
+                    ColumnDef::new(Alias::new(name))
+                        .integer()
+                        .not_null()
+                        .to_owned()
+                };
+                // 小说ID只在书源内唯一，加上 source_id，旧数据都属于默认书源
+                manager
+                    .create_table(
+                        Table::create()
+                            .table(Alias::new("novel_download_picture_new"))
+                            .col(string_col("aid"))
+                            .col(string_col("volume_id"))
+                            .col(string_col("chapter_id"))
+                            .col(int_col("picture_idx"))
+                            .col(string_col("url"))
+                            .col(string_col("url_md5"))
+                            .col(int_col("download_status"))
+                            .col(string_col("source_id"))
+                            .primary_key(
+                                Index::create()
+                                    .col(Alias::new("source_id"))
+                                    .col(Alias::new("aid"))
+                                    .col(Alias::new("volume_id"))
+                                    .col(Alias::new("chapter_id"))
+                                    .col(Alias::new("picture_idx")),
+                            )
+                            .to_owned(),
+                    )
+                    .await?;
+                manager
+                    .get_connection()
+                    .execute_unprepared(
+                        "INSERT INTO novel_download_picture_new (aid, volume_id, chapter_id, \
+                        picture_idx, url, url_md5, download_status, source_id) \
+                        SELECT p.aid, p.volume_id, p.chapter_id, p.picture_idx, p.url, \
+                        p.url_md5, p.download_status, IFNULL(n.source_id, 'wenku8') \
+                        FROM novel_download_picture p \
+                        LEFT JOIN novel_download n ON n.novel_id = p.aid",
+                    )
+                    .await?;
+                manager
+                    .drop_table(
+                        Table::drop()
+                            .table(Alias::new("novel_download_picture"))
+                            .to_owned(),
+                    )
+                    .await?;
+                manager
+                    .rename_table(
+                        Table::rename()
+                            .table(
+                                Alias::new("novel_download_picture_new"),
+                                Alias::new("novel_download_picture"),
+                            )
+                            .to_owned(),
+                    )
+                    .await?;
+                // 旧表上的索引随表删除，重新创建
+                for (name, cols) in [
+                    ("idx_novel_download_picture_url", &["url"][..]),
+                    ("idx_novel_download_picture_chapter_id", &["chapter_id"][..]),
+                    (
+                        "idx_novel_download_picture_chapter_id_picture_idx",
+                        &["chapter_id", "picture_idx"][..],
+                    ),
+                    ("idx_novel_download_picture_url_md5", &["url_md5"][..]),
+                ] {
+                    let mut index = Index::create();
+                    index
+                        .if_not_exists()
+                        .name(name)
+                        .table(Alias::new("novel_download_picture"));
+                    for col in cols {
+                        index.col(Alias::new(*col));
+                    }
+                    manager.create_index(index).await?;
+                }
+                Ok(())
+            }
+
+            async fn down(
+                &self,
+                _manager: &SchemaManager,
+            ) -> std::result::Result<(), sea_orm_migration::DbErr> {
+                Ok(())
+            }
+        }
+    }
+
+    pub(crate) mod m000007_language_primary_key {
+        use sea_orm::sea_query::{Alias, ColumnDef, Index, Table};
+        use sea_orm::ConnectionTrait;
+        use sea_orm_migration::{MigrationName, MigrationTrait, SchemaManager};
+
+        pub struct Migration;
+
+        impl MigrationName for Migration {
+            fn name(&self) -> &str {
+                "m000007_language_primary_key_novel_download_picture"
+            }
+        }
+
+        #[async_trait::async_trait]
+        impl MigrationTrait for Migration {
+            async fn up(
+                &self,
+                manager: &SchemaManager,
+            ) -> std::result::Result<(), sea_orm_migration::DbErr> {
+                let string_col = |name: &str| {
+                    ColumnDef::new(Alias::new(name))
+                        .string()
+                        .not_null()
+                        .to_owned()
+                };
+                let int_col = |name: &str| {
+                    ColumnDef::new(Alias::new(name))
+                        .integer()
+                        .not_null()
+                        .to_owned()
+                };
+                // 简繁版本的图片分开保存，加上 language，旧数据沿用小说的语言
+                manager
+                    .create_table(
+                        Table::create()
+                            .table(Alias::new("novel_download_picture_new"))
+                            .col(string_col("aid"))
+                            .col(string_col("volume_id"))
+                            .col(string_col("chapter_id"))
+                            .col(int_col("picture_idx"))
+                            .col(string_col("url"))
+                            .col(string_col("url_md5"))
+                            .col(int_col("download_status"))
+                            .col(string_col("source_id"))
+                            .col(string_col("language"))
+                            .primary_key(
+                                Index::create()
+                                    .col(Alias::new("source_id"))
+                                    .col(Alias::new("language"))
+                                    .col(Alias::new("aid"))
+                                    .col(Alias::new("volume_id"))
+                                    .col(Alias::new("chapter_id"))
+                                    .col(Alias::new("picture_idx")),
+                            )
+                            .to_owned(),
+                    )
+                    .await?;
+                manager
+                    .get_connection()
+                    .execute_unprepared(
+                        "INSERT INTO novel_download_picture_new (aid, volume_id, chapter_id, \
+                        picture_idx, url, url_md5, download_status, source_id, language) \
+                        SELECT p.aid, p.volume_id, p.chapter_id, p.picture_idx, p.url, \
+                        p.url_md5, p.download_status, p.source_id, IFNULL(n.language, 'zh-Hans') \
+                        FROM novel_download_picture p \
+                        LEFT JOIN novel_download n \
+                        ON n.source_id = p.source_id AND n.novel_id = p.aid",
+                    )
+                    .await?;
+                manager
+                    .drop_table(
+                        Table::drop()
+                            .table(Alias::new("novel_download_picture"))
+                            .to_owned(),
+                    )
+                    .await?;
+                manager
+                    .rename_table(
+                        Table::rename()
+                            .table(
+                                Alias::new("novel_download_picture_new"),
+                                Alias::new("novel_download_picture"),
+                            )
+                            .to_owned(),
+                    )
+                    .await?;
+                // 旧表上的索引随表删除，重新创建
+                for (name, cols) in [
+                    ("idx_novel_download_picture_url", &["url"][..]),
+                    ("idx_novel_download_picture_chapter_id", &["chapter_id"][..]),
+                    (
+                        "idx_novel_download_picture_chapter_id_picture_idx",
+                        &["chapter_id", "picture_idx"][..],
+                    ),
+                    ("idx_novel_download_picture_url_md5", &["url_md5"][..]),
+                ] {
+                    let mut index = Index::create();
+                    index
+                        .if_not_exists()
+                        .name(name)
+                        .table(Alias::new("novel_download_picture"));
+                    for col in cols {
+                        index.col(Alias::new(*col));
+                    }
+                    manager.create_index(index).await?;
+                }
+                Ok(())
+            }
+
+            async fn down(
+                &self,
+                _manager: &SchemaManager,
+            ) -> std::result::Result<(), sea_orm_migration::DbErr> {
+                Ok(())
+            }
+        }
+    }
 }
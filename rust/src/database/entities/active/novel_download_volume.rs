@@ -14,6 +14,10 @@ pub struct Model {
     pub title: String,
     pub download_status: i32,
     pub create_time: i64,
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub source_id: String,
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub language: String,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -32,15 +36,27 @@ impl Entity {
         Ok(())
     }
 
-    pub async fn find_by_id(id: &str) -> Result<Option<Model>, DbErr> {
+    pub async fn find_by_id(
+        source_id: &str,
+        language: &str,
+        id: &str,
+    ) -> Result<Option<Model>, DbErr> {
         Entity::find()
+            .filter(Column::SourceId.eq(source_id))
+            .filter(Column::Language.eq(language))
             .filter(Column::Id.eq(id))
             .one(get_connect().await.deref())
             .await
     }
 
-    pub async fn find_by_novel_id(novel_id: &str) -> Result<Vec<Model>, DbErr> {
+    pub async fn find_by_novel_id(
+        source_id: &str,
+        language: &str,
+        novel_id: &str,
+    ) -> Result<Vec<Model>, DbErr> {
         Entity::find()
+            .filter(Column::SourceId.eq(source_id))
+            .filter(Column::Language.eq(language))
             .filter(Column::NovelId.eq(novel_id))
             .order_by(Column::VolumeIdx, Order::Asc)
             .all(get_connect().await.deref())
@@ -48,6 +64,8 @@ impl Entity {
     }
 
     pub async fn upsert(
+        source_id: &str,
+        language: &str,
         id: &str,
         novel_id: &str,
         volume_idx: i32,
@@ -62,11 +80,17 @@ impl Entity {
             title: Set(title.to_string()),
             download_status: Set(download_status),
             create_time: Set(now),
+            source_id: Set(source_id.to_string()),
+            language: Set(language.to_string()),
         };
 
         Entity::insert(model)
             .on_conflict(
-                sea_orm::sea_query::OnConflict::column(Column::Id)
+                sea_orm::sea_query::OnConflict::columns([
+                    Column::SourceId,
+                    Column::Language,
+                    Column::Id,
+                ])
                     .update_columns([
                         Column::NovelId,
                         Column::VolumeIdx,
@@ -82,16 +106,22 @@ impl Entity {
     }
 
     pub async fn update_download_status(
+        source_id: &str,
+        language: &str,
         id: &str,
         download_status: i32,
     ) -> Result<(), DbErr> {
         let model = ActiveModel {
+            source_id: Set(source_id.to_string()),
+            language: Set(language.to_string()),
             id: Set(id.to_string()),
             download_status: Set(download_status),
             ..Default::default()
         };
 
         Entity::update(model)
+            .filter(Column::SourceId.eq(source_id))
+            .filter(Column::Language.eq(language))
             .filter(Column::Id.eq(id))
             .exec(get_connect().await.deref())
             .await?;
@@ -99,8 +129,15 @@ impl Entity {
         Ok(())
     }
 
-    pub async fn delete_by_novel_id(conn: &impl ConnectionTrait, novel_id: &str) -> Result<(), DbErr> {
+    pub async fn delete_by_novel_id(
+        conn: &impl ConnectionTrait,
+        source_id: &str,
+        language: &str,
+        novel_id: &str,
+    ) -> Result<(), DbErr> {
         Entity::delete_many()
+            .filter(Column::SourceId.eq(source_id))
+            .filter(Column::Language.eq(language))
             .filter(Column::NovelId.eq(novel_id))
             .exec(conn)
             .await?;
@@ -124,8 +161,14 @@ impl Entity {
         Ok(())
     }
 
-    pub async fn find_incomplete_by_novel(novel_id: &str) -> Result<Option<Model>, DbErr> {
+    pub async fn find_incomplete_by_novel(
+        source_id: &str,
+        language: &str,
+        novel_id: &str,
+    ) -> Result<Option<Model>, DbErr> {
         Entity::find()
+            .filter(Column::SourceId.eq(source_id))
+            .filter(Column::Language.eq(language))
             .filter(Column::NovelId.eq(novel_id))
             .filter(Column::DownloadStatus.eq(0))
             .order_by(Column::Id, Order::Asc)
@@ -134,8 +177,16 @@ impl Entity {
             .await
     }
 
-    pub async fn update_status(novel_id: &str, volume_id: &str, status: i32) -> Result<(), DbErr> {
+    pub async fn update_status(
+        source_id: &str,
+        language: &str,
+        novel_id: &str,
+        volume_id: &str,
+        status: i32,
+    ) -> Result<(), DbErr> {
         Entity::update_many()
+            .filter(Column::SourceId.eq(source_id))
+            .filter(Column::Language.eq(language))
             .filter(Column::NovelId.eq(novel_id))
             .filter(Column::Id.eq(volume_id))
             .set(ActiveModel {
@@ -275,4 +326,246 @@ pub mod migrations {
             Ok(())
         }
     }
-} 
\ No newline at end of file
+
+    pub(crate) mod m000004_source_primary_key {
+        use sea_orm::sea_query::{Alias, ColumnDef, Index, Table};
+        use sea_orm::ConnectionTrait;
+        use sea_orm_migration::{MigrationName, MigrationTrait, SchemaManager};
+
+        pub struct Migration;
+
+        impl MigrationName for Migration {
+            fn name(&self) -> &str {
+                "m000004_source_primary_key_novel_download_volume"
+            }
+        }
+
+        #[async_trait::async_trait]
+        impl MigrationTrait for Migration {
+            async fn up(
+                &self,
+                manager: &SchemaManager,
+            ) -> std::result::Result<(), sea_orm_migration::DbErr> {
+                let string_col = |name: &str| {
+                    ColumnDef::new(Alias::new(name))
+                        .string()
+                        .not_null()
+                        .to_owned()
+                };
+                let int_col = |name: &str| {
+                    ColumnDef::new(Alias::new(name))
+                        .integer()
+                        .not_null()
+                        .to_owned()
+                };
+                // 卷ID只在书源内唯一，加上 source_id，旧数据都属于默认书源
+                manager
+                    .create_table(
+                        Table::create()
+                            .table(Alias::new("novel_download_volume_new"))
+                            .col(string_col("id"))
+                            .col(string_col("novel_id"))
+                            .col(int_col("volume_idx"))
+                            .col(string_col("title"))
+                            .col(int_col("download_status"))
+                            .col(
+                                ColumnDef::new(Alias::new("create_time"))
+                                    .big_integer()
+                                    .not_null(),
+                            )
+                            .col(string_col("source_id"))
+                            .primary_key(
+                                Index::create()
+                                    .col(Alias::new("source_id"))
+                                    .col(Alias::new("id")),
+                            )
+                            .to_owned(),
+                    )
+                    .await?;
+                manager
+                    .get_connection()
+                    .execute_unprepared(
+                        "INSERT INTO novel_download_volume_new (id, novel_id, volume_idx, title, \
+                        download_status, create_time, source_id) \
+                        SELECT v.id, v.novel_id, v.volume_idx, v.title, v.download_status, \
+                        v.create_time, IFNULL(n.source_id, 'wenku8') \
+                        FROM novel_download_volume v \
+                        LEFT JOIN novel_download n ON n.novel_id = v.novel_id",
+                    )
+                    .await?;
+                manager
+                    .drop_table(
+                        Table::drop()
+                            .table(Alias::new("novel_download_volume"))
+                            .to_owned(),
+                    )
+                    .await?;
+                manager
+                    .rename_table(
+                        Table::rename()
+                            .table(
+                                Alias::new("novel_download_volume_new"),
+                                Alias::new("novel_download_volume"),
+                            )
+                            .to_owned(),
+                    )
+                    .await?;
+                // 旧表上的索引随表删除，重新创建
+                manager
+                    .create_index(
+                        Index::create()
+                            .if_not_exists()
+                            .name("idx_novel_download_volume_novel_id")
+                            .table(Alias::new("novel_download_volume"))
+                            .col(Alias::new("novel_id"))
+                            .to_owned(),
+                    )
+                    .await?;
+                manager
+                    .create_index(
+                        Index::create()
+                            .if_not_exists()
+                            .name("idx_novel_download_volume_novel_id_volume_idx")
+                            .table(Alias::new("novel_download_volume"))
+                            .col(Alias::new("source_id"))
+                            .col(Alias::new("novel_id"))
+                            .col(Alias::new("volume_idx"))
+                            .unique()
+                            .to_owned(),
+                    )
+                    .await?;
+                Ok(())
+            }
+
+            async fn down(
+                &self,
+                _manager: &SchemaManager,
+            ) -> std::result::Result<(), sea_orm_migration::DbErr> {
+                Ok(())
+            }
+        }
+    }
+
+    pub(crate) mod m000005_language_primary_key {
+        use sea_orm::sea_query::{Alias, ColumnDef, Index, Table};
+        use sea_orm::ConnectionTrait;
+        use sea_orm_migration::{MigrationName, MigrationTrait, SchemaManager};
+
+        pub struct Migration;
+
+        impl MigrationName for Migration {
+            fn name(&self) -> &str {
+                "m000005_language_primary_key_novel_download_volume"
+            }
+        }
+
+        #[async_trait::async_trait]
+        impl MigrationTrait for Migration {
+            async fn up(
+                &self,
+                manager: &SchemaManager,
+            ) -> std::result::Result<(), sea_orm_migration::DbErr> {
+                let string_col = |name: &str| {
+                    ColumnDef::new(Alias::new(name))
+                        .string()
+                        .not_null()
+                        .to_owned()
+                };
+                let int_col = |name: &str| {
+                    ColumnDef::new(Alias::new(name))
+                        .integer()
+                        .not_null()
+                        .to_owned()
+                };
+                // 简繁版本的卷分开保存，加上 language，旧数据沿用小说的语言
+                manager
+                    .create_table(
+                        Table::create()
+                            .table(Alias::new("novel_download_volume_new"))
+                            .col(string_col("id"))
+                            .col(string_col("novel_id"))
+                            .col(int_col("volume_idx"))
+                            .col(string_col("title"))
+                            .col(int_col("download_status"))
+                            .col(
+                                ColumnDef::new(Alias::new("create_time"))
+                                    .big_integer()
+                                    .not_null(),
+                            )
+                            .col(string_col("source_id"))
+                            .col(string_col("language"))
+                            .primary_key(
+                                Index::create()
+                                    .col(Alias::new("source_id"))
+                                    .col(Alias::new("language"))
+                                    .col(Alias::new("id")),
+                            )
+                            .to_owned(),
+                    )
+                    .await?;
+                manager
+                    .get_connection()
+                    .execute_unprepared(
+                        "INSERT INTO novel_download_volume_new (id, novel_id, volume_idx, title, \
+                        download_status, create_time, source_id, language) \
+                        SELECT v.id, v.novel_id, v.volume_idx, v.title, v.download_status, \
+                        v.create_time, v.source_id, IFNULL(n.language, 'zh-Hans') \
+                        FROM novel_download_volume v \
+                        LEFT JOIN novel_download n \
+                        ON n.source_id = v.source_id AND n.novel_id = v.novel_id",
+                    )
+                    .await?;
+                manager
+                    .drop_table(
+                        Table::drop()
+                            .table(Alias::new("novel_download_volume"))
+                            .to_owned(),
+                    )
+                    .await?;
+                manager
+                    .rename_table(
+                        Table::rename()
+                            .table(
+                                Alias::new("novel_download_volume_new"),
+                                Alias::new("novel_download_volume"),
+                            )
+                            .to_owned(),
+                    )
+                    .await?;
+                // 旧表上的索引随表删除，重新创建
+                manager
+                    .create_index(
+                        Index::create()
+                            .if_not_exists()
+                            .name("idx_novel_download_volume_novel_id")
+                            .table(Alias::new("novel_download_volume"))
+                            .col(Alias::new("novel_id"))
+                            .to_owned(),
+                    )
+                    .await?;
+                manager
+                    .create_index(
+                        Index::create()
+                            .if_not_exists()
+                            .name("idx_novel_download_volume_novel_id_volume_idx")
+                            .table(Alias::new("novel_download_volume"))
+                            .col(Alias::new("source_id"))
+                            .col(Alias::new("novel_id"))
+                            .col(Alias::new("language"))
+                            .col(Alias::new("volume_idx"))
+                            .unique()
+                            .to_owned(),
+                    )
+                    .await?;
+                Ok(())
+            }
+
+            async fn down(
+                &self,
+                _manager: &SchemaManager,
+            ) -> std::result::Result<(), sea_orm_migration::DbErr> {
+                Ok(())
+            }
+        }
+    }
+}
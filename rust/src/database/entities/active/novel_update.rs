@@ -0,0 +1,308 @@
+use sea_orm::{prelude::*, Order, QueryOrder, QuerySelect, Set};
+use serde::{Deserialize, Serialize};
+use std::ops::Deref;
+
+/// 检测到的新章节
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "novel_updates")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i64,
+    pub novel_id: String,
+    pub novel_name: String,
+    pub volume_id: String,
+    pub volume_title: String,
+    pub chapter_id: String,
+    pub chapter_title: String,
+    pub create_time: i64,
+    pub seen: bool,
+    pub source_id: String,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+pub(super) mod migrations {
+    pub(crate) mod m000001_create_table_novel_updates {
+        use sea_orm::{ConnectionTrait, Schema};
+        use sea_orm_migration::{MigrationName, MigrationTrait, SchemaManager};
+
+        pub struct Migration;
+
+        impl MigrationName for Migration {
+            fn name(&self) -> &str {
+                "m000001_create_table_novel_updates"
+            }
+        }
+
+        #[async_trait::async_trait]
+        impl MigrationTrait for Migration {
+            async fn up(
+                &self,
+                manager: &SchemaManager,
+            ) -> std::result::Result<(), sea_orm_migration::DbErr> {
+                let db = manager.get_connection();
+                let backend = db.get_database_backend();
+                let schema = Schema::new(backend);
+                manager
+                    .create_table(
+                        schema
+                            .create_table_from_entity(super::super::Entity)
+                            .if_not_exists()
+                            .to_owned(),
+                    )
+                    .await?;
+                Ok(())
+            }
+
+            async fn down(
+                &self,
+                _manager: &SchemaManager,
+            ) -> std::result::Result<(), sea_orm_migration::DbErr> {
+                Ok(())
+            }
+        }
+    }
+
+    pub(crate) mod m000002_idx_novel_updates_novel_chapter {
+        use sea_orm_migration::prelude::*;
+
+        pub struct Migration;
+
+        impl MigrationName for Migration {
+            fn name(&self) -> &str {
+                "m000002_idx_novel_updates_novel_chapter"
+            }
+        }
+
+        #[async_trait::async_trait]
+        impl MigrationTrait for Migration {
+            async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+                manager
+                    .create_index(
+                        Index::create()
+                            .name("idx_novel_updates_novel_chapter")
+                            .table(super::super::Entity)
+                            .if_not_exists()
+                            .unique()
+                            .col(super::super::Column::NovelId)
+                            .col(super::super::Column::ChapterId)
+                            .to_owned(),
+                    )
+                    .await?;
+                manager
+                    .create_index(
+                        Index::create()
+                            .name("idx_novel_updates_seen")
+                            .table(super::super::Entity)
+                            .if_not_exists()
+                            .col(super::super::Column::Seen)
+                            .to_owned(),
+                    )
+                    .await?;
+                Ok(())
+            }
+
+            async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
+                Ok(())
+            }
+        }
+    }
+
+    pub(crate) mod m000003_add_source_id {
+        use sea_orm::{EntityName, IdenStatic};
+        use sea_orm_migration::prelude::*;
+
+        pub struct Migration;
+
+        impl MigrationName for Migration {
+            fn name(&self) -> &str {
+                "m000003_add_novel_updates_source_id"
+            }
+        }
+
+        #[async_trait::async_trait]
+        impl MigrationTrait for Migration {
+            async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+                if !manager
+                    .has_column(
+                        super::super::Entity.table_name(),
+                        super::super::Column::SourceId.as_str(),
+                    )
+                    .await?
+                {
+                    manager
+                        .alter_table(
+                            Table::alter()
+                                .table(super::super::Entity)
+                                .add_column(
+                                    ColumnDef::new(super::super::Column::SourceId)
+                                        .string()
+                                        .not_null()
+                                        .default(crate::source::DEFAULT_SOURCE_ID),
+                                )
+                                .to_owned(),
+                        )
+                        .await?;
+                }
+                Ok(())
+            }
+
+            async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
+                Ok(())
+            }
+        }
+    }
+
+    pub(crate) mod m000004_idx_novel_updates_source_chapter {
+        use sea_orm_migration::prelude::*;
+
+        // 不同书源的小说ID可能相同，唯一索引需要带上 source_id
+        pub struct Migration;
+
+        impl MigrationName for Migration {
+            fn name(&self) -> &str {
+                "m000004_idx_novel_updates_source_chapter"
+            }
+        }
+
+        #[async_trait::async_trait]
+        impl MigrationTrait for Migration {
+            async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+                manager
+                    .drop_index(
+                        Index::drop()
+                            .name("idx_novel_updates_novel_chapter")
+                            .table(super::super::Entity)
+                            .if_exists()
+                            .to_owned(),
+                    )
+                    .await?;
+                manager
+                    .create_index(
+                        Index::create()
+                            .name("idx_novel_updates_source_novel_chapter")
+                            .table(super::super::Entity)
+                            .if_not_exists()
+                            .unique()
+                            .col(super::super::Column::SourceId)
+                            .col(super::super::Column::NovelId)
+                            .col(super::super::Column::ChapterId)
+                            .to_owned(),
+                    )
+                    .await?;
+                Ok(())
+            }
+
+            async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Entity {
+    /// 记录新章节，已存在的章节忽略
+    pub async fn insert_update(
+        source_id: &str,
+        novel_id: &str,
+        novel_name: &str,
+        volume_id: &str,
+        volume_title: &str,
+        chapter_id: &str,
+        chapter_title: &str,
+    ) -> crate::Result<()> {
+        let db = super::get_connect().await;
+        let model = ActiveModel {
+            novel_id: Set(novel_id.to_string()),
+            novel_name: Set(novel_name.to_string()),
+            volume_id: Set(volume_id.to_string()),
+            volume_title: Set(volume_title.to_string()),
+            chapter_id: Set(chapter_id.to_string()),
+            chapter_title: Set(chapter_title.to_string()),
+            create_time: Set(chrono::Utc::now().timestamp()),
+            seen: Set(false),
+            source_id: Set(source_id.to_string()),
+            ..Default::default()
+        };
+        Entity::insert(model)
+            .on_conflict(
+                sea_orm::sea_query::OnConflict::columns([
+                    Column::SourceId,
+                    Column::NovelId,
+                    Column::ChapterId,
+                ])
+                    .do_nothing()
+                    .to_owned(),
+            )
+            .do_nothing()
+            .exec(db.deref())
+            .await?;
+        Ok(())
+    }
+
+    /// 未读的更新，按发现时间倒序
+    pub async fn list_unseen(offset: i32, limit: i32) -> crate::Result<Vec<Model>> {
+        let db = super::get_connect().await;
+        Ok(Entity::find()
+            .filter(Column::Seen.eq(false))
+            .order_by(Column::CreateTime, Order::Desc)
+            .order_by(Column::Id, Order::Desc)
+            .offset(offset as u64)
+            .limit(limit as u64)
+            .all(db.deref())
+            .await?)
+    }
+
+    pub async fn mark_seen_by_ids(ids: Vec<i64>) -> crate::Result<()> {
+        let db = super::get_connect().await;
+        Entity::update_many()
+            .filter(Column::Id.is_in(ids))
+            .set(ActiveModel {
+                seen: Set(true),
+                ..Default::default()
+            })
+            .exec(db.deref())
+            .await?;
+        Ok(())
+    }
+
+    pub async fn mark_seen_by_novel_id(novel_id: &str) -> crate::Result<()> {
+        let db = super::get_connect().await;
+        Entity::update_many()
+            .filter(Column::NovelId.eq(novel_id))
+            .set(ActiveModel {
+                seen: Set(true),
+                ..Default::default()
+            })
+            .exec(db.deref())
+            .await?;
+        Ok(())
+    }
+
+    pub async fn mark_all_seen() -> crate::Result<()> {
+        let db = super::get_connect().await;
+        Entity::update_many()
+            .set(ActiveModel {
+                seen: Set(true),
+                ..Default::default()
+            })
+            .exec(db.deref())
+            .await?;
+        Ok(())
+    }
+
+    /// 删除已读且超过 30 天的记录
+    pub async fn delete_old_records() -> crate::Result<()> {
+        let db = super::get_connect().await;
+        let cutoff = chrono::Utc::now().timestamp() - 30 * 24 * 60 * 60;
+        Entity::delete_many()
+            .filter(Column::Seen.eq(true))
+            .filter(Column::CreateTime.lt(cutoff))
+            .exec(db.deref())
+            .await?;
+        Ok(())
+    }
+}
@@ -10,6 +10,9 @@ use std::ops::Deref;
 #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
 #[sea_orm(table_name = "reading_history")]
 pub struct Model {
+    /// 阅读时的账号，0 为未绑定账号
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub account_id: i64,
     #[sea_orm(primary_key, auto_increment = false)]
     pub novel_id: String,
     pub novel_name: String,
@@ -22,6 +25,7 @@ pub struct Model {
     pub progress_page: i32,
     pub cover: String,
     pub author: String,
+    pub source_id: String,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -308,12 +312,253 @@ pub(super) mod migrations {
             }
         }
     }
+
+    pub(crate) mod m000005_add_source_id {
+        use sea_orm::sea_query::Table;
+        use sea_orm::{ConnectionTrait, EntityName, IdenStatic, Schema};
+        use sea_orm_migration::{MigrationName, MigrationTrait, SchemaManager};
+
+        pub struct Migration;
+
+        impl MigrationName for Migration {
+            fn name(&self) -> &str {
+                "m000005_add_source_id"
+            }
+        }
+
+        #[async_trait::async_trait]
+        impl MigrationTrait for Migration {
+            async fn up(
+                &self,
+                manager: &SchemaManager,
+            ) -> std::result::Result<(), sea_orm_migration::DbErr> {
+                let db = manager.get_connection();
+                let backend = db.get_database_backend();
+                let schema = Schema::new(backend);
+                if !manager
+                    .has_column(
+                        super::super::Entity.table_name(),
+                        super::super::Column::SourceId.as_str(),
+                    )
+                    .await?
+                {
+                    manager
+                        .alter_table(
+                            Table::alter()
+                                .table(super::super::Entity.table_ref())
+                                .add_column(
+                                    schema
+                                        .get_column_def::<super::super::Entity>(
+                                            super::super::Column::SourceId,
+                                        )
+                                        .default(sea_orm::Value::String(Some(Box::new(crate::source::DEFAULT_SOURCE_ID.to_string())))),
+                                )
+                                .to_owned(),
+                        )
+                        .await?;
+                }
+                Ok(())
+            }
+
+            async fn down(
+                &self,
+                _manager: &SchemaManager,
+            ) -> std::result::Result<(), sea_orm_migration::DbErr> {
+                Ok(())
+            }
+        }
+    }
+
+    pub(crate) mod m000006_add_account_id {
+        use sea_orm::sea_query::Table;
+        use sea_orm::{ConnectionTrait, EntityName, IdenStatic, Schema};
+        use sea_orm_migration::{MigrationName, MigrationTrait, SchemaManager};
+
+        pub struct Migration;
+
+        impl MigrationName for Migration {
+            fn name(&self) -> &str {
+                "m000006_add_account_id_reading_history"
+            }
+        }
+
+        #[async_trait::async_trait]
+        impl MigrationTrait for Migration {
+            async fn up(
+                &self,
+                manager: &SchemaManager,
+            ) -> std::result::Result<(), sea_orm_migration::DbErr> {
+                let db = manager.get_connection();
+                let backend = db.get_database_backend();
+                let schema = Schema::new(backend);
+                if !manager
+                    .has_column(
+                        super::super::Entity.table_name(),
+                        super::super::Column::AccountId.as_str(),
+                    )
+                    .await?
+                {
+                    manager
+                        .alter_table(
+                            Table::alter()
+                                .table(super::super::Entity.table_ref())
+                                .add_column(
+                                    schema
+                                        .get_column_def::<super::super::Entity>(
+                                            super::super::Column::AccountId,
+                                        )
+                                        .default(0),
+                                )
+                                .to_owned(),
+                        )
+                        .await?;
+                }
+                Ok(())
+            }
+
+            async fn down(
+                &self,
+                _manager: &SchemaManager,
+            ) -> std::result::Result<(), sea_orm_migration::DbErr> {
+                Ok(())
+            }
+        }
+    }
+
+    /// 主键改为 (account_id, novel_id)，不同账号可以各自保存同一本小说的阅读记录
+    /// 表结构按这个版本写死，不随 Entity 变化
+    pub(crate) mod m000007_account_primary_key {
+        use sea_orm::sea_query::{Alias, ColumnDef, Index, Table};
+        use sea_orm::ConnectionTrait;
+        use sea_orm_migration::{MigrationName, MigrationTrait, SchemaManager};
+
+        pub struct Migration;
+
+        impl MigrationName for Migration {
+            fn name(&self) -> &str {
+                "m000007_account_primary_key_reading_history"
+            }
+        }
+
+        #[async_trait::async_trait]
+        impl MigrationTrait for Migration {
+            async fn up(
+                &self,
+                manager: &SchemaManager,
+            ) -> std::result::Result<(), sea_orm_migration::DbErr> {
+                let string_col = |name: &str| {
+                    ColumnDef::new(Alias::new(name))
+                        .string()
+                        .not_null()
+                        .to_owned()
+                };
+                let int_col = |name: &str| {
+                    ColumnDef::new(Alias::new(name))
+                        .integer()
+                        .not_null()
+                        .to_owned()
+                };
+                manager
+                    .create_table(
+                        Table::create()
+                            .table(Alias::new("reading_history_new"))
+                            .col(
+                                ColumnDef::new(Alias::new("account_id"))
+                                    .big_integer()
+                                    .not_null(),
+                            )
+                            .col(string_col("novel_id"))
+                            .col(string_col("novel_name"))
+                            .col(string_col("volume_id"))
+                            .col(string_col("volume_name"))
+                            .col(string_col("chapter_id"))
+                            .col(string_col("chapter_title"))
+                            .col(
+                                ColumnDef::new(Alias::new("last_read_at"))
+                                    .big_integer()
+                                    .not_null(),
+                            )
+                            .col(int_col("progress"))
+                            .col(int_col("progress_page"))
+                            .col(string_col("cover"))
+                            .col(string_col("author"))
+                            .col(string_col("source_id"))
+                            .primary_key(
+                                Index::create()
+                                    .col(Alias::new("account_id"))
+                                    .col(Alias::new("novel_id")),
+                            )
+                            .to_owned(),
+                    )
+                    .await?;
+                // 旧版本加列时可能留下 NULL，复制时补上默认值
+                manager
+                    .get_connection()
+                    .execute_unprepared(
+                        "INSERT INTO reading_history_new (account_id, novel_id, novel_name, \
+                        volume_id, volume_name, chapter_id, chapter_title, last_read_at, progress, \
+                        progress_page, cover, author, source_id) \
+                        SELECT IFNULL(account_id, 0), novel_id, IFNULL(novel_name, ''), \
+                        IFNULL(volume_id, ''), IFNULL(volume_name, ''), IFNULL(chapter_id, ''), \
+                        IFNULL(chapter_title, ''), IFNULL(last_read_at, 0), IFNULL(progress, 0), \
+                        IFNULL(progress_page, 0), IFNULL(cover, ''), IFNULL(author, ''), \
+                        IFNULL(source_id, 'wenku8') FROM reading_history",
+                    )
+                    .await?;
+                manager
+                    .drop_table(
+                        Table::drop()
+                            .table(Alias::new("reading_history"))
+                            .to_owned(),
+                    )
+                    .await?;
+                manager
+                    .rename_table(
+                        Table::rename()
+                            .table(
+                                Alias::new("reading_history_new"),
+                                Alias::new("reading_history"),
+                            )
+                            .to_owned(),
+                    )
+                    .await?;
+                // 旧表上的索引随表删除，重新创建
+                manager
+                    .create_index(
+                        Index::create()
+                            .if_not_exists()
+                            .name("idx_reading_histories_novel_id")
+                            .table(Alias::new("reading_history"))
+                            .col(Alias::new("novel_id"))
+                            .to_owned(),
+                    )
+                    .await?;
+                Ok(())
+            }
+
+            async fn down(
+                &self,
+                _manager: &SchemaManager,
+            ) -> std::result::Result<(), sea_orm_migration::DbErr> {
+                Ok(())
+            }
+        }
+    }
 }
 
 impl Entity {
-    pub async fn list_reading_history(offset: i32, limit: i32) -> crate::Result<Vec<Model>> {
+    /// account_id 为 None 时列出所有账号的记录
+    pub async fn list_reading_history(
+        account_id: Option<i64>,
+        offset: i32,
+        limit: i32,
+    ) -> crate::Result<Vec<Model>> {
         let db = super::get_connect().await;
-        let records = Entity::find()
+        let mut query = Entity::find();
+        if let Some(account_id) = account_id {
+            query = query.filter(Column::AccountId.eq(account_id));
+        }
+        let records = query
             .order_by(Column::LastReadAt, Order::Desc)
             .offset(offset as u64)
             .limit(limit as u64)
@@ -322,45 +567,32 @@ impl Entity {
         Ok(records)
     }
 
-    /// 获取指定小说最新的阅读记录
-    pub async fn find_latest_by_novel_id(novel_id: &str) -> crate::Result<Option<Model>> {
+    /// 获取指定小说最新的阅读记录，account_id 为 None 时在所有账号的记录中查找
+    pub async fn find_latest_by_novel_id(
+        account_id: Option<i64>,
+        novel_id: &str,
+    ) -> crate::Result<Option<Model>> {
         let db = super::get_connect().await;
-        let record = Entity::find()
-            .filter(Column::NovelId.eq(novel_id))
+        let mut query = Entity::find().filter(Column::NovelId.eq(novel_id));
+        if let Some(account_id) = account_id {
+            query = query.filter(Column::AccountId.eq(account_id));
+        }
+        let record = query
             .order_by(Column::LastReadAt, Order::Desc)
             .one(&*db)
             .await?;
         Ok(record)
     }
 
-    pub async fn upsert(
-        novel_id: &str,
-        novel_name: &str,
-        volume_id: &str,
-        volume_name: &str,
-        chapter_id: &str,
-        chapter_title: &str,
-        progress: i32,
-        progress_page: i32,
-        cover: &str,
-        author: &str,
-    ) -> crate::Result<()> {
+    /// 保存阅读记录，last_read_at 使用保存时的时间
+    pub async fn upsert(model: Model) -> crate::Result<()> {
         let db = super::get_connect().await;
-        let time = chrono::Local::now().timestamp_millis();
-        let model = ActiveModel {
-            novel_id: Set(novel_id.to_string()),
-            novel_name: Set(novel_name.to_string()),
-            volume_id: Set(volume_id.to_string()),
-            volume_name: Set(volume_name.to_string()),
-            chapter_id: Set(chapter_id.to_string()),
-            chapter_title: Set(chapter_title.to_string()),
-            last_read_at: Set(time),
-            progress: Set(progress),
-            progress_page: Set(progress_page),
-            cover: Set(cover.to_string()),
-            author: Set(author.to_string()),
-        };
-        if let Some(_existing_record) = Entity::find_by_id(novel_id).one(db.deref()).await? {
+        let existing = Entity::find_by_id((model.account_id, model.novel_id.clone()))
+            .one(db.deref())
+            .await?;
+        let mut model = ActiveModel::from(model).reset_all();
+        model.last_read_at = Set(chrono::Local::now().timestamp_millis());
+        if existing.is_some() {
             // 如果记录已存在，则更新
             model.update(db.deref()).await?;
         } else {
@@ -396,15 +628,25 @@ impl Entity {
         Ok(())
     }
 
-    pub async fn delete_all() -> crate::Result<()> {
+    /// account_id 为 None 时删除所有账号的记录
+    pub async fn delete_all(account_id: Option<i64>) -> crate::Result<()> {
         let db = super::get_connect().await;
-        Entity::delete_many().exec(db.deref()).await?;
+        let mut delete = Entity::delete_many();
+        if let Some(account_id) = account_id {
+            delete = delete.filter(Column::AccountId.eq(account_id));
+        }
+        delete.exec(db.deref()).await?;
         Ok(())
     }
 
-    pub async fn delete_by_novel_id(novel_id: &str) -> crate::Result<()> {
+    /// account_id 为 None 时删除所有账号中这本小说的记录
+    pub async fn delete_by_novel_id(account_id: Option<i64>, novel_id: &str) -> crate::Result<()> {
         let db = super::get_connect().await;
-        Entity::delete_by_id(novel_id).exec(db.deref()).await?;
+        let mut delete = Entity::delete_many().filter(Column::NovelId.eq(novel_id));
+        if let Some(account_id) = account_id {
+            delete = delete.filter(Column::AccountId.eq(account_id));
+        }
+        delete.exec(db.deref()).await?;
         Ok(())
     }
 }
@@ -0,0 +1,219 @@
+use sea_orm::{prelude::*, Order, QueryOrder, QuerySelect, Set};
+use serde::{Deserialize, Serialize};
+use std::ops::Deref;
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "recommend_log")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i64,
+    /// 推荐的账号，0 为未绑定账号
+    pub account_id: i64,
+    pub date: String, // 格式：YYYY-MM-DD
+    pub novel_id: String,
+    pub create_time: i64,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+pub(super) mod migrations {
+    pub(crate) mod m000001_create_table_recommend_log {
+        use sea_orm::{ConnectionTrait, Schema};
+        use sea_orm_migration::{MigrationName, MigrationTrait, SchemaManager};
+
+        pub struct Migration;
+
+        impl MigrationName for Migration {
+            fn name(&self) -> &str {
+                "m000001_create_table_recommend_log"
+            }
+        }
+
+        #[async_trait::async_trait]
+        impl MigrationTrait for Migration {
+            async fn up(
+                &self,
+                manager: &SchemaManager,
+            ) -> std::result::Result<(), sea_orm_migration::DbErr> {
+                let db = manager.get_connection();
+                let backend = db.get_database_backend();
+                let schema = Schema::new(backend);
+                manager
+                    .create_table(
+                        schema
+                            .create_table_from_entity(super::super::Entity)
+                            .if_not_exists()
+                            .to_owned(),
+                    )
+                    .await?;
+                Ok(())
+            }
+
+            async fn down(
+                &self,
+                _manager: &SchemaManager,
+            ) -> std::result::Result<(), sea_orm_migration::DbErr> {
+                Ok(())
+            }
+        }
+    }
+
+    pub(crate) mod m000002_idx_recommend_log_date {
+        use sea_orm::sea_query::Index;
+        use sea_orm::EntityName;
+        use sea_orm_migration::{MigrationName, MigrationTrait, SchemaManager};
+
+        pub struct Migration;
+
+        impl MigrationName for Migration {
+            fn name(&self) -> &str {
+                "m000002_idx_recommend_log_date"
+            }
+        }
+
+        #[async_trait::async_trait]
+        impl MigrationTrait for Migration {
+            async fn up(
+                &self,
+                manager: &SchemaManager,
+            ) -> std::result::Result<(), sea_orm_migration::DbErr> {
+                manager
+                    .create_index(
+                        Index::create()
+                            .if_not_exists()
+                            .name("idx_recommend_log_date")
+                            .table(super::super::Entity.table_ref())
+                            .col(super::super::Column::Date)
+                            .to_owned(),
+                    )
+                    .await?;
+                Ok(())
+            }
+
+            async fn down(
+                &self,
+                _manager: &SchemaManager,
+            ) -> std::result::Result<(), sea_orm_migration::DbErr> {
+                Ok(())
+            }
+        }
+    }
+
+    pub(crate) mod m000003_add_account_id {
+        use sea_orm::sea_query::Table;
+        use sea_orm::{ConnectionTrait, EntityName, IdenStatic, Schema};
+        use sea_orm_migration::{MigrationName, MigrationTrait, SchemaManager};
+
+        pub struct Migration;
+
+        impl MigrationName for Migration {
+            fn name(&self) -> &str {
+                "m000003_add_account_id_recommend_log"
+            }
+        }
+
+        #[async_trait::async_trait]
+        impl MigrationTrait for Migration {
+            async fn up(
+                &self,
+                manager: &SchemaManager,
+            ) -> std::result::Result<(), sea_orm_migration::DbErr> {
+                let db = manager.get_connection();
+                let backend = db.get_database_backend();
+                let schema = Schema::new(backend);
+                if !manager
+                    .has_column(
+                        super::super::Entity.table_name(),
+                        super::super::Column::AccountId.as_str(),
+                    )
+                    .await?
+                {
+                    manager
+                        .alter_table(
+                            Table::alter()
+                                .table(super::super::Entity.table_ref())
+                                .add_column(
+                                    schema
+                                        .get_column_def::<super::super::Entity>(
+                                            super::super::Column::AccountId,
+                                        )
+                                        .default(0),
+                                )
+                                .to_owned(),
+                        )
+                        .await?;
+                }
+                Ok(())
+            }
+
+            async fn down(
+                &self,
+                _manager: &SchemaManager,
+            ) -> std::result::Result<(), sea_orm_migration::DbErr> {
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Entity {
+    /// 添加推荐记录
+    pub async fn record(account_id: i64, novel_id: &str) -> crate::Result<()> {
+        let db = super::get_connect().await;
+        let now = chrono::Local::now();
+        let model = ActiveModel {
+            account_id: Set(account_id),
+            date: Set(now.format("%Y-%m-%d").to_string()),
+            novel_id: Set(novel_id.to_string()),
+            create_time: Set(now.timestamp()),
+            ..Default::default()
+        };
+        model.insert(db.deref()).await?;
+        Ok(())
+    }
+
+    /// 账号今天已经推荐的次数，推荐次数是按账号计算的
+    pub async fn count_today(account_id: i64) -> crate::Result<i64> {
+        let db = super::get_connect().await;
+        let today = chrono::Local::now().format("%Y-%m-%d").to_string();
+        let count = Entity::find()
+            .filter(Column::AccountId.eq(account_id))
+            .filter(Column::Date.eq(today))
+            .count(db.deref())
+            .await?;
+        Ok(count as i64)
+    }
+
+    /// 获取账号的推荐记录列表
+    pub async fn list_recommend_log(
+        account_id: i64,
+        offset: i32,
+        limit: i32,
+    ) -> crate::Result<Vec<Model>> {
+        let db = super::get_connect().await;
+        let records = Entity::find()
+            .filter(Column::AccountId.eq(account_id))
+            .order_by(Column::CreateTime, Order::Desc)
+            .offset(offset as u64)
+            .limit(limit as u64)
+            .all(db.deref())
+            .await?;
+        Ok(records)
+    }
+
+    /// 删除100天以前的推荐记录
+    pub async fn delete_old_records() -> crate::Result<()> {
+        let db = super::get_connect().await;
+        let cutoff_date = (chrono::Local::now() - chrono::Duration::days(100))
+            .format("%Y-%m-%d")
+            .to_string();
+        Entity::delete_many()
+            .filter(Column::Date.lt(cutoff_date))
+            .exec(db.deref())
+            .await?;
+        Ok(())
+    }
+}
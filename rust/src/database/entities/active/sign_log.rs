@@ -6,6 +6,9 @@ use flutter_rust_bridge::frb;
 #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
 #[sea_orm(table_name = "sign_log")]
 pub struct Model {
+    /// 签到的账号，0 为未绑定账号
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub account_id: i64,
     #[sea_orm(primary_key, auto_increment = false)]
     pub date: String, // 格式：YYYY-MM-DD
 }
@@ -56,32 +59,110 @@ pub(super) mod migrations {
             }
         }
     }
+
+    /// 主键改为 (account_id, date)，SQLite 不能修改主键，只能重建表
+    pub(crate) mod m000002_add_account_id {
+        use sea_orm::sea_query::{Alias, Table};
+        use sea_orm::{ConnectionTrait, EntityName, IdenStatic, Schema};
+        use sea_orm_migration::{MigrationName, MigrationTrait, SchemaManager};
+
+        pub struct Migration;
+
+        impl MigrationName for Migration {
+            fn name(&self) -> &str {
+                "m000002_add_account_id_sign_log"
+            }
+        }
+
+        #[async_trait::async_trait]
+        impl MigrationTrait for Migration {
+            async fn up(
+                &self,
+                manager: &SchemaManager,
+            ) -> std::result::Result<(), sea_orm_migration::DbErr> {
+                if manager
+                    .has_column(
+                        super::super::Entity.table_name(),
+                        super::super::Column::AccountId.as_str(),
+                    )
+                    .await?
+                {
+                    return Ok(());
+                }
+                let db = manager.get_connection();
+                let backend = db.get_database_backend();
+                let schema = Schema::new(backend);
+                manager
+                    .create_table(
+                        schema
+                            .create_table_from_entity(super::super::Entity)
+                            .table(Alias::new("sign_log_new"))
+                            .to_owned(),
+                    )
+                    .await?;
+                db.execute_unprepared(
+                    "INSERT INTO sign_log_new (account_id, date) SELECT 0, date FROM sign_log",
+                )
+                .await?;
+                manager
+                    .drop_table(Table::drop().table(super::super::Entity).to_owned())
+                    .await?;
+                manager
+                    .rename_table(
+                        Table::rename()
+                            .table(Alias::new("sign_log_new"), super::super::Entity)
+                            .to_owned(),
+                    )
+                    .await?;
+                Ok(())
+            }
+
+            async fn down(
+                &self,
+                _manager: &SchemaManager,
+            ) -> std::result::Result<(), sea_orm_migration::DbErr> {
+                Ok(())
+            }
+        }
+    }
 }
 
 impl Entity {
     /// 添加签到记录
-    pub async fn sign() -> crate::Result<()> {
+    pub async fn sign(account_id: i64) -> crate::Result<()> {
         let db = super::get_connect().await;
         let today = chrono::Local::now().format("%Y-%m-%d").to_string();
         let model = ActiveModel {
+            account_id: Set(account_id),
             date: Set(today),
         };
         model.insert(db.deref()).await?;
         Ok(())
     }
 
-    /// 检查今天是否已签到
-    pub async fn is_signed_today() -> crate::Result<bool> {
+    /// 检查今天是否已签到，account_id 为 None 时任意账号签到过都算
+    pub async fn is_signed_today(account_id: Option<i64>) -> crate::Result<bool> {
         let db = super::get_connect().await;
         let today = chrono::Local::now().format("%Y-%m-%d").to_string();
-        let record = Entity::find_by_id(today).one(db.deref()).await?;
-        Ok(record.is_some())
+        let mut query = Entity::find().filter(Column::Date.eq(today));
+        if let Some(account_id) = account_id {
+            query = query.filter(Column::AccountId.eq(account_id));
+        }
+        Ok(query.count(db.deref()).await? > 0)
     }
 
-    /// 获取签到记录列表
-    pub async fn list_sign_log(offset: i32, limit: i32) -> crate::Result<Vec<Model>> {
+    /// 获取签到记录列表，account_id 为 None 时列出所有账号的记录
+    pub async fn list_sign_log(
+        account_id: Option<i64>,
+        offset: i32,
+        limit: i32,
+    ) -> crate::Result<Vec<Model>> {
         let db = super::get_connect().await;
-        let records = Entity::find()
+        let mut query = Entity::find();
+        if let Some(account_id) = account_id {
+            query = query.filter(Column::AccountId.eq(account_id));
+        }
+        let records = query
             .order_by(Column::Date, Order::Desc)
             .offset(offset as u64)
             .limit(limit as u64)
@@ -136,6 +136,16 @@ impl Entity {
         Ok(())
     }
 
+    /// 删除以 prefix 开头的缓存，用于数据变化后让列表重新加载
+    pub async fn delete_by_prefix(prefix: &str) -> Result<(), DbErr> {
+        let db = get_connect().await;
+        Self::delete_many()
+            .filter(Column::CacheKey.starts_with(prefix))
+            .exec(db.deref())
+            .await?;
+        Ok(())
+    }
+
     pub async fn delete_all() -> Result<(), DbErr> {
         let db = get_connect().await;
         Self::delete_many().exec(db.deref()).await?;
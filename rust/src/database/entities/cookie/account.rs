@@ -0,0 +1,69 @@
+use std::ops::Deref;
+
+use sea_orm::entity::prelude::*;
+use sea_orm::ActiveValue::Set;
+use sea_orm::QueryOrder;
+use serde::{Deserialize, Serialize};
+
+/// 文库账号，cookie 按 id 分开保存
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "account")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i64,
+    #[sea_orm(unique)]
+    pub username: String,
+    pub create_time: i64,
+    pub last_login_time: i64,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl Entity {
+    pub async fn list_all() -> crate::Result<Vec<Model>> {
+        let db = super::get_connect().await;
+        Ok(Entity::find()
+            .order_by_asc(Column::CreateTime)
+            .all(db.deref())
+            .await?)
+    }
+
+    pub async fn find_account(id: i64) -> crate::Result<Option<Model>> {
+        let db = super::get_connect().await;
+        Ok(Entity::find_by_id(id).one(db.deref()).await?)
+    }
+
+    /// 按用户名找到账号并更新登录时间，不存在时创建
+    pub async fn login(username: &str) -> crate::Result<Model> {
+        let db = super::get_connect().await;
+        let now = chrono::Local::now().timestamp();
+        let exists = Entity::find()
+            .filter(Column::Username.eq(username))
+            .one(db.deref())
+            .await?;
+        let model = if let Some(exists) = exists {
+            let mut model: ActiveModel = exists.into();
+            model.last_login_time = Set(now);
+            model.update(db.deref()).await?
+        } else {
+            ActiveModel {
+                username: Set(username.to_string()),
+                create_time: Set(now),
+                last_login_time: Set(now),
+                ..Default::default()
+            }
+            .insert(db.deref())
+            .await?
+        };
+        Ok(model)
+    }
+
+    pub async fn delete_account(id: i64) -> crate::Result<()> {
+        let db = super::get_connect().await;
+        Entity::delete_by_id(id).exec(db.deref()).await?;
+        Ok(())
+    }
+}
@@ -1,5 +1,3 @@
-use std::ops::Deref;
-
 use sea_orm::entity::prelude::*;
 use sea_orm::ActiveValue::Set;
 use sea_orm::IntoActiveModel;
@@ -8,15 +6,23 @@ use sea_orm_migration::prelude::*;
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
 #[sea_orm(table_name = "cookie")]
 pub struct Model {
+    /// 所属账号，0 为未绑定账号的 cookie
+    #[sea_orm(primary_key)]
+    pub account_id: i64,
+    /// 不带前导的点，host_only 时为设置 cookie 的主机名
     #[sea_orm(primary_key)]
     pub domain: String,
     #[sea_orm(primary_key)]
+    pub path: String,
+    #[sea_orm(primary_key)]
     pub name: String,
     pub value: String,
-    pub path: String,
+    /// 过期时间（秒），None 为会话 cookie
     pub expires: Option<i64>,
     pub secure: Option<bool>,
     pub http_only: Option<bool>,
+    /// 没有 Domain 属性的 cookie 只发送给设置它的主机，旧数据为 None 时按域名 cookie 处理
+    pub host_only: Option<bool>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -25,57 +31,83 @@ pub enum Relation {}
 impl ActiveModelBehavior for ActiveModel {}
 
 impl Entity {
-    pub async fn find_by_domain(domain: &str) -> crate::Result<Vec<Model>> {
-        let db = super::get_connect().await;
-        Ok(Entity::find()
-            .filter(Column::Domain.eq(domain))
-            .all(db.deref())
-            .await?)
+    /// 所有账号的 cookie，启动时载入内存
+    pub async fn find_all(db: &impl ConnectionTrait) -> crate::Result<Vec<Model>> {
+        Ok(Entity::find().all(db).await?)
     }
 
-    pub async fn save_or_update_cookie(cookie: Model) -> crate::Result<()> {
-        let db = super::get_connect().await;
-        let exists = Entity::find()
-            .filter(Column::Domain.eq(cookie.domain.as_str()))
-            .filter(Column::Name.eq(cookie.name.as_str()))
-            .one(db.deref())
-            .await?;
-        if let Some(exists) = exists {
-            Entity::update(cookie.clone().into_active_model())
-                .filter(Column::Domain.eq(cookie.domain.as_str()))
-                .filter(Column::Name.eq(cookie.name.as_str()))
-                .exec(db.deref())
+    /// 账号、域名、路径、名称相同的 cookie 会被替换
+    pub async fn save_or_update_cookie(
+        db: &impl ConnectionTrait,
+        cookie: Model,
+    ) -> crate::Result<()> {
+        let exists = Entity::find_by_id((
+            cookie.account_id,
+            cookie.domain.clone(),
+            cookie.path.clone(),
+            cookie.name.clone(),
+        ))
+        .one(db)
+        .await?;
+        if exists.is_some() {
+            // into_active_model 的字段都是 Unchanged，需要标记为修改才会更新
+            Entity::update(cookie.into_active_model().reset_all())
+                .exec(db)
                 .await?;
         } else {
-            Entity::insert(cookie.into_active_model())
-                .exec(db.deref())
-                .await?;
+            Entity::insert(cookie.into_active_model()).exec(db).await?;
         }
         Ok(())
     }
 
-    pub async fn delete_by_domain(domain: &str) -> crate::Result<()> {
-        let db = super::get_connect().await;
+    pub async fn delete_cookie(
+        db: &impl ConnectionTrait,
+        account_id: i64,
+        domain: &str,
+        path: &str,
+        name: &str,
+    ) -> crate::Result<()> {
+        Entity::delete_by_id((
+            account_id,
+            domain.to_string(),
+            path.to_string(),
+            name.to_string(),
+        ))
+        .exec(db)
+        .await?;
+        Ok(())
+    }
+
+    /// 删除所有账号已过期的 cookie
+    pub async fn delete_expired(db: &impl ConnectionTrait, now: i64) -> crate::Result<()> {
         Entity::delete_many()
-            .filter(Column::Domain.eq(domain))
-            .exec(db.deref())
+            .filter(Column::Expires.lte(now))
+            .exec(db)
             .await?;
         Ok(())
     }
-    
-    pub async fn exists(name: &str) -> crate::Result<bool> {
-        let db = super::get_connect().await;
-        let exists = Entity::find()
-            .filter(Column::Name.eq(name))
-            .count(db.deref())
+
+    pub async fn delete_by_account(
+        db: &impl ConnectionTrait,
+        account_id: i64,
+    ) -> crate::Result<()> {
+        Entity::delete_many()
+            .filter(Column::AccountId.eq(account_id))
+            .exec(db)
             .await?;
-        Ok(exists > 0)
+        Ok(())
     }
-    
-    pub async fn delete_all() -> crate::Result<()> {
-        let db = super::get_connect().await;
+
+    /// 把 from 的 cookie 转给 to，to 原有的 cookie 会被删除
+    pub async fn move_account(db: &impl ConnectionTrait, from: i64, to: i64) -> crate::Result<()> {
         Entity::delete_many()
-            .exec(db.deref())
+            .filter(Column::AccountId.eq(to))
+            .exec(db)
+            .await?;
+        Entity::update_many()
+            .col_expr(Column::AccountId, Expr::value(to))
+            .filter(Column::AccountId.eq(from))
+            .exec(db)
             .await?;
         Ok(())
     }
@@ -1,86 +1,388 @@
-use crate::database::entities::cookie::cookie::ActiveModel;
-use crate::database::entities::cookie::cookie::Column;
-use crate::database::entities::cookie::cookie::Entity;
-use crate::database::entities::CookieEntity;
+use crate::database::entities::{Cookie, CookieEntity};
 use bytes::Bytes;
+use once_cell::sync::OnceCell;
 use reqwest::cookie::CookieStore;
 use reqwest::header::HeaderValue;
 use reqwest::Url;
-use sea_orm::{ActiveModelTrait, ColumnTrait, EntityTrait, QueryFilter};
-use std::ops::Deref;
+use sea_orm::{DatabaseConnection, TransactionTrait};
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::Duration;
+use tokio::sync::Notify;
+use tracing::{debug, warn};
 
-pub struct DatabaseCookieStore {}
+/// 修改后等待一会再写入，合并短时间内的多次修改
+const FLUSH_DELAY: Duration = Duration::from_secs(1);
+/// 写入失败后重试的间隔
+const FLUSH_RETRY_DELAY: Duration = Duration::from_secs(10);
+
+/// 按 RFC 6265 保存和发送 cookie，cookie 按当前账号分开保存，见 crate::account
+/// 所有账号的 cookie 在启动时载入内存，请求时只读写内存，修改在后台写入 cookie.db
+#[derive(Default)]
+pub struct DatabaseCookieStore {
+    jar: RwLock<Vec<Cookie>>,
+    /// 还没有写入数据库的修改，按发生的顺序
+    pending: Mutex<Vec<CookieChange>>,
+    notify: Notify,
+    db: OnceCell<DatabaseConnection>,
+    /// 保证修改按顺序写入
+    flush_lock: tokio::sync::Mutex<()>,
+}
+
+enum CookieChange {
+    Save(Cookie),
+    Delete {
+        account_id: i64,
+        domain: String,
+        path: String,
+        name: String,
+    },
+    DeleteExpired(i64),
+    DeleteAccount(i64),
+    MoveAccount {
+        from: i64,
+        to: i64,
+    },
+}
+
+impl CookieChange {
+    async fn apply(&self, db: &impl sea_orm::ConnectionTrait) -> anyhow::Result<()> {
+        match self {
+            CookieChange::Save(cookie) => {
+                CookieEntity::save_or_update_cookie(db, cookie.clone()).await
+            }
+            CookieChange::Delete {
+                account_id,
+                domain,
+                path,
+                name,
+            } => CookieEntity::delete_cookie(db, *account_id, domain, path, name).await,
+            CookieChange::DeleteExpired(now) => CookieEntity::delete_expired(db, *now).await,
+            CookieChange::DeleteAccount(account_id) => {
+                CookieEntity::delete_by_account(db, *account_id).await
+            }
+            CookieChange::MoveAccount { from, to } => {
+                CookieEntity::move_account(db, *from, *to).await
+            }
+        }
+    }
+}
+
+fn same_cookie(a: &Cookie, b: &Cookie) -> bool {
+    a.account_id == b.account_id && a.domain == b.domain && a.path == b.path && a.name == b.name
+}
 
 impl DatabaseCookieStore {
-    async fn save_cookie(&self, cookie: &::cookie::Cookie<'_>, url: &Url) -> anyhow::Result<()> {
+    /// 从数据库载入所有 cookie，之后的修改写入这个数据库，只能调用一次
+    pub(crate) async fn load(&self, db: DatabaseConnection) -> anyhow::Result<()> {
+        let cookies = CookieEntity::find_all(&db).await?;
+        debug!(count = cookies.len(), "Cookies loaded");
+        *self.jar.write().unwrap() = cookies;
+        self.db
+            .set(db)
+            .map_err(|_| anyhow::anyhow!("Cookie store already loaded"))?;
+        Ok(())
+    }
+
+    /// 启动后台写入任务
+    pub(crate) fn spawn_flush(self: &Arc<Self>) {
+        let store = Arc::clone(self);
+        tokio::spawn(async move {
+            loop {
+                store.notify.notified().await;
+                tokio::time::sleep(FLUSH_DELAY).await;
+                if let Err(err) = store.flush().await {
+                    warn!("Failed to save cookies: {err:?}");
+                    tokio::time::sleep(FLUSH_RETRY_DELAY).await;
+                    store.notify.notify_one();
+                }
+            }
+        });
+    }
+
+    /// 把还没写入的修改在一个事务中写入数据库，失败时保留这些修改等待下次写入
+    /// 还没有载入数据库时不做任何事
+    pub(crate) async fn flush(&self) -> anyhow::Result<()> {
+        let Some(db) = self.db.get() else {
+            return Ok(());
+        };
+        let _guard = self.flush_lock.lock().await;
+        let changes = std::mem::take(&mut *self.pending.lock().unwrap());
+        if changes.is_empty() {
+            return Ok(());
+        }
+        let result = async {
+            let txn = db.begin().await?;
+            for change in &changes {
+                change.apply(&txn).await?;
+            }
+            txn.commit().await?;
+            anyhow::Ok(())
+        }
+        .await;
+        if result.is_err() {
+            let mut pending = self.pending.lock().unwrap();
+            let newer = std::mem::replace(&mut *pending, changes);
+            pending.extend(newer);
+            self.notify.notify_one();
+        }
+        result
+    }
+
+    fn push_change(&self, change: CookieChange) {
+        self.pending.lock().unwrap().push(change);
+        self.notify.notify_one();
+    }
+
+    fn put(&self, cookie: Cookie) {
+        let mut jar = self.jar.write().unwrap();
+        match jar.iter_mut().find(|saved| same_cookie(saved, &cookie)) {
+            Some(saved) => *saved = cookie.clone(),
+            None => jar.push(cookie.clone()),
+        }
+        self.push_change(CookieChange::Save(cookie));
+    }
+
+    fn remove(&self, cookie: &Cookie) {
+        let mut jar = self.jar.write().unwrap();
+        jar.retain(|saved| !same_cookie(saved, cookie));
+        self.push_change(CookieChange::Delete {
+            account_id: cookie.account_id,
+            domain: cookie.domain.clone(),
+            path: cookie.path.clone(),
+            name: cookie.name.clone(),
+        });
+    }
+
+    /// 按 RFC 6265 5.3 保存响应中的 cookie，过期的 cookie 会删除已保存的同名 cookie
+    pub(crate) fn save_cookie(&self, cookie: &::cookie::Cookie<'_>, url: &Url) {
+        let Some(host) = url.host_str().map(|host| host.to_ascii_lowercase()) else {
+            return;
+        };
+        let now = chrono::Utc::now().timestamp();
+        // Max-Age 优先于 Expires，Max-Age 不大于 0 时立即过期
+        let expires = match cookie.max_age() {
+            // 服务器可能给出很大的 Max-Age，相加不能溢出
+            Some(max_age) => Some(now.saturating_add(max_age.whole_seconds())),
+            None => cookie.expires_datetime().map(|e| e.unix_timestamp()),
+        };
         let domain = cookie
             .domain()
-            .unwrap_or_else(|| url.host_str().unwrap())
-            .to_string();
-        let path = cookie.path().unwrap_or("/").to_string();
-        let expires = cookie
-            .expires()
-            .map(|e| e.datetime().map(|e| e.unix_timestamp()));
-
-        let model = crate::database::entities::Cookie {
+            .map(|domain| domain.trim_start_matches('.').to_ascii_lowercase())
+            .filter(|domain| !domain.is_empty());
+        let (domain, host_only) = match domain {
+            Some(domain) => {
+                // 只能设置给自己或上级域名，不能设置给顶级域名
+                if !domain_match(&host, &domain) || (!domain.contains('.') && domain != host) {
+                    debug!(%host, %domain, name = cookie.name(), "Cookie domain rejected");
+                    return;
+                }
+                (domain, false)
+            }
+            None => (host, true),
+        };
+        let path = match cookie.path() {
+            Some(path) if path.starts_with('/') => path.to_string(),
+            _ => default_path(url.path()),
+        };
+        let model = Cookie {
+            account_id: crate::account::active_account_id(),
             domain,
+            path,
             name: cookie.name().to_string(),
             value: cookie.value().to_string(),
-            path,
-            expires: expires.unwrap_or(None),
-            secure: cookie.secure(),
-            http_only: cookie.http_only(),
+            expires,
+            secure: Some(cookie.secure().unwrap_or(false)),
+            http_only: Some(cookie.http_only().unwrap_or(false)),
+            host_only: Some(host_only),
         };
+        if model.expires.is_some_and(|expires| expires <= now) {
+            self.remove(&model);
+        } else {
+            self.put(model);
+        }
+    }
 
-        CookieEntity::save_or_update_cookie(model).await?;
-        Ok(())
+    /// 手动写入 cookie（例如 WebView 拿到的 cf_clearance），只发送给该地址的主机
+    pub fn import_cookie(&self, url: &Url, name: &str, value: &str) {
+        self.put(Cookie {
+            account_id: crate::account::active_account_id(),
+            domain: url.host_str().unwrap_or_default().to_ascii_lowercase(),
+            path: "/".to_string(),
+            name: name.to_string(),
+            value: value.to_string(),
+            expires: None,
+            secure: Some(url.scheme() == "https"),
+            http_only: Some(true),
+            host_only: Some(true),
+        });
     }
 
-    async fn load_cookies(&self, url: &Url) -> anyhow::Result<Vec<::cookie::Cookie>> {
-        let mut host = url.host_str().unwrap();
-        if host.contains(".wenku8.") && host != "app.wenku8.com" {
-            host = "www.wenku8.net";
+    /// 把浏览器导出的 cookie 导入到 account_id，返回导入的数量
+    /// 只保留 hosts 和它们的子域名、上级域名下的，已过期的跳过
+    pub fn import_cookies(&self, account_id: i64, hosts: &[String], cookies: Vec<Cookie>) -> usize {
+        let now = chrono::Utc::now().timestamp();
+        let mut count = 0;
+        for mut cookie in cookies {
+            cookie.domain = cookie
+                .domain
+                .trim()
+                .trim_start_matches('.')
+                .to_ascii_lowercase();
+            let domain = cookie.domain.as_str();
+            if !hosts.iter().any(|host| {
+                domain == host
+                    || domain.ends_with(&format!(".{host}"))
+                    || domain_match(host, domain)
+            }) {
+                continue;
+            }
+            if cookie.expires.is_some_and(|expires| expires <= now) {
+                continue;
+            }
+            cookie.account_id = account_id;
+            self.put(cookie);
+            count += 1;
         }
-        let cookies = CookieEntity::find_by_domain(host).await?;
-        let cookies = cookies
-            .into_iter()
-            .map(|e| ::cookie::Cookie::new(e.name, e.value))
-            .collect();
-        Ok(cookies)
+        count
+    }
+
+    /// 账号的所有 cookie
+    pub fn export_cookies(&self, account_id: i64) -> Vec<Cookie> {
+        self.jar
+            .read()
+            .unwrap()
+            .iter()
+            .filter(|cookie| cookie.account_id == account_id)
+            .cloned()
+            .collect()
+    }
+
+    /// 账号是否有名为 name 且没有过期的 cookie
+    pub(crate) fn has_cookie(&self, account_id: i64, name: &str) -> bool {
+        let now = chrono::Utc::now().timestamp();
+        self.jar.read().unwrap().iter().any(|cookie| {
+            cookie.account_id == account_id
+                && cookie.name == name
+                && cookie.expires.is_none_or(|expires| expires > now)
+        })
+    }
+
+    pub(crate) fn delete_account(&self, account_id: i64) {
+        let mut jar = self.jar.write().unwrap();
+        jar.retain(|cookie| cookie.account_id != account_id);
+        self.push_change(CookieChange::DeleteAccount(account_id));
+    }
+
+    /// 把之前 export_cookies 得到的 cookie 原样放回
+    pub(crate) fn restore_cookies(&self, cookies: Vec<Cookie>) {
+        for cookie in cookies {
+            self.put(cookie);
+        }
+    }
+
+    /// 把 from 的 cookie 转给 to，to 原有的 cookie 会被删除
+    pub(crate) fn move_account(&self, from: i64, to: i64) {
+        let mut jar = self.jar.write().unwrap();
+        jar.retain(|cookie| cookie.account_id != to);
+        for cookie in jar.iter_mut().filter(|cookie| cookie.account_id == from) {
+            cookie.account_id = to;
+        }
+        self.push_change(CookieChange::MoveAccount { from, to });
+    }
+
+    /// 按 RFC 6265 5.4 找出发送给 url 的 cookie，路径长的在前
+    /// 顺便删除已经过期的 cookie
+    pub(crate) fn load_cookies(&self, url: &Url) -> Vec<Cookie> {
+        let Some(host) = url.host_str().map(|host| host.to_ascii_lowercase()) else {
+            return vec![];
+        };
+        let now = chrono::Utc::now().timestamp();
+        let account_id = crate::account::active_account_id();
+        let secure = matches!(url.scheme(), "https" | "wss");
+        let is_expired = |cookie: &Cookie| cookie.expires.is_some_and(|expires| expires <= now);
+        let (mut cookies, has_expired) = {
+            let jar = self.jar.read().unwrap();
+            let cookies = jar
+                .iter()
+                .filter(|cookie| cookie.account_id == account_id)
+                .filter(|cookie| !is_expired(cookie))
+                .filter(|cookie| secure || !cookie.secure.unwrap_or(false))
+                .filter(|cookie| {
+                    if cookie.host_only.unwrap_or(false) {
+                        host == cookie.domain
+                    } else {
+                        domain_match(&host, &cookie.domain)
+                    }
+                })
+                .filter(|cookie| path_match(url.path(), &cookie.path))
+                .cloned()
+                .collect::<Vec<_>>();
+            (cookies, jar.iter().any(is_expired))
+        };
+        if has_expired {
+            let mut jar = self.jar.write().unwrap();
+            jar.retain(|cookie| !is_expired(cookie));
+            self.push_change(CookieChange::DeleteExpired(now));
+        }
+        cookies.sort_by_key(|cookie| std::cmp::Reverse(cookie.path.len()));
+        cookies
+    }
+}
+
+/// RFC 6265 5.1.3，host 等于 domain 或者是 domain 的子域名，IP 地址只能完全相等
+pub(crate) fn domain_match(host: &str, domain: &str) -> bool {
+    if host == domain {
+        return true;
+    }
+    host.ends_with(domain)
+        && host[..host.len() - domain.len()].ends_with('.')
+        && host.parse::<std::net::IpAddr>().is_err()
+}
+
+/// RFC 6265 5.1.4，cookie 的路径等于请求路径或是它的上级目录
+pub(crate) fn path_match(request_path: &str, cookie_path: &str) -> bool {
+    if request_path == cookie_path {
+        return true;
+    }
+    request_path.starts_with(cookie_path)
+        && (cookie_path.ends_with('/') || request_path[cookie_path.len()..].starts_with('/'))
+}
+
+/// RFC 6265 5.1.4，没有 Path 属性时使用请求路径所在的目录
+pub(crate) fn default_path(request_path: &str) -> String {
+    if !request_path.starts_with('/') {
+        return "/".to_string();
+    }
+    match request_path.rfind('/') {
+        Some(0) | None => "/".to_string(),
+        Some(index) => request_path[..index].to_string(),
     }
 }
 
-#[async_trait::async_trait]
 impl CookieStore for DatabaseCookieStore {
     fn set_cookies(&self, cookie_headers: &mut dyn Iterator<Item = &HeaderValue>, url: &Url) {
         for header in cookie_headers {
-            if let Ok(cookie) = ::cookie::Cookie::parse(header.to_str().unwrap()) {
-                let _ = tokio::task::block_in_place(|| {
-                    tokio::runtime::Handle::current()
-                        .block_on(async move { self.save_cookie(&cookie, &url).await })
-                });
+            let Ok(header) = header.to_str() else {
+                continue;
+            };
+            if let Ok(cookie) = ::cookie::Cookie::parse(header) {
+                self.save_cookie(&cookie, url);
             }
         }
     }
 
     fn cookies(&self, url: &Url) -> Option<HeaderValue> {
-        if let Ok(cookies) = tokio::task::block_in_place(|| {
-            tokio::runtime::Handle::current().block_on(async move { self.load_cookies(url).await })
-        }) {
-            let cookies: Vec<_> = cookies.into_iter().collect();
-            let s = cookies
-                .into_iter()
-                .map(|c| format!("{}={}", c.name(), c.value()))
-                .collect::<Vec<_>>()
-                .join("; ");
-
-            if s.is_empty() {
-                return None;
-            }
+        let s = self
+            .load_cookies(url)
+            .into_iter()
+            .map(|c| format!("{}={}", c.name, c.value))
+            .collect::<Vec<_>>()
+            .join("; ");
 
-            HeaderValue::from_maybe_shared(Bytes::from(s)).ok()
-        } else {
-            None
+        if s.is_empty() {
+            return None;
         }
+
+        HeaderValue::from_maybe_shared(Bytes::from(s)).ok()
     }
 }
@@ -1,27 +1,36 @@
-use sea_orm::Schema;
 use sea_orm_migration::prelude::*;
 
+/// 表结构按这个版本写死，不随 Entity 变化，之后的迁移才能在新安装时按顺序执行
 #[derive(DeriveMigrationName)]
 pub struct Migration;
 
 #[async_trait::async_trait]
 impl MigrationTrait for Migration {
     async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
-        let db = manager.get_connection();
-        let backend = db.get_database_backend();
-        let schema = Schema::new(backend);
         manager
             .create_table(
-                schema
-                    .create_table_from_entity(super::super::cookie::Entity)
+                Table::create()
+                    .table(Alias::new("cookie"))
                     .if_not_exists()
+                    .col(ColumnDef::new(Alias::new("domain")).string().not_null())
+                    .col(ColumnDef::new(Alias::new("name")).string().not_null())
+                    .col(ColumnDef::new(Alias::new("value")).string().not_null())
+                    .col(ColumnDef::new(Alias::new("path")).string().not_null())
+                    .col(ColumnDef::new(Alias::new("expires")).big_integer().null())
+                    .col(ColumnDef::new(Alias::new("secure")).boolean().null())
+                    .col(ColumnDef::new(Alias::new("http_only")).boolean().null())
+                    .primary_key(
+                        Index::create()
+                            .col(Alias::new("domain"))
+                            .col(Alias::new("name")),
+                    )
                     .to_owned(),
             )
             .await?;
         Ok(())
     }
 
-    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
-       Ok(())
+    async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
+        Ok(())
     }
 }
@@ -0,0 +1,62 @@
+use sea_orm_migration::prelude::*;
+
+/// 主键改为 (account_id, domain, name)，SQLite 不能修改主键，只能重建表
+/// 已有的 cookie 归到 0，即未绑定账号
+/// 新表按这个版本写死：用当前的 Entity 建表会提前带上 host_only，m000004 的清理就不会执行
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if manager.has_column("cookie", "account_id").await? {
+            return Ok(());
+        }
+        let db = manager.get_connection();
+        manager
+            .create_table(
+                Table::create()
+                    .table(Alias::new("cookie_new"))
+                    .col(
+                        ColumnDef::new(Alias::new("account_id"))
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(Alias::new("domain")).string().not_null())
+                    .col(ColumnDef::new(Alias::new("name")).string().not_null())
+                    .col(ColumnDef::new(Alias::new("value")).string().not_null())
+                    .col(ColumnDef::new(Alias::new("path")).string().not_null())
+                    .col(ColumnDef::new(Alias::new("expires")).big_integer().null())
+                    .col(ColumnDef::new(Alias::new("secure")).boolean().null())
+                    .col(ColumnDef::new(Alias::new("http_only")).boolean().null())
+                    .primary_key(
+                        Index::create()
+                            .col(Alias::new("account_id"))
+                            .col(Alias::new("domain"))
+                            .col(Alias::new("name")),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        db.execute_unprepared(
+            "INSERT INTO cookie_new (account_id, domain, name, value, path, expires, secure, http_only) \
+             SELECT 0, domain, name, value, path, expires, secure, http_only FROM cookie",
+        )
+        .await?;
+        manager
+            .drop_table(Table::drop().table(Alias::new("cookie")).to_owned())
+            .await?;
+        manager
+            .rename_table(
+                Table::rename()
+                    .table(Alias::new("cookie_new"), Alias::new("cookie"))
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
+        Ok(())
+    }
+}
@@ -0,0 +1,64 @@
+use sea_orm_migration::prelude::*;
+
+/// 添加 host_only，主键加上 path，SQLite 不能修改主键，只能重建表
+/// 旧数据的域名去掉前导的点，host_only 为 NULL 按域名 cookie 处理
+/// 新表按这个版本写死，不随 Entity 变化
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if manager.has_column("cookie", "host_only").await? {
+            return Ok(());
+        }
+        let db = manager.get_connection();
+        manager
+            .create_table(
+                Table::create()
+                    .table(Alias::new("cookie_new"))
+                    .col(
+                        ColumnDef::new(Alias::new("account_id"))
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(Alias::new("domain")).string().not_null())
+                    .col(ColumnDef::new(Alias::new("path")).string().not_null())
+                    .col(ColumnDef::new(Alias::new("name")).string().not_null())
+                    .col(ColumnDef::new(Alias::new("value")).string().not_null())
+                    .col(ColumnDef::new(Alias::new("expires")).big_integer().null())
+                    .col(ColumnDef::new(Alias::new("secure")).boolean().null())
+                    .col(ColumnDef::new(Alias::new("http_only")).boolean().null())
+                    .col(ColumnDef::new(Alias::new("host_only")).boolean().null())
+                    .primary_key(
+                        Index::create()
+                            .col(Alias::new("account_id"))
+                            .col(Alias::new("domain"))
+                            .col(Alias::new("path"))
+                            .col(Alias::new("name")),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        db.execute_unprepared(
+            "INSERT OR REPLACE INTO cookie_new (account_id, domain, path, name, value, expires, secure, http_only) \
+             SELECT account_id, ltrim(domain, '.'), path, name, value, expires, secure, http_only FROM cookie",
+        )
+        .await?;
+        manager
+            .drop_table(Table::drop().table(Alias::new("cookie")).to_owned())
+            .await?;
+        manager
+            .rename_table(
+                Table::rename()
+                    .table(Alias::new("cookie_new"), Alias::new("cookie"))
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
+        Ok(())
+    }
+}
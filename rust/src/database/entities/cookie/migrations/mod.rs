@@ -1,12 +1,20 @@
 pub use sea_orm_migration::prelude::*;
 
 mod m000001_create_table_cookies;
+mod m000002_create_table_account;
+mod m000003_add_account_id_cookies;
+mod m000004_add_host_only_cookies;
 
 pub struct Migrator;
 
 #[async_trait::async_trait]
 impl MigratorTrait for Migrator {
     fn migrations() -> Vec<Box<dyn MigrationTrait>> {
-        vec![Box::new(m000001_create_table_cookies::Migration)]
+        vec![
+            Box::new(m000001_create_table_cookies::Migration),
+            Box::new(m000002_create_table_account::Migration),
+            Box::new(m000003_add_account_id_cookies::Migration),
+            Box::new(m000004_add_host_only_cookies::Migration),
+        ]
     }
-} 
\ No newline at end of file
+}
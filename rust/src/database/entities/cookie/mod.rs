@@ -5,15 +5,21 @@ use sea_orm_migration::{MigrationTrait, MigratorTrait};
 
 use crate::database::COOKIE_DB_CONNECT;
 
+pub mod account;
 pub mod cookie;
 pub mod cookie_store;
-mod migrations;
+pub(crate) mod migrations;
 
 async fn get_connect() -> tokio::sync::MutexGuard<'static, DatabaseConnection> {
     COOKIE_DB_CONNECT.get().unwrap().lock().await
 }
 
 pub(crate) async fn migrations() -> crate::Result<()> {
-    migrations::Migrator::up(get_connect().await.deref(), None).await?;
+    migrations_on(get_connect().await.deref()).await
+}
+
+/// 在指定的数据库上建表，测试时用于内存数据库
+pub(crate) async fn migrations_on(db: &DatabaseConnection) -> crate::Result<()> {
+    migrations::Migrator::up(db, None).await?;
     Ok(())
 }
@@ -17,6 +17,9 @@ pub use active::chapter_cache::Model as ChapterCache;
 pub use cookie::cookie::Entity as CookieEntity;
 pub use cookie::cookie::Model as Cookie;
 
+pub use cookie::account::Entity as AccountEntity;
+pub use cookie::account::Model as Account;
+
 pub use active::web_cache::Entity as WebCacheEntity;
 pub use active::web_cache::Model as WebCache;
 
@@ -26,3 +29,9 @@ pub use active::search_history::Model as SearchHistory;
 pub use active::sign_log::Model as SignLog;
 pub use active::sign_log::Entity as SignLogEntity;
 
+pub use active::recommend_log::Entity as RecommendLogEntity;
+
+
+pub use active::novel_update::Entity as NovelUpdateEntity;
+
+pub use active::chapter_snapshot::Entity as ChapterSnapshotEntity;
@@ -1,37 +1,606 @@
 use crate::{
+    api::database::{load_property, save_property},
+    chapter_document::{parse_segments, ChapterSegmentKind},
     database::entities::active::{
         self, novel_download, novel_download_chapter, novel_download_picture, novel_download_volume,
+        DOWNLOAD_STATUS_FAILED, DOWNLOAD_STATUS_NOT_DOWNLOAD, DOWNLOAD_STATUS_PAUSED,
+        DOWNLOAD_STATUS_SUCCESS,
     },
-    Result, CLIENT, DOWNLOAD_FOLDER,
+    source::{source, source_key, NovelSource},
+    wenku8::Language,
+    Result, DOWNLOAD_FOLDER,
 };
+use anyhow::anyhow;
 use once_cell::sync::Lazy;
 use sea_orm::{EntityTrait, QueryFilter, QueryOrder, QuerySelect};
-use std::path::Path;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
-use tokio::sync::Mutex;
-use tokio::task::spawn;
+use tokio::sync::{broadcast, Mutex};
+use tokio::task::{spawn, JoinSet};
 use tracing::{debug, error, info, instrument, warn, Level};
 
 pub(crate) static RESTART_FLAG: Lazy<Mutex<bool>> = Lazy::new(|| Mutex::new(false));
 
+// 全局暂停开关，暂停时只处理删除
+static DOWNLOAD_PAUSED: Lazy<Mutex<bool>> = Lazy::new(|| Mutex::new(false));
+
+const DEFAULT_DOWNLOAD_CONCURRENCY: u64 = 3;
+const MAX_DOWNLOAD_CONCURRENCY: u64 = 16;
+
+// 下载进度事件，没有订阅者时直接丢弃
+static DOWNLOAD_EVENTS: Lazy<broadcast::Sender<DownloadEvent>> =
+    Lazy::new(|| broadcast::channel(256).0);
+
+/// 下载进度事件的类型
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DownloadEventKind {
+    ChapterStarted,
+    ChapterDone,
+    ChapterFailed,
+    PictureDone,
+    NovelCompleted,
+    NovelFailed,
+    Deleted,
+}
+
+/// 下载进度事件，通过 StreamSink 推送给 Flutter
+/// 与 kind 无关的字段为空字符串或 0
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DownloadEvent {
+    pub kind: DownloadEventKind,
+    pub source_id: String,
+    pub language: String,
+    pub novel_id: String,
+    pub volume_id: String,
+    pub chapter_id: String,
+    /// ChapterDone/NovelCompleted 为已下载章节数，PictureDone 为已下载图片数
+    pub download_count: i32,
+    /// ChapterDone 为选择的章节数，PictureDone 为图片总数
+    pub total_count: i32,
+    /// ChapterFailed 的错误信息
+    pub error: String,
+}
+
+impl DownloadEvent {
+    fn novel(kind: DownloadEventKind, source_id: &str, language: &str, novel_id: &str) -> Self {
+        Self {
+            kind,
+            source_id: source_id.to_string(),
+            language: language.to_string(),
+            novel_id: novel_id.to_string(),
+            volume_id: String::new(),
+            chapter_id: String::new(),
+            download_count: 0,
+            total_count: 0,
+            error: String::new(),
+        }
+    }
+
+    fn chapter(
+        kind: DownloadEventKind,
+        source_id: &str,
+        language: &str,
+        novel_id: &str,
+        volume_id: &str,
+        chapter_id: &str,
+    ) -> Self {
+        Self {
+            volume_id: volume_id.to_string(),
+            chapter_id: chapter_id.to_string(),
+            ..Self::novel(kind, source_id, language, novel_id)
+        }
+    }
+}
+
+pub(crate) fn subscribe_events() -> broadcast::Receiver<DownloadEvent> {
+    DOWNLOAD_EVENTS.subscribe()
+}
+
+fn publish(event: DownloadEvent) {
+    let _ = DOWNLOAD_EVENTS.send(event);
+}
+
+async fn publish_chapter_done(chapter: &novel_download_chapter::Model) -> Result<()> {
+    if let Some(novel) = novel_download::Entity::find_by_novel_id(
+        &chapter.source_id,
+        &chapter.language,
+        &chapter.aid,
+    )
+    .await?
+    {
+        publish(DownloadEvent {
+            download_count: novel.download_chapter_count,
+            total_count: novel.choose_chapter_count,
+            ..chapter_event(DownloadEventKind::ChapterDone, chapter)
+        });
+    }
+    Ok(())
+}
+
+async fn publish_picture_done(picture: &novel_download_picture::Model) -> Result<()> {
+    let pictures = novel_download_picture::Entity::find_by_novel_id(
+        &picture.source_id,
+        &picture.language,
+        &picture.aid,
+    )
+    .await?;
+    publish(DownloadEvent {
+        download_count: pictures.iter().filter(|p| p.download_status == 1).count() as i32,
+        total_count: pictures.len() as i32,
+        ..DownloadEvent::chapter(
+            DownloadEventKind::PictureDone,
+            &picture.source_id,
+            &picture.language,
+            &picture.aid,
+            &picture.volume_id,
+            &picture.chapter_id,
+        )
+    });
+    Ok(())
+}
+
+fn publish_chapter_failed(chapter: &novel_download_chapter::Model, error: String) {
+    publish(DownloadEvent {
+        error,
+        ..chapter_event(DownloadEventKind::ChapterFailed, chapter)
+    });
+}
+
+fn chapter_event(kind: DownloadEventKind, chapter: &novel_download_chapter::Model) -> DownloadEvent {
+    DownloadEvent::chapter(
+        kind,
+        &chapter.source_id,
+        &chapter.language,
+        &chapter.aid,
+        &chapter.volume_id,
+        &chapter.id,
+    )
+}
+
+/// 小说的下载目录，其他书源的小说ID带书源前缀，简繁版本分别放在语言子目录
+pub(crate) fn novel_dir(source_id: &str, language: &str, novel_id: &str) -> Result<PathBuf> {
+    Ok(novel_root_dir(source_id, novel_id)?.join(language))
+}
+
+fn novel_root_dir(source_id: &str, novel_id: &str) -> Result<PathBuf> {
+    let download_folder = DOWNLOAD_FOLDER
+        .get()
+        .ok_or_else(|| anyhow!("Download folder not initialized"))?;
+    Ok(Path::new(download_folder).join(source_key(source_id, novel_id)))
+}
+
+/// 旧版本的下载文件直接放在小说目录下，移到下载时语言的子目录
+async fn move_legacy_files() -> Result<()> {
+    for novel in novel_download::Entity::find_all().await? {
+        let root_dir = novel_root_dir(&novel.source_id, &novel.novel_id)?;
+        let mut entries = match tokio::fs::read_dir(&root_dir).await {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+            Err(e) => return Err(e.into()),
+        };
+        let language_dir = root_dir.join(&novel.language);
+        while let Some(entry) = entries.next_entry().await? {
+            if !entry.file_type().await?.is_file() {
+                continue;
+            }
+            tokio::fs::create_dir_all(&language_dir).await?;
+            tokio::fs::rename(entry.path(), language_dir.join(entry.file_name())).await?;
+        }
+    }
+    Ok(())
+}
+
 async fn need_restart() -> bool {
     *RESTART_FLAG.lock().await
 }
 
+async fn request_restart() {
+    *RESTART_FLAG.lock().await = true;
+}
+
+pub(crate) async fn downloads_paused() -> bool {
+    *DOWNLOAD_PAUSED.lock().await
+}
+
+pub(crate) async fn set_downloads_paused(paused: bool) -> Result<()> {
+    save_property("download_paused".to_string(), paused.to_string()).await?;
+    *DOWNLOAD_PAUSED.lock().await = paused;
+    if paused {
+        request_restart().await;
+    }
+    info!(paused, "Set downloads paused");
+    Ok(())
+}
+
+/// 暂停单本小说，只有等待下载的小说可以暂停
+pub(crate) async fn pause_download(
+    source_id: &str,
+    language: &str,
+    novel_id: &str,
+) -> Result<()> {
+    if novel_download::Entity::update_status_from(
+        source_id,
+        language,
+        novel_id,
+        DOWNLOAD_STATUS_NOT_DOWNLOAD,
+        DOWNLOAD_STATUS_PAUSED,
+    )
+    .await?
+    {
+        request_restart().await;
+    }
+    Ok(())
+}
+
+pub(crate) async fn resume_download(
+    source_id: &str,
+    language: &str,
+    novel_id: &str,
+) -> Result<()> {
+    novel_download::Entity::update_status_from(
+        source_id,
+        language,
+        novel_id,
+        DOWNLOAD_STATUS_PAUSED,
+        DOWNLOAD_STATUS_NOT_DOWNLOAD,
+    )
+    .await?;
+    Ok(())
+}
+
+/// 把小说移到下载队列最前面，正在下载的小说会被打断
+pub(crate) async fn move_download_to_front(
+    source_id: &str,
+    language: &str,
+    novel_id: &str,
+) -> Result<()> {
+    let priority = novel_download::Entity::max_priority().await? + 1;
+    novel_download::Entity::update_priority(source_id, language, novel_id, priority).await?;
+    request_restart().await;
+    Ok(())
+}
+
+/// 按给定顺序重排下载队列，排在前面的先下载
+pub(crate) async fn reorder_downloads(
+    source_id: &str,
+    language: &str,
+    novel_ids: &[String],
+) -> Result<()> {
+    let count = novel_ids.len() as i32;
+    for (idx, novel_id) in novel_ids.iter().enumerate() {
+        novel_download::Entity::update_priority(source_id, language, novel_id, count - idx as i32).await?;
+    }
+    request_restart().await;
+    Ok(())
+}
+
+/// 同时下载的章节数和图片数
+pub(crate) async fn download_concurrency() -> Result<u64> {
+    let value = load_property("download_concurrency".to_string()).await?;
+    Ok(value
+        .parse::<u64>()
+        .unwrap_or(DEFAULT_DOWNLOAD_CONCURRENCY)
+        .clamp(1, MAX_DOWNLOAD_CONCURRENCY))
+}
+
+pub(crate) async fn set_download_concurrency(concurrency: u64) -> Result<()> {
+    let concurrency = concurrency.clamp(1, MAX_DOWNLOAD_CONCURRENCY);
+    save_property("download_concurrency".to_string(), concurrency.to_string()).await?;
+    Ok(())
+}
+
 pub async fn reset_fail_downloads() -> Result<()> {
     novel_download_picture::Entity::reset_fail_downloads().await?;
     novel_download_chapter::Entity::reset_fail_downloads().await?;
     novel_download_volume::Entity::reset_fail_downloads().await?;
     novel_download::Entity::reset_fail_downloads().await?;
     // need restart
-    *RESTART_FLAG.lock().await = true;
+    request_restart().await;
     info!("Reset fail downloads");
     Ok(())
 }
 
+/// 提取章节正文中的插图地址
+pub(crate) fn chapter_picture_urls(content: &str) -> Vec<String> {
+    parse_segments(content)
+        .into_iter()
+        .filter(|segment| segment.kind == ChapterSegmentKind::Image)
+        .map(|segment| segment.url)
+        .collect()
+}
+
+/// 登记章节插图到 novel_download_picture，并更新章节图片数
+/// 重新下载后插图变少时，删除多出来的记录和不再使用的图片文件
+async fn save_chapter_pictures(
+    chapter: &novel_download_chapter::Model,
+    content: &str,
+    novel_dir: &Path,
+) -> Result<()> {
+    let (source_id, language) = (chapter.source_id.as_str(), chapter.language.as_str());
+    let (aid, volume_id, chapter_id) = (&chapter.aid, &chapter.volume_id, &chapter.id);
+    let urls = chapter_picture_urls(content);
+    for (picture_idx, url) in urls.iter().enumerate() {
+        let url_md5 = hex::encode(md5::compute(url.as_bytes()).0);
+        novel_download_picture::Entity::upsert(
+            source_id,
+            language,
+            aid,
+            volume_id,
+            chapter_id,
+            picture_idx as i32,
+            url,
+            &url_md5,
+            DOWNLOAD_STATUS_NOT_DOWNLOAD,
+        )
+        .await?;
+    }
+    let removed = novel_download_picture::Entity::delete_from_idx(
+        source_id,
+        language,
+        aid,
+        volume_id,
+        chapter_id,
+        urls.len() as i32,
+    )
+    .await?;
+    if !removed.is_empty() {
+        // 同一张图片可能在其他章节中使用，还有记录引用时保留文件
+        let in_use = novel_download_picture::Entity::find_by_novel_id(source_id, language, aid)
+            .await?
+            .into_iter()
+            .map(|picture| picture.url_md5)
+            .collect::<HashSet<_>>();
+        for picture in removed {
+            if in_use.contains(&picture.url_md5) {
+                continue;
+            }
+            let picture_file_path = novel_dir.join(format!("picture_{}", picture.url_md5));
+            if let Err(e) = tokio::fs::remove_file(&picture_file_path).await {
+                if e.kind() != std::io::ErrorKind::NotFound {
+                    warn!(path = ?picture_file_path, error = %e, "Failed to delete picture file");
+                }
+            }
+        }
+    }
+    novel_download_chapter::Entity::update_total_picture(
+        source_id,
+        language,
+        chapter_id,
+        urls.len() as i32,
+    )
+    .await?;
+    debug!(chapter_id = %chapter_id, total_picture = urls.len(), "Saved chapter pictures");
+    Ok(())
+}
+
+/// 下载单个章节，由下载线程池并发调用
+async fn download_chapter(
+    source: &'static dyn NovelSource,
+    chapter: novel_download_chapter::Model,
+    novel_dir: PathBuf,
+    language: Language,
+) {
+    debug!(
+        novel_id = %chapter.aid,
+        volume_id = %chapter.volume_id,
+        chapter_id = %chapter.id,
+        chapter_title = %chapter.title,
+        "Processing chapter"
+    );
+    if need_restart().await {
+        warn!(chapter_id = %chapter.id, "Download interrupted");
+        return;
+    }
+
+    let chapter_file_path = novel_dir.join(format!("chapter_{}", chapter.id));
+    publish(chapter_event(DownloadEventKind::ChapterStarted, &chapter));
+
+    // Download chapter content
+    match source
+        .chapter_content_in(&chapter.aid, &chapter.id, language)
+        .await
+    {
+        Ok(chapter_content) => {
+            match tokio::fs::write(&chapter_file_path, &chapter_content).await {
+                Ok(_) => {
+                    debug!(
+                        novel_id = %chapter.aid,
+                        chapter_id = %chapter.id,
+                        "Successfully downloaded chapter"
+                    );
+                    let status =
+                        match save_chapter_pictures(&chapter, &chapter_content, &novel_dir).await {
+                            Ok(_) => DOWNLOAD_STATUS_SUCCESS,
+                            Err(e) => {
+                                error!(
+                                    novel_id = %chapter.aid,
+                                    chapter_id = %chapter.id,
+                                    error = %e,
+                                    "Failed to save chapter pictures"
+                                );
+                                publish_chapter_failed(&chapter, e.to_string());
+                                DOWNLOAD_STATUS_FAILED
+                            }
+                        };
+                    if update_chapter_status(&chapter, status).await
+                        && status == DOWNLOAD_STATUS_SUCCESS
+                    {
+                        // 更新小说下载章节数
+                        if let Err(e) = novel_download::Entity::add_one_download_chapter_count(
+                            &chapter.source_id,
+                            &chapter.language,
+                            &chapter.aid,
+                        )
+                        .await
+                        {
+                            error!(
+                                novel_id = %chapter.aid,
+                                chapter_id = %chapter.id,
+                                error = %e,
+                                "Failed to update novel download chapter count"
+                            );
+                        }
+                        if let Err(e) = publish_chapter_done(&chapter).await {
+                            warn!(
+                                novel_id = %chapter.aid,
+                                chapter_id = %chapter.id,
+                                error = %e,
+                                "Failed to publish chapter progress"
+                            );
+                        }
+                    }
+                }
+                Err(e) => {
+                    error!(
+                        novel_id = %chapter.aid,
+                        chapter_id = %chapter.id,
+                        error = %e,
+                        "Failed to write chapter file"
+                    );
+                    publish_chapter_failed(&chapter, e.to_string());
+                    update_chapter_status(&chapter, DOWNLOAD_STATUS_FAILED).await;
+                }
+            }
+        }
+        Err(e) => {
+            error!(
+                novel_id = %chapter.aid,
+                chapter_id = %chapter.id,
+                error = %e,
+                "Failed to download chapter content"
+            );
+            publish_chapter_failed(&chapter, e.to_string());
+            update_chapter_status(&chapter, DOWNLOAD_STATUS_FAILED).await;
+        }
+    }
+}
+
+/// 更新章节状态，成功状态写不进去时退回标记为失败，避免同一章节被反复领取
+async fn update_chapter_status(chapter: &novel_download_chapter::Model, status: i32) -> bool {
+    let update = |status| {
+        novel_download_chapter::Entity::update_status(
+            &chapter.source_id,
+            &chapter.language,
+            &chapter.aid,
+            &chapter.volume_id,
+            &chapter.id,
+            status,
+        )
+    };
+    match update(status).await {
+        Ok(_) => true,
+        Err(e) => {
+            error!(
+                novel_id = %chapter.aid,
+                chapter_id = %chapter.id,
+                error = %e,
+                "Failed to update chapter status"
+            );
+            if status != DOWNLOAD_STATUS_FAILED {
+                if let Err(e) = update(DOWNLOAD_STATUS_FAILED).await {
+                    error!(
+                        novel_id = %chapter.aid,
+                        chapter_id = %chapter.id,
+                        error = %e,
+                        "Failed to mark chapter failed"
+                    );
+                }
+            }
+            false
+        }
+    }
+}
+
+/// 下载单张插图，由下载线程池并发调用
+async fn download_picture(
+    source: &'static dyn NovelSource,
+    picture: novel_download_picture::Model,
+    novel_dir: PathBuf,
+) {
+    debug!(
+        novel_id = %picture.aid,
+        picture_id = %picture.url,
+        "Processing picture"
+    );
+
+    if need_restart().await {
+        warn!(picture_id = %picture.url, "Download interrupted");
+        return;
+    }
+
+    let status = match source.download_image(&picture.url).await {
+        Ok(content) => {
+            let picture_file_path =
+                novel_dir.join(format!("picture_{}", picture.url_md5));
+            match tokio::fs::write(&picture_file_path, content).await {
+                Ok(_) => {
+                    debug!(novel_id = %picture.aid, "Successfully downloaded picture");
+                    DOWNLOAD_STATUS_SUCCESS
+                }
+                Err(e) => {
+                    error!(picture_id = %picture.url, error = %e, "Failed to write picture file");
+                    DOWNLOAD_STATUS_FAILED
+                }
+            }
+        }
+        Err(e) => {
+            error!(picture_id = %picture.url, error = %e, "Failed to download picture");
+            DOWNLOAD_STATUS_FAILED
+        }
+    };
+    if !update_picture_status(&picture, status).await || status != DOWNLOAD_STATUS_SUCCESS {
+        return;
+    }
+    if let Err(e) = publish_picture_done(&picture).await {
+        warn!(picture_id = %picture.url, error = %e, "Failed to publish picture progress");
+    }
+}
+
+/// 更新插图状态，成功状态写不进去时退回标记为失败，避免同一张插图被反复领取
+async fn update_picture_status(picture: &novel_download_picture::Model, status: i32) -> bool {
+    let update = |status| {
+        novel_download_picture::Entity::update_download_status(
+            &picture.source_id,
+            &picture.language,
+            &picture.aid,
+            &picture.volume_id,
+            &picture.chapter_id,
+            picture.picture_idx,
+            status,
+        )
+    };
+    match update(status).await {
+        Ok(_) => true,
+        Err(e) => {
+            error!(picture_id = %picture.url, error = %e, "Failed to update picture status");
+            if status != DOWNLOAD_STATUS_FAILED {
+                if let Err(e) = update(DOWNLOAD_STATUS_FAILED).await {
+                    error!(picture_id = %picture.url, error = %e, "Failed to mark picture failed");
+                }
+            }
+            false
+        }
+    }
+}
+
+/// 等待一批下载任务结束，任务自己处理错误，这里只记录异常退出的任务
+async fn join_workers(mut workers: JoinSet<()>) {
+    while let Some(joined) = workers.join_next().await {
+        if let Err(e) = joined {
+            error!(error = %e, "Download worker panicked");
+        }
+    }
+}
+
 #[instrument(skip_all)]
 pub async fn start_downloading() -> Result<()> {
     info!("Starting download manager...");
+    let paused = load_property("download_paused".to_string()).await?;
+    *DOWNLOAD_PAUSED.lock().await = paused == "true";
+    if let Err(e) = move_legacy_files().await {
+        warn!(error = %e, "Failed to move legacy download files");
+    }
     spawn(downloading_loop());
     Ok(())
 }
@@ -57,15 +626,33 @@ async fn downloading_loop() -> Result<()> {
             );
 
             // Delete novel folder
-            let novel_dir = Path::new(DOWNLOAD_FOLDER.get().unwrap()).join(&novel.novel_id);
+            let novel_dir = novel_dir(&novel.source_id, &novel.language, &novel.novel_id)?;
             match tokio::fs::remove_dir_all(&novel_dir).await {
                 Ok(_) => info!(path = ?novel_dir, "Successfully deleted novel directory"),
                 Err(e) => warn!(path = ?novel_dir, error = %e, "Failed to delete novel directory"),
             }
+            // 另一个语言的版本还在时目录不为空，删除失败即可
+            if let Ok(root_dir) = novel_root_dir(&novel.source_id, &novel.novel_id) {
+                let _ = tokio::fs::remove_dir(root_dir).await;
+            }
 
             // Delete from database
-            match active::remove_download_data(&novel.novel_id).await {
-                Ok(_) => info!(novel_id = %novel.novel_id, "Successfully removed download data"),
+            match active::remove_download_data(
+                &novel.source_id,
+                &novel.language,
+                &novel.novel_id,
+            )
+            .await
+            {
+                Ok(_) => {
+                    info!(novel_id = %novel.novel_id, "Successfully removed download data");
+                    publish(DownloadEvent::novel(
+                        DownloadEventKind::Deleted,
+                        &novel.source_id,
+                        &novel.language,
+                        &novel.novel_id,
+                    ));
+                }
                 Err(e) => {
                     error!(novel_id = %novel.novel_id, error = %e, "Failed to remove download data")
                 }
@@ -78,6 +665,11 @@ async fn downloading_loop() -> Result<()> {
             continue;
         }
 
+        if downloads_paused().await {
+            tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
+            continue;
+        }
+
         // Step 2: Find first incomplete novel
         while let Some(novel) = novel_download::Entity::find_first_not_started().await? {
             if need_restart().await {
@@ -90,36 +682,69 @@ async fn downloading_loop() -> Result<()> {
                 novel_name = %novel.novel_name,
                 "Processing novel"
             );
-            let novel_dir = Path::new(DOWNLOAD_FOLDER.get().unwrap()).join(&novel.novel_id);
+            let novel_dir = novel_dir(&novel.source_id, &novel.language, &novel.novel_id)?;
+            let concurrency = match download_concurrency().await {
+                Ok(concurrency) => concurrency,
+                Err(e) => {
+                    warn!(error = %e, "Failed to load download concurrency, using default");
+                    DEFAULT_DOWNLOAD_CONCURRENCY
+                }
+            };
+            let source = match source(&novel.source_id) {
+                Ok(source) => source,
+                Err(e) => {
+                    error!(novel_id = %novel.novel_id, error = %e, "Novel source not available");
+                    if let Err(e) = novel_download::Entity::update_status(
+                        &novel.source_id,
+                        &novel.language,
+                        &novel.novel_id,
+                        DOWNLOAD_STATUS_FAILED,
+                    )
+                    .await
+                    {
+                        error!(novel_id = %novel.novel_id, error = %e, "Failed to update novel status");
+                    }
+                    continue;
+                }
+            };
+
+            match tokio::fs::create_dir_all(&novel_dir).await {
+                Ok(_) => debug!(path = ?novel_dir, "Created/verified novel directory"),
+                Err(e) => {
+                    error!(path = ?novel_dir, error = %e, "Failed to create novel directory");
+                    continue;
+                }
+            }
 
             if novel.cover_download_status == 0 {
-                match CLIENT.download_image(&novel.cover_url).await {
+                let cover_status = match source.download_image(&novel.cover_url).await {
                     Ok(cover_content) => {
                         let cover_file_path = novel_dir.join("cover");
                         match tokio::fs::write(&cover_file_path, cover_content).await {
                             Ok(_) => {
                                 debug!(novel_id = %novel.novel_id, "Successfully downloaded cover");
-                                novel_download::Entity::update_cover_download_status(
-                                    &novel.novel_id,
-                                    1,
-                                )
-                                .await?;
+                                DOWNLOAD_STATUS_SUCCESS
                             }
                             Err(e) => {
                                 error!(novel_id = %novel.novel_id, error = %e, "Failed to write cover file");
-                                novel_download::Entity::update_cover_download_status(
-                                    &novel.novel_id,
-                                    2,
-                                )
-                                .await?;
+                                DOWNLOAD_STATUS_FAILED
                             }
                         }
                     }
                     Err(e) => {
                         error!(novel_id = %novel.novel_id, error = %e, "Failed to download cover");
-                        novel_download::Entity::update_cover_download_status(&novel.novel_id, 2)
-                            .await?;
+                        DOWNLOAD_STATUS_FAILED
                     }
+                };
+                if let Err(e) = novel_download::Entity::update_cover_download_status(
+                    &novel.source_id,
+                    &novel.language,
+                    &novel.novel_id,
+                    cover_status,
+                )
+                .await
+                {
+                    error!(novel_id = %novel.novel_id, error = %e, "Failed to update cover status");
                 }
             }
 
@@ -128,21 +753,18 @@ async fn downloading_loop() -> Result<()> {
                 continue;
             }
 
-            match tokio::fs::create_dir_all(&novel_dir).await {
-                Ok(_) => debug!(path = ?novel_dir, "Created/verified novel directory"),
-                Err(e) => {
-                    error!(path = ?novel_dir, error = %e, "Failed to create novel directory");
-                    continue;
-                }
-            }
-
             if need_restart().await {
                 warn!(novel_id = %novel.novel_id, "Download interrupted");
                 break;
             }
 
             while let Some(volume) =
-                novel_download_volume::Entity::find_incomplete_by_novel(&novel.novel_id).await?
+                novel_download_volume::Entity::find_incomplete_by_novel(
+                    &novel.source_id,
+                    &novel.language,
+                    &novel.novel_id,
+                )
+                .await?
             {
                 info!(
                     novel_id = %novel.novel_id,
@@ -156,119 +778,24 @@ async fn downloading_loop() -> Result<()> {
                     break;
                 }
 
-                if let Some(chapter) = novel_download_chapter::Entity::find_incomplete_by_volume(
+                let batch = novel_download_chapter::Entity::find_incomplete_batch_by_volume(
+                    &novel.source_id,
+                    &novel.language,
                     &novel.novel_id,
                     &volume.id,
+                    concurrency,
                 )
-                .await?
-                {
-                    debug!(
-                        novel_id = %novel.novel_id,
-                        volume_id = %volume.id,
-                        chapter_id = %chapter.id,
-                        chapter_title = %chapter.title,
-                        "Processing chapter"
-                    );
-                    tokio::time::sleep(tokio::time::Duration::from_secs(5)).await; // 防止下载太快
-
-                    if need_restart().await {
-                        warn!(chapter_id = %chapter.id, "Download interrupted");
-                        break;
-                    }
-
-                    let chapter_file_path = novel_dir.join(format!("chapter_{}", chapter.id));
-
-                    // Download chapter content
-                    match CLIENT.c_content(&novel.novel_id, &chapter.id).await {
-                        Ok(chapter_content) => {
-                            match tokio::fs::write(&chapter_file_path, chapter_content).await {
-                                Ok(_) => {
-                                    debug!(
-                                        novel_id = %novel.novel_id,
-                                        chapter_id = %chapter.id,
-                                        "Successfully downloaded chapter"
-                                    );
-                                    if let Err(e) = novel_download_chapter::Entity::update_status(
-                                        &novel.novel_id,
-                                        &volume.id,
-                                        &chapter.id,
-                                        1, // Success
-                                    )
-                                    .await
-                                    {
-                                        error!(
-                                            novel_id = %novel.novel_id,
-                                            chapter_id = %chapter.id,
-                                            error = %e,
-                                            "Failed to update chapter status"
-                                        );
-                                    } else {
-                                        // 更新小说下载章节数
-                                        if let Err(e) =
-                                            novel_download::Entity::add_one_download_chapter_count(
-                                                &novel.novel_id,
-                                            )
-                                            .await
-                                        {
-                                            error!(
-                                                novel_id = %novel.novel_id,
-                                                chapter_id = %chapter.id,
-                                                error = %e,
-                                                "Failed to update novel download chapter count"
-                                            );
-                                        }
-                                    }
-                                }
-                                Err(e) => {
-                                    error!(
-                                        novel_id = %novel.novel_id,
-                                        chapter_id = %chapter.id,
-                                        error = %e,
-                                        "Failed to write chapter file"
-                                    );
-                                    if let Err(e) = novel_download_chapter::Entity::update_status(
-                                        &novel.novel_id,
-                                        &volume.id,
-                                        &chapter.id,
-                                        2, // Failed
-                                    )
-                                    .await
-                                    {
-                                        error!(
-                                            novel_id = %novel.novel_id,
-                                            chapter_id = %chapter.id,
-                                            error = %e,
-                                            "Failed to update chapter status"
-                                        );
-                                    }
-                                }
-                            }
-                        }
-                        Err(e) => {
-                            error!(
-                                novel_id = %novel.novel_id,
-                                chapter_id = %chapter.id,
-                                error = %e,
-                                "Failed to download chapter content"
-                            );
-                            if let Err(e) = novel_download_chapter::Entity::update_status(
-                                &novel.novel_id,
-                                &volume.id,
-                                &chapter.id,
-                                2, // Failed
-                            )
-                            .await
-                            {
-                                error!(
-                                    novel_id = %novel.novel_id,
-                                    chapter_id = %chapter.id,
-                                    error = %e,
-                                    "Failed to update chapter status"
-                                );
-                            }
-                        }
-                    }
+                .await?;
+                let mut workers = JoinSet::new();
+                for chapter in batch {
+                    workers.spawn(download_chapter(
+                        source,
+                        chapter,
+                        novel_dir.clone(),
+                        Language::from_code(&novel.language),
+                    ));
                 }
+                join_workers(workers).await;
 
                 if need_restart().await {
                     warn!(volume_id = %volume.id, "Download interrupted after chapter processing");
@@ -276,6 +803,8 @@ async fn downloading_loop() -> Result<()> {
                 }
 
                 let chapters = novel_download_chapter::Entity::find_incomplete_by_volume(
+                    &novel.source_id,
+                    &novel.language,
                     &novel.novel_id,
                     &volume.id,
                 )
@@ -289,6 +818,8 @@ async fn downloading_loop() -> Result<()> {
                         "Volume has failed chapters"
                     );
                     if let Err(e) = novel_download_volume::Entity::update_status(
+                        &novel.source_id,
+                        &novel.language,
                         &novel.novel_id,
                         &volume.id,
                         2, // Failed
@@ -311,6 +842,8 @@ async fn downloading_loop() -> Result<()> {
                             "Volume completed successfully"
                         );
                         if let Err(e) = novel_download_volume::Entity::update_status(
+                            &novel.source_id,
+                            &novel.language,
                             &novel.novel_id,
                             &volume.id,
                             1, // Success
@@ -333,61 +866,30 @@ async fn downloading_loop() -> Result<()> {
                 break;
             }
 
-            while let Some(picture) =
-                novel_download_picture::Entity::find_incomplete_by_novel(&novel.novel_id).await?
-            {
-                info!(
-                    novel_id = %novel.novel_id,
-                    picture_id = %picture.url,
-                    "Processing picture"
-                );
-
+            loop {
                 if need_restart().await {
-                    warn!(picture_id = %picture.url, "Download interrupted after picture processing");
+                    warn!(novel_id = %novel.novel_id, "Download interrupted during picture processing");
                     break;
                 }
-
-                match CLIENT.download_image(&picture.url).await {
-                    Ok(content) => {
-                        let picture_file_path =
-                            novel_dir.join(format!("picture_{}", picture.url_md5));
-                        match tokio::fs::write(&picture_file_path, content).await {
-                            Ok(_) => {
-                                debug!(novel_id = %novel.novel_id, "Successfully downloaded picture");
-                                novel_download_picture::Entity::update_download_status(
-                                    &picture.aid,
-                                    &picture.volume_id,
-                                    &picture.chapter_id,
-                                    picture.picture_idx,
-                                    1,
-                                )
-                                .await?;
-                            }
-                            Err(e) => {
-                                error!(picture_id = %picture.url, error = %e, "Failed to write picture file");
-                                novel_download_picture::Entity::update_download_status(
-                                    &picture.aid,
-                                    &picture.volume_id,
-                                    &picture.chapter_id,
-                                    picture.picture_idx,
-                                    2,
-                                )
-                                .await?;
-                            }
-                        }
-                    }
-                    Err(e) => {
-                        error!(picture_id = %picture.url, error = %e, "Failed to download picture");
-                        novel_download_picture::Entity::update_download_status(
-                            &picture.aid,
-                            &picture.volume_id,
-                            &picture.chapter_id,
-                            picture.picture_idx,
-                            2,
-                        )
-                        .await?;
-                    }
+                let batch = novel_download_picture::Entity::find_incomplete_batch_by_novel(
+                    &novel.source_id,
+                    &novel.language,
+                    &novel.novel_id,
+                    concurrency,
+                )
+                .await?;
+                if batch.is_empty() {
+                    break;
+                }
+                let mut workers = JoinSet::new();
+                for picture in batch {
+                    workers.spawn(download_picture(
+                        source,
+                        picture,
+                        novel_dir.clone(),
+                    ));
                 }
+                join_workers(workers).await;
             }
 
             if need_restart().await {
@@ -397,26 +899,41 @@ async fn downloading_loop() -> Result<()> {
 
             // 总结
             // Check novel status
-            let volumes = novel_download_volume::Entity::find_by_novel_id(&novel.novel_id).await?;
+            let volumes =
+                novel_download_volume::Entity::find_by_novel_id(&novel.source_id, &novel.language, &novel.novel_id)
+                    .await?;
             let has_failed = volumes.iter().any(|volume| volume.download_status == 2);
             if has_failed {
                 info!(
                     novel_id = %novel.novel_id,
                     "Novel has failed volumes"
                 );
-                let _ = novel_download::Entity::update_status(&novel.novel_id, 2).await;
+                let _ = novel_download::Entity::update_status(&novel.source_id, &novel.language, &novel.novel_id, 2).await;
+                publish(DownloadEvent::novel(
+                    DownloadEventKind::NovelFailed,
+                    &novel.source_id,
+                    &novel.language,
+                    &novel.novel_id,
+                ));
             }
             let all_success = volumes.iter().all(|volume| volume.download_status == 1);
             if !all_success {
                 continue;
             }
             let all_pictures =
-                novel_download_picture::Entity::find_by_novel_id(&novel.novel_id).await?;
+                novel_download_picture::Entity::find_by_novel_id(&novel.source_id, &novel.language, &novel.novel_id)
+                    .await?;
             let has_failed = all_pictures
                 .iter()
                 .any(|picture| picture.download_status == 2);
             if has_failed {
-                let _ = novel_download::Entity::update_status(&novel.novel_id, 2).await;
+                let _ = novel_download::Entity::update_status(&novel.source_id, &novel.language, &novel.novel_id, 2).await;
+                publish(DownloadEvent::novel(
+                    DownloadEventKind::NovelFailed,
+                    &novel.source_id,
+                    &novel.language,
+                    &novel.novel_id,
+                ));
                 continue;
             }
             let all_success = all_pictures
@@ -425,10 +942,18 @@ async fn downloading_loop() -> Result<()> {
             if !all_success {
                 continue;
             }
-            let all_chapters = novel_download_chapter::Entity::find_by_novel_id(&novel.novel_id).await?;
+            let all_chapters =
+                novel_download_chapter::Entity::find_by_novel_id(&novel.source_id, &novel.language, &novel.novel_id)
+                    .await?;
             let has_failed = all_chapters.iter().any(|chapter| chapter.download_status == 2);
             if has_failed {
-                let _ = novel_download::Entity::update_status(&novel.novel_id, 2).await;
+                let _ = novel_download::Entity::update_status(&novel.source_id, &novel.language, &novel.novel_id, 2).await;
+                publish(DownloadEvent::novel(
+                    DownloadEventKind::NovelFailed,
+                    &novel.source_id,
+                    &novel.language,
+                    &novel.novel_id,
+                ));
                 continue;
             }
             let all_success = all_chapters.iter().all(|chapter| chapter.download_status == 1);
@@ -436,8 +961,17 @@ async fn downloading_loop() -> Result<()> {
                 continue;
             }
             let success_chapter_count = all_chapters.iter().filter(|chapter| chapter.download_status == 1).count();
-            let _ = novel_download::Entity::update_download_chapter_count(&novel.novel_id, success_chapter_count.try_into().unwrap()).await;
-            let _ = novel_download::Entity::update_status(&novel.novel_id, 1).await;
+            let _ = novel_download::Entity::update_download_chapter_count(&novel.source_id, &novel.language, &novel.novel_id, success_chapter_count.try_into().unwrap()).await;
+            let _ = novel_download::Entity::update_status(&novel.source_id, &novel.language, &novel.novel_id, 1).await;
+            publish(DownloadEvent {
+                download_count: success_chapter_count as i32,
+                ..DownloadEvent::novel(
+                    DownloadEventKind::NovelCompleted,
+                    &novel.source_id,
+                    &novel.language,
+                    &novel.novel_id,
+                )
+            });
         }
 
         tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
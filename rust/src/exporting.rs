@@ -0,0 +1,577 @@
+use crate::chapter_document::{parse_segments, ChapterSegmentKind};
+use crate::database::entities::active::{
+    novel_download, novel_download_chapter, novel_download_picture, novel_download_volume,
+    DOWNLOAD_STATUS_SUCCESS,
+};
+use crate::downloading::novel_dir;
+use crate::wenku8::Language;
+use crate::zh_convert::{convert_from, load_display_language};
+use crate::Result;
+use anyhow::{anyhow, Context};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::collections::HashMap;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use tracing::{info, instrument, warn};
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipWriter};
+
+/// 一本待写出的 EPUB
+pub(crate) struct EpubBook {
+    pub(crate) identifier: String,
+    pub(crate) title: String,
+    pub(crate) author: String,
+    pub(crate) description: String,
+    pub(crate) cover: Option<EpubImage>,
+    pub(crate) sections: Vec<EpubSection>,
+    pub(crate) images: Vec<EpubImage>,
+}
+
+/// 目录中的一节，对应一卷
+pub(crate) struct EpubSection {
+    pub(crate) idx: usize,
+    pub(crate) title: String,
+    pub(crate) chapters: Vec<EpubChapter>,
+    /// 本卷用到的图片 url_md5
+    pub(crate) images: Vec<String>,
+}
+
+pub(crate) struct EpubChapter {
+    pub(crate) id: String,
+    pub(crate) title: String,
+    pub(crate) body: String,
+}
+
+#[derive(Clone)]
+pub(crate) struct EpubImage {
+    pub(crate) file_name: String,
+    pub(crate) media_type: &'static str,
+    pub(crate) data: Vec<u8>,
+}
+
+/// 导出已下载的小说为 EPUB3
+///
+/// - volume_ids 为空时导出全部卷
+/// - split_volumes 为 true 时每卷一个文件，out_path 视为目录；否则 out_path 为文件路径
+///
+/// 返回写出的文件路径
+#[instrument(skip(volume_ids))]
+pub(crate) async fn export_epub(
+    source_id: &str,
+    language: &str,
+    novel_id: &str,
+    volume_ids: Vec<String>,
+    out_path: &str,
+    split_volumes: bool,
+) -> Result<Vec<String>> {
+    let novel = novel_download::Entity::find_by_novel_id(source_id, language, novel_id)
+        .await?
+        .ok_or_else(|| anyhow!("Novel not downloaded: {}", novel_id))?;
+    let novel_dir = novel_dir(source_id, language, novel_id)?;
+    // 按阅读设置转换简繁，下载时的语言记录在下载记录中
+    let content_language = Language::from_code(&novel.language);
+    let display_language = load_display_language().await;
+    let convert = |text: String| convert_from(text, content_language, display_language);
+
+    let volumes = novel_download_volume::Entity::find_by_novel_id(source_id, language, novel_id)
+        .await?
+        .into_iter()
+        .filter(|v| volume_ids.is_empty() || volume_ids.contains(&v.id))
+        .collect::<Vec<_>>();
+    if volumes.is_empty() {
+        return Err(anyhow!("No volume to export"));
+    }
+
+    // 只有下载成功的图片才能嵌入
+    let mut pictures = HashMap::new();
+    let mut picture_files = HashMap::new();
+    for picture in novel_download_picture::Entity::find_by_novel_id(source_id, language, novel_id).await? {
+        if picture.download_status != DOWNLOAD_STATUS_SUCCESS
+            || picture_files.contains_key(&picture.url_md5)
+        {
+            continue;
+        }
+        let data =
+            match tokio::fs::read(novel_dir.join(format!("picture_{}", picture.url_md5))).await {
+                Ok(data) => data,
+                Err(e) => {
+                    warn!(url = %picture.url, error = %e, "Failed to read picture");
+                    continue;
+                }
+            };
+        let image = EpubImage {
+            file_name: format!("{}.{}", picture.url_md5, image_extension(&data)),
+            media_type: image_media_type(&data),
+            data,
+        };
+        pictures.insert(picture.url, picture.url_md5.clone());
+        picture_files.insert(picture.url_md5, image);
+    }
+
+    let cover = if novel.cover_download_status == DOWNLOAD_STATUS_SUCCESS {
+        match tokio::fs::read(novel_dir.join("cover")).await {
+            Ok(data) => Some(EpubImage {
+                file_name: format!("cover.{}", image_extension(&data)),
+                media_type: image_media_type(&data),
+                data,
+            }),
+            Err(e) => {
+                warn!(novel_id = %novel_id, error = %e, "Failed to read cover");
+                None
+            }
+        }
+    } else {
+        None
+    };
+
+    let mut sections = Vec::with_capacity(volumes.len());
+    for (idx, volume) in volumes.iter().enumerate() {
+        let chapters =
+            novel_download_chapter::Entity::find_by_volume_id(source_id, language, &volume.id).await?;
+        let mut section = EpubSection {
+            idx,
+            title: convert(volume.title.clone()),
+            chapters: vec![],
+            images: vec![],
+        };
+        for chapter in chapters {
+            if chapter.download_status != DOWNLOAD_STATUS_SUCCESS {
+                continue;
+            }
+            let content =
+                tokio::fs::read_to_string(novel_dir.join(format!("chapter_{}", chapter.id)))
+                    .await
+                    .with_context(|| format!("Failed to read chapter {}", chapter.id))?;
+            let content = convert(content);
+            let body = chapter_body(&content, |url| {
+                let url_md5 = pictures.get(url)?;
+                if !section.images.contains(url_md5) {
+                    section.images.push(url_md5.clone());
+                }
+                Some(picture_files.get(url_md5)?.file_name.clone())
+            });
+            section.chapters.push(EpubChapter {
+                id: chapter.id,
+                title: convert(chapter.title),
+                body,
+            });
+        }
+        // 没有下载成功章节的卷不写出，EPUB3 目录中不允许空的 <ol>
+        if section.chapters.is_empty() {
+            continue;
+        }
+        sections.push(section);
+    }
+    if sections.is_empty() {
+        return Err(anyhow!("No downloaded chapter to export"));
+    }
+
+    let section_images = |sections: &[EpubSection]| {
+        let mut images = Vec::<EpubImage>::new();
+        for url_md5 in sections.iter().flat_map(|s| s.images.iter()) {
+            if let Some(image) = picture_files.get(url_md5) {
+                if !images.iter().any(|i| i.file_name == image.file_name) {
+                    images.push(image.clone());
+                }
+            }
+        }
+        images
+    };
+
+    let description = convert(strip_tags(&novel.introduce));
+    let novel_name = convert(novel.novel_name.clone());
+    let author = convert(novel.author.clone());
+    let mut books = vec![];
+    if split_volumes {
+        let out_dir = PathBuf::from(out_path);
+        tokio::fs::create_dir_all(&out_dir).await?;
+        for section in sections {
+            let title = format!("{} {}", novel_name, section.title);
+            let path = out_dir.join(format!("{}.epub", sanitize_file_name(&title)));
+            let sections = vec![section];
+            books.push((
+                path,
+                EpubBook {
+                    identifier: format!(
+                        "{}-{}-{}",
+                        novel.source_id, novel.novel_id, volumes[sections[0].idx].id
+                    ),
+                    title,
+                    author: author.clone(),
+                    description: description.clone(),
+                    cover: cover.clone(),
+                    images: section_images(&sections),
+                    sections,
+                },
+            ));
+        }
+    } else {
+        if let Some(parent) = Path::new(out_path).parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+        books.push((
+            PathBuf::from(out_path),
+            EpubBook {
+                identifier: format!("{}-{}", novel.source_id, novel.novel_id),
+                title: novel_name,
+                author,
+                description,
+                cover,
+                images: section_images(&sections),
+                sections,
+            },
+        ));
+    }
+
+    let mut written = vec![];
+    for (path, book) in books {
+        let path_str = path
+            .to_str()
+            .ok_or_else(|| anyhow!("Invalid export path: {}", path.display()))?
+            .to_string();
+        tokio::task::spawn_blocking(move || write_epub(&book, &path)).await??;
+        info!(path = %path_str, "Exported epub");
+        written.push(path_str);
+    }
+    Ok(written)
+}
+
+/// 章节正文转 XHTML 片段，图片由 resolve 映射为本地文件名，映射不到的图片会被忽略
+fn chapter_body(content: &str, mut resolve: impl FnMut(&str) -> Option<String>) -> String {
+    let mut body = String::new();
+    for segment in parse_segments(content) {
+        match segment.kind {
+            ChapterSegmentKind::Paragraph => {
+                body.push_str(&format!("<p>{}</p>\n", xml_escape(&segment.text)));
+            }
+            ChapterSegmentKind::Image => {
+                if let Some(file_name) = resolve(&segment.url) {
+                    body.push_str(&format!(
+                        "<div class=\"illus\"><img src=\"../images/{}\" alt=\"\"/></div>\n",
+                        file_name,
+                    ));
+                }
+            }
+            ChapterSegmentKind::Separator => body.push_str("<hr/>\n"),
+        }
+    }
+    body
+}
+
+pub(crate) fn write_epub(book: &EpubBook, path: &Path) -> Result<()> {
+    let file = std::fs::File::create(path)?;
+    let mut zip = ZipWriter::new(file);
+    let stored = SimpleFileOptions::default().compression_method(CompressionMethod::Stored);
+    let deflated = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+
+    // mimetype 必须是第一个文件且不压缩
+    zip.start_file("mimetype", stored)?;
+    zip.write_all(b"application/epub+zip")?;
+
+    zip.start_file("META-INF/container.xml", deflated)?;
+    zip.write_all(CONTAINER_XML.as_bytes())?;
+
+    zip.start_file("OEBPS/style.css", deflated)?;
+    zip.write_all(STYLE_CSS.as_bytes())?;
+
+    if let Some(cover) = &book.cover {
+        zip.start_file(format!("OEBPS/images/{}", cover.file_name), stored)?;
+        zip.write_all(&cover.data)?;
+        zip.start_file("OEBPS/text/cover.xhtml", deflated)?;
+        zip.write_all(
+            xhtml_page(
+                &book.title,
+                &format!(
+                    "<div class=\"cover\"><img src=\"../images/{}\" alt=\"{}\"/></div>",
+                    cover.file_name,
+                    xml_escape(&book.title),
+                ),
+            )
+            .as_bytes(),
+        )?;
+    }
+
+    for image in &book.images {
+        zip.start_file(format!("OEBPS/images/{}", image.file_name), stored)?;
+        zip.write_all(&image.data)?;
+    }
+
+    for section in &book.sections {
+        zip.start_file(format!("OEBPS/text/volume_{}.xhtml", section.idx), deflated)?;
+        zip.write_all(
+            xhtml_page(
+                &section.title,
+                &format!("<h1 class=\"volume\">{}</h1>", xml_escape(&section.title)),
+            )
+            .as_bytes(),
+        )?;
+        for chapter in &section.chapters {
+            zip.start_file(format!("OEBPS/text/chapter_{}.xhtml", chapter.id), deflated)?;
+            zip.write_all(
+                xhtml_page(
+                    &chapter.title,
+                    &format!("<h2>{}</h2>\n{}", xml_escape(&chapter.title), chapter.body),
+                )
+                .as_bytes(),
+            )?;
+        }
+    }
+
+    zip.start_file("OEBPS/nav.xhtml", deflated)?;
+    zip.write_all(nav_xhtml(book).as_bytes())?;
+
+    zip.start_file("OEBPS/toc.ncx", deflated)?;
+    zip.write_all(toc_ncx(book).as_bytes())?;
+
+    zip.start_file("OEBPS/content.opf", deflated)?;
+    zip.write_all(content_opf(book).as_bytes())?;
+
+    zip.finish()?;
+    Ok(())
+}
+
+const CONTAINER_XML: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<container version="1.0" xmlns="urn:oasis:names:tc:opendocument:xmlns:container">
+  <rootfiles>
+    <rootfile full-path="OEBPS/content.opf" media-type="application/oebps-package+xml"/>
+  </rootfiles>
+</container>
+"#;
+
+const STYLE_CSS: &str = r#"body { margin: 0 5%; line-height: 1.6; }
+p { text-indent: 2em; margin: 0.3em 0; }
+h1.volume { text-align: center; margin-top: 30%; }
+h2 { text-align: center; margin: 1em 0; }
+div.illus, div.cover { text-align: center; margin: 0; padding: 0; }
+div.illus img, div.cover img { max-width: 100%; max-height: 100%; }
+"#;
+
+fn xhtml_page(title: &str, body: &str) -> String {
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE html>
+<html xmlns="http://www.w3.org/1999/xhtml" xmlns:epub="http://www.idpf.org/2007/ops" xml:lang="zh" lang="zh">
+<head>
+<title>{}</title>
+<link rel="stylesheet" type="text/css" href="../style.css"/>
+</head>
+<body>
+{}
+</body>
+</html>
+"#,
+        xml_escape(title),
+        body,
+    )
+}
+
+fn nav_xhtml(book: &EpubBook) -> String {
+    let mut ol = String::new();
+    for section in &book.sections {
+        ol.push_str(&format!(
+            "<li><a href=\"text/volume_{}.xhtml\">{}</a>\n<ol>\n",
+            section.idx,
+            xml_escape(&section.title),
+        ));
+        for chapter in &section.chapters {
+            ol.push_str(&format!(
+                "<li><a href=\"text/chapter_{}.xhtml\">{}</a></li>\n",
+                chapter.id,
+                xml_escape(&chapter.title),
+            ));
+        }
+        ol.push_str("</ol>\n</li>\n");
+    }
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE html>
+<html xmlns="http://www.w3.org/1999/xhtml" xmlns:epub="http://www.idpf.org/2007/ops" xml:lang="zh" lang="zh">
+<head>
+<title>{}</title>
+</head>
+<body>
+<nav epub:type="toc" id="toc">
+<h1>目录</h1>
+<ol>
+{}</ol>
+</nav>
+</body>
+</html>
+"#,
+        xml_escape(&book.title),
+        ol,
+    )
+}
+
+/// EPUB2 的目录，部分墨水屏设备只认这个
+fn toc_ncx(book: &EpubBook) -> String {
+    let mut nav_points = String::new();
+    let mut play_order = 0;
+    for section in &book.sections {
+        play_order += 1;
+        nav_points.push_str(&format!(
+            "<navPoint id=\"nav_volume_{0}\" playOrder=\"{1}\"><navLabel><text>{2}</text></navLabel><content src=\"text/volume_{0}.xhtml\"/>\n",
+            section.idx,
+            play_order,
+            xml_escape(&section.title),
+        ));
+        for chapter in &section.chapters {
+            play_order += 1;
+            nav_points.push_str(&format!(
+                "<navPoint id=\"nav_chapter_{0}\" playOrder=\"{1}\"><navLabel><text>{2}</text></navLabel><content src=\"text/chapter_{0}.xhtml\"/></navPoint>\n",
+                chapter.id,
+                play_order,
+                xml_escape(&chapter.title),
+            ));
+        }
+        nav_points.push_str("</navPoint>\n");
+    }
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<ncx xmlns="http://www.daisy.org/z3986/2005/ncx/" version="2005-1">
+<head>
+<meta name="dtb:uid" content="{}"/>
+<meta name="dtb:depth" content="2"/>
+</head>
+<docTitle><text>{}</text></docTitle>
+<navMap>
+{}</navMap>
+</ncx>
+"#,
+        xml_escape(&book.identifier),
+        xml_escape(&book.title),
+        nav_points,
+    )
+}
+
+fn content_opf(book: &EpubBook) -> String {
+    let mut manifest = String::new();
+    let mut spine = String::new();
+    manifest.push_str(
+        "<item id=\"nav\" href=\"nav.xhtml\" media-type=\"application/xhtml+xml\" properties=\"nav\"/>\n",
+    );
+    manifest
+        .push_str("<item id=\"ncx\" href=\"toc.ncx\" media-type=\"application/x-dtbncx+xml\"/>\n");
+    manifest.push_str("<item id=\"style\" href=\"style.css\" media-type=\"text/css\"/>\n");
+    let mut cover_meta = String::new();
+    if let Some(cover) = &book.cover {
+        manifest.push_str(&format!(
+            "<item id=\"cover-image\" href=\"images/{}\" media-type=\"{}\" properties=\"cover-image\"/>\n",
+            cover.file_name, cover.media_type,
+        ));
+        manifest.push_str(
+            "<item id=\"cover\" href=\"text/cover.xhtml\" media-type=\"application/xhtml+xml\"/>\n",
+        );
+        spine.push_str("<itemref idref=\"cover\"/>\n");
+        cover_meta.push_str("<meta name=\"cover\" content=\"cover-image\"/>\n");
+    }
+    for image in &book.images {
+        manifest.push_str(&format!(
+            "<item id=\"img_{}\" href=\"images/{}\" media-type=\"{}\"/>\n",
+            image.file_name.replace('.', "_"),
+            image.file_name,
+            image.media_type,
+        ));
+    }
+    for section in &book.sections {
+        manifest.push_str(&format!(
+            "<item id=\"volume_{0}\" href=\"text/volume_{0}.xhtml\" media-type=\"application/xhtml+xml\"/>\n",
+            section.idx,
+        ));
+        spine.push_str(&format!("<itemref idref=\"volume_{}\"/>\n", section.idx));
+        for chapter in &section.chapters {
+            manifest.push_str(&format!(
+                "<item id=\"chapter_{0}\" href=\"text/chapter_{0}.xhtml\" media-type=\"application/xhtml+xml\"/>\n",
+                chapter.id,
+            ));
+            spine.push_str(&format!("<itemref idref=\"chapter_{}\"/>\n", chapter.id));
+        }
+    }
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<package xmlns="http://www.idpf.org/2007/opf" version="3.0" unique-identifier="book-id" xml:lang="zh">
+<metadata xmlns:dc="http://purl.org/dc/elements/1.1/">
+<dc:identifier id="book-id">{}</dc:identifier>
+<dc:title>{}</dc:title>
+<dc:creator>{}</dc:creator>
+<dc:language>zh</dc:language>
+<dc:description>{}</dc:description>
+<meta property="dcterms:modified">{}</meta>
+{}</metadata>
+<manifest>
+{}</manifest>
+<spine toc="ncx">
+{}</spine>
+</package>
+"#,
+        xml_escape(&book.identifier),
+        xml_escape(&book.title),
+        xml_escape(&book.author),
+        xml_escape(&book.description),
+        chrono::Utc::now().format("%Y-%m-%dT%H:%M:%SZ"),
+        cover_meta,
+        manifest,
+        spine,
+    )
+}
+
+fn xml_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            // XML 1.0 不允许的控制字符
+            c if (c as u32) < 0x20 && c != '\t' && c != '\n' && c != '\r' => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+static BR_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"<br\s*/?>").unwrap());
+static TAG_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"<[^>]+>").unwrap());
+
+fn strip_tags(html: &str) -> String {
+    let text = BR_REGEX.replace_all(html, "\n");
+    let text = TAG_REGEX.replace_all(&text, "");
+    text.replace("&nbsp;", " ")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&")
+        .trim()
+        .to_string()
+}
+
+fn sanitize_file_name(name: &str) -> String {
+    name.chars()
+        .map(|c| match c {
+            '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|' => '_',
+            c => c,
+        })
+        .collect::<String>()
+        .trim()
+        .to_string()
+}
+
+fn image_media_type(data: &[u8]) -> &'static str {
+    match image::guess_format(data) {
+        Ok(image::ImageFormat::Png) => "image/png",
+        Ok(image::ImageFormat::Gif) => "image/gif",
+        Ok(image::ImageFormat::WebP) => "image/webp",
+        _ => "image/jpeg",
+    }
+}
+
+fn image_extension(data: &[u8]) -> &'static str {
+    match image_media_type(data) {
+        "image/png" => "png",
+        "image/gif" => "gif",
+        "image/webp" => "webp",
+        _ => "jpg",
+    }
+}
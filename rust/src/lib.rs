@@ -1,5 +1,7 @@
 use crate::api::database::save_property;
-use crate::wenku8::Wenku8Client;
+use crate::proxy::ProxySettings;
+use crate::wenku8::rate_limit::{RateLimiter, MIN_RATE};
+use crate::wenku8::{Language, Wenku8Client};
 use crate::{
     api::database::load_property, database::entities::cookie::cookie_store::DatabaseCookieStore,
 };
@@ -11,37 +13,69 @@ use reqwest::Client;
 use std::ops::Deref;
 use std::path::Path;
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::{Mutex, RwLock};
 
+mod account;
 mod api;
 mod cache_manager;
+mod chapter_document;
+mod cookie_file;
 mod database;
 mod downloading;
+mod exporting;
 mod frb_generated;
 mod local;
+mod proxy;
+mod source;
 #[cfg(test)]
 mod test;
+mod update_checker;
+mod vault;
 mod wenku8;
+mod zh_convert;
 
 pub(crate) type Result<T> = anyhow::Result<T>;
 
 pub(crate) static COOKIE_STORE: Lazy<Arc<DatabaseCookieStore>> =
-    Lazy::new(|| Arc::new(DatabaseCookieStore {}));
+    Lazy::new(|| Arc::new(DatabaseCookieStore::default()));
 
 pub(crate) static CLIENT: Lazy<Wenku8Client> = Lazy::new(|| {
-    let cookie_store = Arc::clone(COOKIE_STORE.deref());
-    let client = Client::builder()
-        .cookie_provider(cookie_store)
-        .gzip(true)
-        .build()
-        .unwrap();
+    let client = build_http_client(&ProxySettings::default()).unwrap();
     Wenku8Client {
-        client,
+        client: std::sync::RwLock::new(client),
         user_agent: RwLock::new("".to_string()),
         api_host: RwLock::new("".to_string()),
+        mirror_hosts: RwLock::new(vec![]),
+        language: RwLock::new(Default::default()),
+        host_changes: tokio::sync::broadcast::channel(16).0,
+        text_limiter: RateLimiter::new(DEFAULT_TEXT_RATE_LIMIT),
+        image_limiter: RateLimiter::new(DEFAULT_IMAGE_RATE_LIMIT),
     }
 });
 
+// 连接超时和两次读取之间的超时，超时的请求会重试
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+const READ_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// 创建 HTTP 客户端，修改代理时重新创建，cookie 始终使用 COOKIE_STORE
+pub(crate) fn build_http_client(proxy: &ProxySettings) -> Result<Client> {
+    let cookie_store = Arc::clone(COOKIE_STORE.deref());
+    let mut builder = Client::builder()
+        .cookie_provider(cookie_store)
+        .gzip(true)
+        .connect_timeout(CONNECT_TIMEOUT)
+        .read_timeout(READ_TIMEOUT);
+    if let Some(proxy) = proxy.to_proxy()? {
+        builder = builder.proxy(proxy);
+    }
+    Ok(builder.build()?)
+}
+
+// 默认每秒请求数，文字和图片分开限速
+const DEFAULT_TEXT_RATE_LIMIT: f64 = 2.0;
+const DEFAULT_IMAGE_RATE_LIMIT: f64 = 8.0;
+
 static INIT_LOCK: OnceCell<Mutex<()>> = OnceCell::new();
 static INIT_DONE: OnceCell<()> = OnceCell::new();
 static IMAGE_CACHE_DIR: OnceCell<String> = OnceCell::new();
@@ -80,6 +114,10 @@ pub async fn init(root: String) -> Result<()> {
 
     // 执行实际的初始化
     database::init_database(root.as_str()).await?;
+    // 载入 cookie 到内存，之后的修改在后台写回
+    let cookie_db = database::COOKIE_DB_CONNECT.get().unwrap().lock().await.clone();
+    COOKIE_STORE.load(cookie_db).await?;
+    COOKIE_STORE.spawn_flush();
 
     // 创建图片缓存目录
     let image_cache_dir = Path::new(&root).join("image_cache");
@@ -101,8 +139,14 @@ pub async fn init(root: String) -> Result<()> {
 
     init_user_agent().await?;
     init_api_host().await?;
+    init_language().await?;
+    account::init_account().await?;
+    zh_convert::init_display_language().await?;
+    proxy::init_proxy().await?;
+    init_rate_limits().await?;
 
     downloading::start_downloading().await?;
+    update_checker::start_update_checker().await?;
 
     // 标记初始化完成
     let _ = INIT_DONE.set(());
@@ -115,6 +159,19 @@ async fn init_api_host() -> Result<()> {
     if !property_api_host.is_empty() {
         CLIENT.set_api_host(property_api_host).await;
     }
+    let property_mirror_hosts = load_property("mirror_hosts".to_string()).await?;
+    if let Ok(hosts) = serde_json::from_str::<Vec<String>>(&property_mirror_hosts) {
+        CLIENT.set_mirror_hosts(hosts).await;
+    }
+    // 自动切换镜像后记住新的镜像
+    let mut receiver = CLIENT.subscribe_host_changes();
+    tokio::spawn(async move {
+        while let Ok(host) = receiver.recv().await {
+            if let Err(e) = save_property("api_host".to_string(), host).await {
+                tracing::error!(error = %e, "Failed to save api host");
+            }
+        }
+    });
     Ok(())
 }
 
@@ -124,11 +181,94 @@ pub async fn set_api_host(api_host: String) -> Result<()> {
     Ok(())
 }
 
+/// 保存镜像列表，空列表恢复为默认镜像
+pub async fn set_mirror_hosts(hosts: Vec<String>) -> Result<()> {
+    let mut mirror_hosts = Vec::<String>::new();
+    for host in hosts {
+        let host = wenku8::mirror::normalize_host(&host);
+        if !host.is_empty() && !mirror_hosts.contains(&host) {
+            mirror_hosts.push(host);
+        }
+    }
+    save_property(
+        "mirror_hosts".to_string(),
+        serde_json::to_string(&mirror_hosts)?,
+    )
+    .await?;
+    CLIENT.set_mirror_hosts(mirror_hosts).await;
+    Ok(())
+}
+
+async fn init_language() -> Result<()> {
+    let language = load_property("language".to_string()).await?;
+    CLIENT.set_language(Language::from_code(&language)).await;
+    Ok(())
+}
+
+/// 切换简体/繁体，缓存按语言区分，不需要清理
+pub async fn set_language(language: Language) -> Result<()> {
+    save_property("language".to_string(), language.code().to_string()).await?;
+    CLIENT.set_language(language).await;
+    Ok(())
+}
+
+async fn init_rate_limits() -> Result<()> {
+    let text_rate_limit = load_property("text_rate_limit".to_string()).await?;
+    if let Ok(rate) = text_rate_limit.parse::<f64>() {
+        match check_rate_limit(rate) {
+            Ok(_) => CLIENT.text_limiter.set_rate(rate).await,
+            Err(e) => tracing::warn!(error = %e, "Ignore saved text rate limit"),
+        }
+    }
+    let image_rate_limit = load_property("image_rate_limit".to_string()).await?;
+    if let Ok(rate) = image_rate_limit.parse::<f64>() {
+        match check_rate_limit(rate) {
+            Ok(_) => CLIENT.image_limiter.set_rate(rate).await,
+            Err(e) => tracing::warn!(error = %e, "Ignore saved image rate limit"),
+        }
+    }
+    Ok(())
+}
+
+// 小于等于 0 表示不限速，大于 0 时不能低于 MIN_RATE
+fn check_rate_limit(rate: f64) -> Result<()> {
+    if !rate.is_finite() || (rate > 0.0 && rate < MIN_RATE) {
+        return Err(anyhow::anyhow!(
+            "Invalid rate limit {}, must be at least {} or <= 0",
+            rate,
+            MIN_RATE
+        ));
+    }
+    Ok(())
+}
+
+/// 设置文字请求的限速（每秒请求数），小于等于 0 不限速
+pub async fn set_text_rate_limit(rate: f64) -> Result<()> {
+    check_rate_limit(rate)?;
+    save_property("text_rate_limit".to_string(), rate.to_string()).await?;
+    CLIENT.text_limiter.set_rate(rate).await;
+    Ok(())
+}
+
+/// 设置图片请求的限速（每秒请求数），小于等于 0 不限速
+pub async fn set_image_rate_limit(rate: f64) -> Result<()> {
+    check_rate_limit(rate)?;
+    save_property("image_rate_limit".to_string(), rate.to_string()).await?;
+    CLIENT.image_limiter.set_rate(rate).await;
+    Ok(())
+}
+
 pub fn get_image_cache_dir() -> &'static str {
     IMAGE_CACHE_DIR.get().unwrap()
 }
 
 async fn init_user_agent() -> Result<()> {
+    // 通过 Cloudflare 验证时使用的 UA 不能更换，否则 cf_clearance 会失效
+    let pinned_user_agent = load_property("pinned_user_agent".to_string()).await?;
+    if !pinned_user_agent.is_empty() {
+        CLIENT.set_user_agent(pinned_user_agent).await;
+        return Ok(());
+    }
     let mut property_user_agent = load_property("user_agent".to_string()).await?;
     if property_user_agent.is_empty() {
         property_user_agent = random_user_agent();
@@ -142,6 +282,34 @@ async fn init_user_agent() -> Result<()> {
     Ok(())
 }
 
+/// 保存 WebView 通过 Cloudflare 验证后拿到的 cf_clearance，并固定使用 WebView 的 UA
+/// url 为空时使用当前的镜像
+pub async fn apply_cf_clearance(
+    url: String,
+    cf_clearance: String,
+    user_agent: String,
+) -> Result<()> {
+    let url = if url.trim().is_empty() {
+        CLIENT.load_api_host().await
+    } else {
+        url
+    };
+    let url = reqwest::Url::parse(url.trim())?;
+    COOKIE_STORE.import_cookie(&url, "cf_clearance", cf_clearance.trim());
+    let user_agent = user_agent.trim().to_string();
+    if !user_agent.is_empty() {
+        save_property("pinned_user_agent".to_string(), user_agent.clone()).await?;
+        CLIENT.set_user_agent(user_agent).await;
+    }
+    Ok(())
+}
+
+/// 取消固定的 UA，恢复为随机生成的 UA
+pub async fn unpin_user_agent() -> Result<()> {
+    save_property("pinned_user_agent".to_string(), "".to_string()).await?;
+    init_user_agent().await
+}
+
 fn random_user_agent() -> String {
     random_android_ua()
 }
@@ -0,0 +1,126 @@
+use crate::{
+    api::database::{load_property, save_property},
+    build_http_client, Result, CLIENT,
+};
+use anyhow::anyhow;
+use reqwest::{NoProxy, Proxy};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use tokio::time::Instant;
+use tracing::info;
+
+const TEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// 代理设置，url 支持 http、https、socks5、socks5h
+/// bypass 为不走代理的域名或 IP，例如 localhost、*.example.com、192.168.0.0/16
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProxySettings {
+    pub enabled: bool,
+    pub url: String,
+    pub username: String,
+    pub password: String,
+    pub bypass: Vec<String>,
+}
+
+/// 代理测试结果，latency_ms 在失败时为 -1
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProxyTestResult {
+    pub success: bool,
+    pub latency_ms: i64,
+    pub error: String,
+}
+
+impl ProxySettings {
+    /// 未启用时返回 None
+    pub(crate) fn to_proxy(&self) -> Result<Option<Proxy>> {
+        if !self.enabled {
+            return Ok(None);
+        }
+        let url = reqwest::Url::parse(self.url.trim())?;
+        if !matches!(url.scheme(), "http" | "https" | "socks5" | "socks5h") {
+            return Err(anyhow!("Unsupported proxy scheme: {}", url.scheme()));
+        }
+        let mut proxy = Proxy::all(url)?;
+        if !self.username.is_empty() {
+            proxy = proxy.basic_auth(&self.username, &self.password);
+        }
+        let bypass = self
+            .bypass
+            .iter()
+            .map(|host| host.trim())
+            .filter(|host| !host.is_empty())
+            .collect::<Vec<_>>()
+            .join(",");
+        Ok(Some(proxy.no_proxy(NoProxy::from_string(&bypass))))
+    }
+}
+
+pub(crate) async fn load_proxy_settings() -> Result<ProxySettings> {
+    let value = load_property("proxy_settings".to_string()).await?;
+    if value.is_empty() {
+        return Ok(ProxySettings::default());
+    }
+    Ok(serde_json::from_str(&value)?)
+}
+
+/// 保存代理设置并重新创建 HTTP 客户端，cookie 保持不变
+pub(crate) async fn save_proxy_settings(settings: ProxySettings) -> Result<()> {
+    let client = build_http_client(&settings)?;
+    save_property(
+        "proxy_settings".to_string(),
+        serde_json::to_string(&settings)?,
+    )
+    .await?;
+    CLIENT.set_http(client);
+    info!(enabled = settings.enabled, "Proxy settings applied");
+    Ok(())
+}
+
+pub(crate) async fn init_proxy() -> Result<()> {
+    let settings = load_proxy_settings().await?;
+    if settings.enabled {
+        CLIENT.set_http(build_http_client(&settings)?);
+    }
+    Ok(())
+}
+
+/// 用给定的代理设置访问当前镜像的首页，不会保存设置
+pub(crate) async fn test_proxy(settings: ProxySettings) -> ProxyTestResult {
+    let client = match build_http_client(&settings) {
+        Ok(client) => client,
+        Err(e) => {
+            return ProxyTestResult {
+                success: false,
+                latency_ms: -1,
+                error: e.to_string(),
+            }
+        }
+    };
+    let url = format!("{}/index.php?charset=gbk", CLIENT.load_api_host().await);
+    let start = Instant::now();
+    let result = client
+        .get(url)
+        .header("User-Agent", CLIENT.load_user_agent().await)
+        .timeout(TEST_TIMEOUT)
+        .send()
+        .await;
+    let error = match result {
+        Ok(response) => match crate::wenku8::error::check_response(response) {
+            Ok(_) => None,
+            Err(e) => Some(e.to_string()),
+        },
+        Err(e) => Some(crate::wenku8::Wenku8Error::from(e).to_string()),
+    };
+    match error {
+        None => ProxyTestResult {
+            success: true,
+            latency_ms: start.elapsed().as_millis() as i64,
+            error: String::new(),
+        },
+        Some(error) => ProxyTestResult {
+            success: false,
+            latency_ms: -1,
+            error,
+        },
+    }
+}
@@ -0,0 +1,68 @@
+use crate::wenku8::{
+    BookshelfItem, HomeBlock, Language, NovelCover, NovelInfo, PageStats, Volume,
+};
+use crate::{Result, CLIENT};
+use anyhow::anyhow;
+use once_cell::sync::Lazy;
+
+/// 默认书源，数据库中没有书源的旧记录都属于这个书源
+pub(crate) const DEFAULT_SOURCE_ID: &str = "wenku8";
+
+/// 小说站点，浏览、阅读和下载都通过它访问
+/// 各站点复用 wenku8 的数据模型
+#[async_trait::async_trait]
+pub trait NovelSource: Send + Sync {
+    /// 书源ID，保存在数据库中，不能修改
+    fn id(&self) -> &'static str;
+
+    fn name(&self) -> &'static str;
+
+    async fn index(&self) -> Result<Vec<HomeBlock>>;
+
+    async fn search(
+        &self,
+        search_type: &str,
+        search_key: &str,
+        page: i32,
+    ) -> Result<PageStats<NovelCover>>;
+
+    async fn novel_info(&self, aid: &str) -> Result<NovelInfo>;
+
+    async fn novel_reader(&self, aid: &str) -> Result<Vec<Volume>>;
+
+    async fn chapter_content(&self, aid: &str, cid: &str) -> Result<String>;
+
+    /// 按指定的语言获取章节，下载时使用，只有一种语言的书源忽略 language
+    async fn chapter_content_in(&self, aid: &str, cid: &str, language: Language) -> Result<String> {
+        let _ = language;
+        self.chapter_content(aid, cid).await
+    }
+
+    async fn download_image(&self, url: &str) -> Result<Vec<u8>>;
+
+    async fn bookshelf(&self) -> Result<Vec<BookshelfItem>>;
+}
+
+static SOURCES: Lazy<Vec<&'static dyn NovelSource>> =
+    Lazy::new(|| vec![&*CLIENT, &crate::wenku8::source::Wenku8AppSource]);
+
+pub(crate) fn all_sources() -> &'static [&'static dyn NovelSource] {
+    SOURCES.as_slice()
+}
+
+pub(crate) fn source(source_id: &str) -> Result<&'static dyn NovelSource> {
+    SOURCES
+        .iter()
+        .find(|source| source.id() == source_id)
+        .copied()
+        .ok_or_else(|| anyhow!("Unknown novel source: {}", source_id))
+}
+
+/// 缓存键和本地ID加上书源前缀，默认书源保持原样以兼容旧数据
+pub(crate) fn source_key(source_id: &str, key: &str) -> String {
+    if source_id == DEFAULT_SOURCE_ID {
+        key.to_string()
+    } else {
+        format!("{}@{}", source_id, key)
+    }
+}
@@ -1,3 +1,4 @@
+use crate::database::entities::cookie::cookie_store::DatabaseCookieStore;
 use crate::wenku8::Wenku8Client;
 use crate::CLIENT;
 
@@ -24,7 +25,7 @@ async fn test_init() -> anyhow::Result<()> {
 async fn test_cookie_store() -> anyhow::Result<()> {
     init_context().await?;
     let response = CLIENT
-        .client
+        .http()
         .get("https://baidu.com/")
         .header("User-Agent", "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/135.0.0.0 Safari/537.36")
         .send()
@@ -173,4 +174,759 @@ async fn test_reviews() -> anyhow::Result<()> {
     Ok(())
 }
 
-1
\ No newline at end of file
+#[test]
+fn test_parse_chapter_segments() -> anyhow::Result<()> {
+    use crate::chapter_document::{parse_segments, ChapterSegment, ChapterSegmentKind};
+    let content = "第一章\r\n\r\n　　正文第一段\r\n＊＊＊\r\n<!--image-->http://pic.wenku8.com/pictures/1/1/1/1.jpg<!--image-->\r\n　　正文第二段\r\n";
+    let segments = parse_segments(content);
+    assert_eq!(segments.len(), 5);
+    assert_eq!(segments[0], ChapterSegment::paragraph("第一章".to_string()));
+    assert_eq!(segments[1], ChapterSegment::paragraph("正文第一段".to_string()));
+    assert_eq!(segments[2].kind, ChapterSegmentKind::Separator);
+    assert_eq!(segments[3].kind, ChapterSegmentKind::Image);
+    assert_eq!(segments[3].url, "http://pic.wenku8.com/pictures/1/1/1/1.jpg");
+    assert_eq!(segments[4], ChapterSegment::paragraph("正文第二段".to_string()));
+    Ok(())
+}
+
+#[test]
+fn test_write_epub() -> anyhow::Result<()> {
+    use crate::exporting::{write_epub, EpubBook, EpubChapter, EpubImage, EpubSection};
+    use std::io::Read;
+    let image = EpubImage {
+        file_name: "abc.png".to_string(),
+        media_type: "image/png",
+        data: vec![0x89, b'P', b'N', b'G'],
+    };
+    let book = EpubBook {
+        identifier: "wenku8-1".to_string(),
+        title: "测试 & 小说".to_string(),
+        author: "作者".to_string(),
+        description: "简介".to_string(),
+        cover: None,
+        sections: vec![EpubSection {
+            idx: 0,
+            title: "第一卷".to_string(),
+            chapters: vec![EpubChapter {
+                id: "101".to_string(),
+                title: "第一章".to_string(),
+                body: "<p>正文</p>\n".to_string(),
+            }],
+            images: vec!["abc".to_string()],
+        }],
+        images: vec![image],
+    };
+    std::fs::create_dir_all("target/test_data")?;
+    let path = std::path::Path::new("target/test_data/test_write_epub.epub");
+    write_epub(&book, path)?;
+
+    let mut zip = zip::ZipArchive::new(std::fs::File::open(path)?)?;
+    let mimetype = zip.by_index(0)?;
+    assert_eq!(mimetype.name(), "mimetype");
+    assert_eq!(mimetype.compression(), zip::CompressionMethod::Stored);
+    drop(mimetype);
+    assert!(zip.by_name("OEBPS/images/abc.png").is_ok());
+    assert!(zip.by_name("OEBPS/text/chapter_101.xhtml").is_ok());
+    let mut nav = String::new();
+    zip.by_name("OEBPS/nav.xhtml")?.read_to_string(&mut nav)?;
+    assert!(nav.contains("<title>测试 &amp; 小说</title>"));
+    assert!(nav.contains("<a href=\"text/chapter_101.xhtml\">第一章</a>"));
+    assert!(!nav.contains("<ol>\n</ol>"));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_rate_limiter() -> anyhow::Result<()> {
+    let limiter = crate::wenku8::rate_limit::RateLimiter::new(10.0);
+    let start = tokio::time::Instant::now();
+    for _ in 0..12 {
+        limiter.acquire().await;
+    }
+    // 桶内 10 个令牌立即可用，之后每个等待 0.1 秒
+    let elapsed = start.elapsed().as_millis();
+    assert!((190..1000).contains(&elapsed), "elapsed {elapsed}ms");
+
+    assert!(crate::check_rate_limit(0.0).is_ok());
+    assert!(crate::check_rate_limit(0.5).is_ok());
+    assert!(crate::check_rate_limit(1e-300).is_err());
+    assert!(crate::check_rate_limit(f64::NAN).is_err());
+    assert!(crate::check_rate_limit(f64::INFINITY).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_classify_error_page() -> anyhow::Result<()> {
+    use crate::wenku8::error::classify_page;
+    use crate::wenku8::Wenku8Error;
+    let error_page = |reason: &str| {
+        format!(
+            "<title>出现错误！</title><div id=\"content\"><div class=\"blocktitle\">出现错误！</div><div class=\"blockcontent\"><br />错误原因：{}<br /><br />请 <a href=\"javascript:history.back(1)\">返 回</a> 并修正</div></div>",
+            reason
+        )
+    };
+    let page = error_page("对不起，该文章不存在或已被删除！");
+    assert_eq!(classify_page(&page), Some(Wenku8Error::NotFound));
+    let page = error_page("对不起，校验码错误！");
+    assert_eq!(classify_page(&page), Some(Wenku8Error::CaptchaWrong));
+    assert_eq!(
+        classify_page("<title>Just a moment...</title>"),
+        Some(Wenku8Error::CloudflareChallenge)
+    );
+    let page = error_page("对不起，您发帖间隔不能少于 30 秒！");
+    assert_eq!(classify_page(&page), Some(Wenku8Error::RateLimited));
+    let page = error_page("对不起，您没有发表书评的权限！");
+    assert_eq!(classify_page(&page), Some(Wenku8Error::PermissionDenied));
+    // 正文或侧边栏里出现的文字不算
+    let page = "<title>轻小说文库</title><div id=\"content\">Just a moment... 出现错误！错误原因：不存在</div>";
+    assert_eq!(classify_page(page), None);
+    // 登录过期后需要登录的页面变成登录页，侧边栏的登录框不算
+    let page = "<title>用户登录 - 轻小说文库</title><form name=\"frmlogin\" action=\"login.php?do=submit\">";
+    assert_eq!(classify_page(page), Some(Wenku8Error::NotLoggedIn));
+    let page = "<title>轻小说文库</title><form name=\"frmlogin\" action=\"login.php?do=submit\">";
+    assert_eq!(classify_page(page), None);
+    assert_eq!(classify_page("<title>轻小说文库</title>"), None);
+    // 前端拿到的是带上下文的错误链
+    let error = anyhow::Error::from(Wenku8Error::Http(403)).context("Failed to get index");
+    let message = format!("{:?}", error);
+    assert_eq!(Wenku8Error::parse(&message), Some(Wenku8Error::Http(403)));
+    assert_eq!(Wenku8Error::of(&error), Some(Wenku8Error::Http(403)));
+    Ok(())
+}
+
+#[test]
+fn test_backoff_delay() {
+    use crate::wenku8::retry::backoff_delay;
+    for attempt in 0..3 {
+        let base = 500u128 << attempt;
+        let delay = backoff_delay(attempt).as_millis();
+        assert!((base..=base * 3 / 2).contains(&delay), "delay {delay}ms");
+    }
+    // 上限 8 秒，加上抖动最多 12 秒
+    assert!(backoff_delay(30).as_millis() <= 12_000);
+}
+
+#[tokio::test]
+async fn test_mirror_failover() -> anyhow::Result<()> {
+    use crate::wenku8::rate_limit::RateLimiter;
+    use tokio::sync::RwLock;
+    let client = Wenku8Client {
+        client: std::sync::RwLock::new(reqwest::Client::new()),
+        user_agent: RwLock::new("".to_string()),
+        api_host: RwLock::new("".to_string()),
+        mirror_hosts: RwLock::new(vec![
+            "https://a.example".to_string(),
+            "https://b.example".to_string(),
+        ]),
+        language: RwLock::new(Default::default()),
+        host_changes: tokio::sync::broadcast::channel(4).0,
+        text_limiter: RateLimiter::new(0.0),
+        image_limiter: RateLimiter::new(0.0),
+    };
+    let mut changes = client.subscribe_host_changes();
+    let mut url = reqwest::Url::parse("https://a.example/index.php?charset=gbk")?;
+    assert!(client.failover(&mut url).await);
+    assert_eq!(url.as_str(), "https://b.example/index.php?charset=gbk");
+    assert_eq!(client.load_api_host().await, "https://b.example");
+    assert_eq!(changes.try_recv()?, "https://b.example");
+    // 并发的请求还在旧镜像上，只改地址不再切换
+    let mut url = reqwest::Url::parse("https://a.example/login.php")?;
+    assert!(client.failover(&mut url).await);
+    assert_eq!(url.as_str(), "https://b.example/login.php");
+    assert!(changes.try_recv().is_err());
+    Ok(())
+}
+
+#[test]
+fn test_proxy_settings() -> anyhow::Result<()> {
+    use crate::proxy::ProxySettings;
+    let mut settings = ProxySettings {
+        enabled: false,
+        url: "ftp://127.0.0.1:21".to_string(),
+        username: "user".to_string(),
+        password: "pass".to_string(),
+        bypass: vec!["localhost".to_string(), " ".to_string()],
+    };
+    assert!(settings.to_proxy()?.is_none());
+    settings.enabled = true;
+    assert!(settings.to_proxy().is_err());
+    settings.url = "socks5h://127.0.0.1:1080".to_string();
+    assert!(settings.to_proxy()?.is_some());
+    assert!(crate::build_http_client(&settings).is_ok());
+    Ok(())
+}
+
+#[test]
+fn test_parse_app_xml() -> anyhow::Result<()> {
+    let metadata = r#"<?xml version="1.0" encoding="utf-8"?>
+<metadata>
+<data name="Title" aid="1143"><![CDATA[某科学的超电磁炮]]></data>
+<data name="Author" value="镰池和马"/>
+<data name="BookStatus" value="连载中"/>
+<data name="LastUpdate" value="2020-01-01"/>
+<data name="Tags" value="校园 超能力"/>
+</metadata>"#;
+    let info = Wenku8Client::parse_app_metadata(metadata)?;
+    assert_eq!(info.title, "某科学的超电磁炮");
+    assert_eq!(info.author, "镰池和马");
+    assert_eq!(info.tags, vec!["校园", "超能力"]);
+
+    let index = r#"<?xml version="1.0" encoding="utf-8"?>
+<package>
+<volume vid="41748"><![CDATA[第一卷]]>
+<chapter cid="41749"><![CDATA[序章]]></chapter>
+<chapter cid="41750"><![CDATA[第一章]]></chapter>
+</volume>
+</package>"#;
+    let volumes = Wenku8Client::parse_app_index(index, "1143", "https://www.wenku8.net")?;
+    assert_eq!(volumes.len(), 1);
+    assert_eq!(volumes[0].id, "41748");
+    assert_eq!(volumes[0].title, "第一卷");
+    assert_eq!(volumes[0].chapters[1].cid, "41750");
+    assert_eq!(volumes[0].chapters[1].title, "第一章");
+
+    let list = "<?xml version=\"1.0\" encoding=\"utf-8\"?>\n<result><page num='166'/><item aid='1143'/><item aid='1213'/></result>";
+    let (max_page, items) = Wenku8Client::parse_app_list(list)?;
+    assert_eq!(max_page, 166);
+    assert_eq!(items.len(), 2);
+    assert_eq!(items[1].aid, "1213");
+
+    assert!(Wenku8Client::parse_app_result("1").is_ok());
+    assert!(Wenku8Client::parse_app_result("4").is_err());
+    Ok(())
+}
+
+#[test]
+fn test_language_variants() {
+    use crate::wenku8::error::classify_page;
+    use crate::wenku8::{Language, Wenku8Error};
+    assert_eq!(Language::from_code("zh-Hant"), Language::Traditional);
+    assert_eq!(Language::from_code(""), Language::Simplified);
+    assert_eq!(Language::Simplified.cache_key("NOVEL_INFO$1"), "NOVEL_INFO$1");
+    assert_eq!(Language::Traditional.cache_key("NOVEL_INFO$1"), "NOVEL_INFO$1$big5");
+    let page = "<title>出現錯誤！</title><div id=\"content\"><b>出現錯誤！</b><br>錯誤原因：對不起，您需要登錄後才能訪問<br></div>";
+    assert_eq!(classify_page(page), Some(Wenku8Error::NotLoggedIn));
+}
+
+#[test]
+fn test_zh_convert() {
+    use crate::wenku8::Language;
+    use crate::zh_convert::convert;
+    assert_eq!(
+        convert("她的头发很干净，后来发现了关系", Language::Traditional),
+        "她的頭髮很乾淨，後來發現了關係"
+    );
+    assert_eq!(convert("皇后干什么", Language::Traditional), "皇后幹什麼");
+    assert_eq!(
+        convert("頭髮很乾淨，著名的乾隆", Language::Simplified),
+        "头发很干净，著名的乾隆"
+    );
+    assert_eq!(convert("abc 123", Language::Simplified), "abc 123");
+}
+
+#[test]
+fn test_parse_review_detail() -> anyhow::Result<()> {
+    use crate::wenku8::ReviewBlock;
+    let text = r##"<html><body><div id="content">
+<table class="grid"><tr><td colspan="2">主题：第一卷感想</td></tr></table>
+<table class="grid"><tr>
+<td class="odd"><a href="https://www.wenku8.net/userpage.php?uid=123">读者甲</a></td>
+<td class="even"><div>2024-01-02 03:04:05 <a href="#yid1">1楼</a></div><hr>
+第一段<br>第二段 <a href="https://example.com/a">链接</a>
+<div class="jieqiQuote">引用的内容<table><tr><td><a href="/userpage.php?uid=9">读者乙</a></td><td>2楼</td></tr></table></div>
+<img src="https://img.example.com/1.jpg">
+</td></tr></table>
+</div><em id="pagestats">1/3</em></body></html>"##;
+    let detail = Wenku8Client::parse_review_detail(text)?;
+    assert_eq!(detail.title, "第一卷感想");
+    assert_eq!(detail.max_page, 3);
+    assert_eq!(detail.posts.len(), 1);
+    let post = &detail.posts[0];
+    assert_eq!(post.uid, "123");
+    assert_eq!(post.uname, "读者甲");
+    assert_eq!(post.time, "2024-01-02 03:04:05");
+    assert_eq!(post.floor, 1);
+    assert_eq!(
+        post.blocks,
+        vec![
+            ReviewBlock::text("第一段".to_string()),
+            ReviewBlock::text("第二段".to_string()),
+            ReviewBlock::link("链接".to_string(), "https://example.com/a".to_string()),
+            ReviewBlock::quote("引用的内容\n读者乙\n2楼".to_string()),
+            ReviewBlock::image("https://img.example.com/1.jpg".to_string()),
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_parse_messages() -> anyhow::Result<()> {
+    let text = r#"<html><body><div id="content">
+<table class="grid">
+<tr><th>选择</th><th>发件人</th><th>标题</th><th>发送时间</th></tr>
+<tr>
+<td><input type="checkbox" name="checkid[]" value="11"></td>
+<td><a href="https://www.wenku8.net/userpage.php?uid=5">系统管理员</a></td>
+<td><b><a href="https://www.wenku8.net/messagedetail.php?id=11">欢迎</a></b></td>
+<td>2024-01-02 03:04</td>
+</tr>
+<tr>
+<td><input type="checkbox" name="checkid[]" value="12"></td>
+<td><a href="https://www.wenku8.net/userpage.php?uid=6">读者乙</a></td>
+<td><a href="https://www.wenku8.net/messagedetail.php?id=12&box=inbox">你好</a></td>
+<td>2024-02-03</td>
+</tr>
+</table>
+<div class="pages"><em id="pagestats">1/2</em></div>
+</div></body></html>"#;
+    let page = Wenku8Client::parse_messages(text)?;
+    assert_eq!(page.current_page, 1);
+    assert_eq!(page.max_page, 2);
+    assert_eq!(page.records.len(), 2);
+    assert_eq!(page.records[0].id, "11");
+    assert_eq!(page.records[0].title, "欢迎");
+    assert_eq!(page.records[0].user_id, "5");
+    assert_eq!(page.records[0].username, "系统管理员");
+    assert_eq!(page.records[0].time, "2024-01-02 03:04");
+    assert!(page.records[0].unread);
+    assert_eq!(page.records[1].id, "12");
+    assert_eq!(page.records[1].time, "2024-02-03");
+    assert!(!page.records[1].unread);
+
+    let text = r#"<html><body><div id="content">
+<table class="grid">
+<tr><td class="odd">发件人：</td><td class="even">系统管理员</td></tr>
+<tr><td class="odd">收件人：</td><td class="even">读者甲</td></tr>
+<tr><td class="odd">发送时间：</td><td class="even">2024-01-02 03:04</td></tr>
+<tr><td class="odd">标题：</td><td class="even">欢迎</td></tr>
+<tr><td class="odd">内容：</td><td class="even">第一行<br />  第二行<br></td></tr>
+</table>
+</div></body></html>"#;
+    let detail = Wenku8Client::parse_message_detail(text)?;
+    assert_eq!(detail.sender, "系统管理员");
+    assert_eq!(detail.receiver, "读者甲");
+    assert_eq!(detail.time, "2024-01-02 03:04");
+    assert_eq!(detail.title, "欢迎");
+    assert_eq!(detail.content, "第一行\n第二行");
+    assert!(Wenku8Client::parse_message_detail("<html><body></body></html>").is_err());
+    Ok(())
+}
+
+#[test]
+fn test_parse_recommend() -> anyhow::Result<()> {
+    use crate::wenku8::Wenku8Error;
+    use crate::wenku8::error::classify_page;
+    let page = "<title>处理成功</title><div id=\"content\">恭喜您，推荐本书成功！</div>";
+    Wenku8Client::parse_recommend(page)?;
+    // 侧边栏等其他位置出现 "成功" 不算推荐成功
+    let page = "<title>推荐本书</title><div id=\"content\">请稍后再试</div><div>注册成功的用户可以推荐</div>";
+    let error = Wenku8Client::parse_recommend(page).unwrap_err();
+    assert_eq!(
+        Wenku8Error::of(&error),
+        Some(Wenku8Error::Server("请稍后再试".to_string()))
+    );
+    // 错误页面在 read_page 中分类
+    let page = "<title>出现错误！</title><div id=\"content\"><br />错误原因：对不起，您今天的推荐次数已经用完，每天最多可以推荐 3 次！<br /></div>";
+    assert_eq!(classify_page(page), Some(Wenku8Error::VotesExhausted));
+    let page = "<title>出现错误！</title><div id=\"content\"><br />错误原因：对不起，您需要登录才能使用本功能！<br /></div>";
+    assert_eq!(classify_page(page), Some(Wenku8Error::NotLoggedIn));
+    Ok(())
+}
+
+#[test]
+fn test_vault_encrypt() -> anyhow::Result<()> {
+    use crate::vault::{decrypt, derive_key, encrypt};
+    let key = derive_key("passphrase", b"0123456789abcdef");
+    let encrypted = encrypt(&key, "密码".as_bytes())?;
+    assert_ne!(encrypt(&key, "密码".as_bytes())?, encrypted);
+    assert_eq!(decrypt(&key, &encrypted)?, "密码".as_bytes());
+    let wrong = derive_key("wrong", b"0123456789abcdef");
+    assert!(decrypt(&wrong, &encrypted).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_cookie_file() -> anyhow::Result<()> {
+    use crate::cookie_file::{format_cookies, parse_cookies, CookieFileFormat};
+    let text = "# Netscape HTTP Cookie File\n\
+        \n\
+        #HttpOnly_.wenku8.net\tTRUE\t/\tFALSE\t1893456000\tjieqiUserInfo\tjieqiUserId%3D1\n\
+        www.wenku8.net\tFALSE\t/\tTRUE\t0\tPHPSESSID\tabc\n\
+        .example.com\tTRUE\t/\tFALSE\t0\tother\t\n";
+    let cookies = parse_cookies(CookieFileFormat::Netscape, text)?;
+    assert_eq!(cookies.len(), 3);
+    assert_eq!(cookies[0].domain, ".wenku8.net");
+    assert_eq!(cookies[0].name, "jieqiUserInfo");
+    assert_eq!(cookies[0].expires, Some(1893456000));
+    assert_eq!(cookies[0].http_only, Some(true));
+    assert_eq!(cookies[0].host_only, Some(false));
+    assert_eq!(cookies[1].host_only, Some(true));
+    assert_eq!(cookies[1].expires, None);
+    assert_eq!(cookies[1].secure, Some(true));
+    assert_eq!(cookies[2].value, "");
+    let exported = format_cookies(CookieFileFormat::Netscape, &cookies)?;
+    assert_eq!(
+        parse_cookies(CookieFileFormat::Netscape, &exported)?,
+        cookies
+    );
+    let exported = format_cookies(CookieFileFormat::Json, &cookies)?;
+    assert_eq!(parse_cookies(CookieFileFormat::Json, &exported)?, cookies);
+
+    // Cookie-Editor 导出的数组和 Playwright 的 storage state
+    let text = r#"[{"domain":".wenku8.net","name":"jieqiUserInfo","value":"x","path":"/","httpOnly":true,"secure":false,"expirationDate":1893456000.5,"hostOnly":false}]"#;
+    let cookies = parse_cookies(CookieFileFormat::Json, text)?;
+    assert_eq!(cookies[0].expires, Some(1893456000));
+    assert_eq!(cookies[0].http_only, Some(true));
+    let text = r#"{"cookies":[{"domain":"www.wenku8.net","name":"PHPSESSID","value":"abc","expires":-1}],"origins":[]}"#;
+    let cookies = parse_cookies(CookieFileFormat::Json, text)?;
+    assert_eq!(cookies[0].expires, None);
+    assert_eq!(cookies[0].path, "/");
+    assert_eq!(cookies[0].host_only, Some(true));
+    assert!(parse_cookies(CookieFileFormat::Netscape, "www.wenku8.net\tFALSE\t/").is_err());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_reading_history_account_key() -> anyhow::Result<()> {
+    use crate::database::entities::active::{reading_history, Migrator};
+    use sea_orm::{ActiveModelTrait, ConnectionTrait, EntityTrait, Set};
+    use sea_orm_migration::MigratorTrait;
+    let mut options = sea_orm::ConnectOptions::new("sqlite::memory:");
+    options.max_connections(1).min_connections(1);
+    let db = sea_orm::Database::connect(options).await?;
+    // 先停在改主键之前，写入一条旧记录
+    let pending = Migrator::migrations()
+        .iter()
+        .position(|m| m.name() == "m000007_account_primary_key_reading_history")
+        .unwrap() as u32;
+    Migrator::up(&db, Some(pending)).await?;
+    db.execute_unprepared(
+        "INSERT INTO reading_history (novel_id, novel_name, volume_id, volume_name, chapter_id, \
+        chapter_title, last_read_at, progress, progress_page, cover, author, source_id, account_id) \
+        VALUES ('1', '小说', 'v', '卷', 'c', '章', 1, 0, 0, '', '', 'wenku8', 0)",
+    )
+    .await?;
+    Migrator::up(&db, None).await?;
+    // 另一个账号可以保存同一本小说的记录
+    reading_history::ActiveModel {
+        account_id: Set(2),
+        novel_id: Set("1".to_string()),
+        novel_name: Set("小说".to_string()),
+        volume_id: Set("v".to_string()),
+        volume_name: Set("卷".to_string()),
+        chapter_id: Set("c2".to_string()),
+        chapter_title: Set("章".to_string()),
+        last_read_at: Set(2),
+        progress: Set(0),
+        progress_page: Set(0),
+        cover: Set(String::new()),
+        author: Set(String::new()),
+        source_id: Set("wenku8".to_string()),
+    }
+    .insert(&db)
+    .await?;
+    let records = reading_history::Entity::find().all(&db).await?;
+    assert_eq!(records.len(), 2);
+    assert!(records
+        .iter()
+        .any(|r| r.account_id == 0 && r.chapter_id == "c"));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_download_language_key() -> anyhow::Result<()> {
+    use crate::database::entities::active::{novel_download_chapter, Migrator};
+    use sea_orm::{ActiveModelTrait, ConnectionTrait, EntityTrait, Set};
+    use sea_orm_migration::MigratorTrait;
+    let mut options = sea_orm::ConnectOptions::new("sqlite::memory:");
+    options.max_connections(1).min_connections(1);
+    let db = sea_orm::Database::connect(options).await?;
+    // 先停在主键加语言之前，写入一本繁体下载
+    let pending = Migrator::migrations()
+        .iter()
+        .position(|m| m.name() == "m000010_language_primary_key_novel_download")
+        .unwrap() as u32;
+    Migrator::up(&db, Some(pending)).await?;
+    db.execute_unprepared(
+        "INSERT INTO novel_download (novel_id, novel_name, download_status, cover_url, \
+        cover_download_status, author, tags, choose_chapter_count, download_chapter_count, \
+        create_time, download_time, introduce, trending, is_animated, fin_update, status, \
+        priority, source_id, language) \
+        VALUES ('1', '小說', 1, '', 1, '', '', 1, 1, 0, 0, '', '', 0, '', '', 0, 'wenku8', 'zh-Hant')",
+    )
+    .await?;
+    db.execute_unprepared(
+        "INSERT INTO novel_download_chapter (id, title, url, aid, volume_id, download_status, \
+        total_picture, chapter_idx, source_id) \
+        VALUES ('c', '章', '', '1', 'v', 1, 0, 0, 'wenku8')",
+    )
+    .await?;
+    Migrator::up(&db, None).await?;
+    // 旧章节沿用小说的语言，简体版本可以保存同一个章节
+    novel_download_chapter::ActiveModel {
+        id: Set("c".to_string()),
+        title: Set("章".to_string()),
+        url: Set(String::new()),
+        aid: Set("1".to_string()),
+        volume_id: Set("v".to_string()),
+        download_status: Set(0),
+        total_picture: Set(0),
+        chapter_idx: Set(0),
+        source_id: Set("wenku8".to_string()),
+        language: Set("zh-Hans".to_string()),
+    }
+    .insert(&db)
+    .await?;
+    let chapters = novel_download_chapter::Entity::find().all(&db).await?;
+    assert_eq!(chapters.len(), 2);
+    assert!(chapters
+        .iter()
+        .any(|c| c.language == "zh-Hant" && c.download_status == 1));
+    Ok(())
+}
+
+async fn memory_cookie_db() -> anyhow::Result<sea_orm::DatabaseConnection> {
+    let mut options = sea_orm::ConnectOptions::new("sqlite::memory:");
+    options.max_connections(1).min_connections(1);
+    let db = sea_orm::Database::connect(options).await?;
+    crate::database::entities::cookie::migrations_on(&db).await?;
+    Ok(db)
+}
+
+async fn memory_cookie_store(
+    db: sea_orm::DatabaseConnection,
+) -> anyhow::Result<DatabaseCookieStore> {
+    let store = DatabaseCookieStore::default();
+    store.load(db).await?;
+    Ok(store)
+}
+
+/// 路径长度相同的 cookie 顺序不固定，按名字排序后返回
+fn cookie_names(store: &DatabaseCookieStore, url: &str) -> anyhow::Result<Vec<String>> {
+    let mut cookies = store.load_cookies(&url.parse()?);
+    cookies.sort_by(|a, b| b.path.len().cmp(&a.path.len()).then(a.name.cmp(&b.name)));
+    Ok(cookies.into_iter().map(|cookie| cookie.name).collect())
+}
+
+#[test]
+fn test_cookie_match() {
+    use crate::database::entities::cookie::cookie_store::{default_path, domain_match, path_match};
+    assert!(domain_match("www.wenku8.net", "wenku8.net"));
+    assert!(domain_match("wenku8.net", "wenku8.net"));
+    assert!(!domain_match("wwwwenku8.net", "wenku8.net"));
+    assert!(!domain_match("wenku8.net", "www.wenku8.net"));
+    assert!(!domain_match("1.2.3.4", "2.3.4"));
+    assert!(path_match("/modules/article", "/modules"));
+    assert!(path_match("/modules/article", "/modules/"));
+    assert!(path_match("/", "/"));
+    assert!(!path_match("/modulesx", "/modules"));
+    assert!(!path_match("/", "/modules"));
+    assert_eq!(
+        default_path("/modules/article/reader.php"),
+        "/modules/article"
+    );
+    assert_eq!(default_path("/login.php"), "/");
+    assert_eq!(default_path(""), "/");
+}
+
+#[test]
+fn test_cookie_store_rules() -> anyhow::Result<()> {
+    let store = DatabaseCookieStore::default();
+    let url = "https://www.wenku8.net/modules/article/index.php".parse()?;
+    for header in [
+        "host=1; Path=/",
+        "domain=1; Domain=.wenku8.net; Path=/",
+        "foreign=1; Domain=example.com",
+        "tld=1; Domain=net",
+        "article=1",
+        "secure=1; Secure; Path=/",
+        "expired=1; Path=/; Expires=Wed, 21 Oct 2015 07:28:00 GMT",
+        "session=1; Path=/; Max-Age=3600",
+    ] {
+        store.save_cookie(&::cookie::Cookie::parse(header)?, &url);
+    }
+    // 路径长的在前，没有 Path 时使用请求所在的目录
+    assert_eq!(
+        cookie_names(&store, "https://www.wenku8.net/modules/article/reader.php")?,
+        ["article", "domain", "host", "secure", "session"]
+    );
+    // host-only 的 cookie 不发送给其他子域名，Secure 的不发送给 http
+    assert_eq!(
+        cookie_names(&store, "http://www.wenku8.net/")?,
+        ["domain", "host", "session"]
+    );
+    assert_eq!(
+        cookie_names(&store, "https://m.wenku8.net/modules/article/x")?,
+        ["domain"]
+    );
+    assert!(cookie_names(&store, "https://www.example.com/")?.is_empty());
+
+    // 替换和 Max-Age=0 删除
+    store.save_cookie(&::cookie::Cookie::parse("session=2; Path=/")?, &url);
+    let cookies = store.load_cookies(&"https://www.wenku8.net/".parse()?);
+    assert_eq!(cookies.len(), 4);
+    assert!(cookies
+        .iter()
+        .any(|cookie| cookie.name == "session" && cookie.value == "2" && cookie.expires.is_none()));
+    store.save_cookie(
+        &::cookie::Cookie::parse("session=; Path=/; Max-Age=0")?,
+        &url,
+    );
+    assert_eq!(
+        cookie_names(&store, "https://www.wenku8.net/")?,
+        ["domain", "host", "secure"]
+    );
+    // 很大的 Max-Age 不会溢出
+    store.save_cookie(
+        &::cookie::Cookie::parse("forever=1; Path=/; Max-Age=9223372036854775807")?,
+        &url,
+    );
+    assert_eq!(
+        cookie_names(&store, "https://www.wenku8.net/")?,
+        ["domain", "forever", "host", "secure"]
+    );
+    Ok(())
+}
+
+/// 从最初的表结构升级，旧的 cookie 归到未绑定账号，域名去掉前导的点
+#[tokio::test]
+async fn test_cookie_migrations_upgrade() -> anyhow::Result<()> {
+    use crate::database::entities::cookie::migrations::Migrator;
+    use crate::database::entities::CookieEntity;
+    use sea_orm::ConnectionTrait;
+    use sea_orm_migration::MigratorTrait;
+    let mut options = sea_orm::ConnectOptions::new("sqlite::memory:");
+    options.max_connections(1).min_connections(1);
+    let db = sea_orm::Database::connect(options).await?;
+    Migrator::up(&db, Some(2)).await?;
+    db.execute_unprepared(
+        "INSERT INTO cookie (domain, name, value, path, expires, secure, http_only) \
+        VALUES ('.wenku8.net', 'jieqiUserInfo', 'x', '/', NULL, 0, 1)",
+    )
+    .await?;
+    Migrator::up(&db, None).await?;
+    let cookies = CookieEntity::find_all(&db).await?;
+    assert_eq!(cookies.len(), 1);
+    assert_eq!(cookies[0].account_id, 0);
+    assert_eq!(cookies[0].domain, "wenku8.net");
+    assert_eq!(cookies[0].host_only, None);
+    // 账号 id 自增
+    db.execute_unprepared(
+        "INSERT INTO account (username, create_time, last_login_time) VALUES ('a', 0, 0), ('b', 0, 0)",
+    )
+    .await?;
+    let ids = db
+        .query_all(sea_orm::Statement::from_string(
+            db.get_database_backend(),
+            "SELECT id FROM account ORDER BY id",
+        ))
+        .await?
+        .iter()
+        .map(|row| row.try_get::<i64>("", "id"))
+        .collect::<Result<Vec<_>, _>>()?;
+    assert_eq!(ids, [1, 2]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cookie_store_purge_expired() -> anyhow::Result<()> {
+    use crate::database::entities::{Cookie, CookieEntity};
+    let db = memory_cookie_db().await?;
+    let now = chrono::Utc::now().timestamp();
+    let cookies = [
+        ("alive", Some(now + 3600)),
+        ("expired", Some(now - 1)),
+        ("session", None),
+    ];
+    for (name, expires) in cookies {
+        let cookie = Cookie {
+            account_id: crate::account::active_account_id(),
+            domain: "wenku8.net".to_string(),
+            path: "/".to_string(),
+            name: name.to_string(),
+            value: "1".to_string(),
+            expires,
+            secure: Some(false),
+            http_only: Some(false),
+            host_only: Some(false),
+        };
+        CookieEntity::save_or_update_cookie(&db, cookie).await?;
+    }
+    let store = memory_cookie_store(db.clone()).await?;
+    assert_eq!(store.export_cookies(crate::account::active_account_id()).len(), 3);
+    // 过期的 cookie 不发送，并在读取时删除
+    assert_eq!(
+        cookie_names(&store, "https://www.wenku8.net/")?,
+        ["alive", "session"]
+    );
+    assert_eq!(store.export_cookies(crate::account::active_account_id()).len(), 2);
+    store.flush().await?;
+    assert_eq!(CookieEntity::find_all(&db).await?.len(), 2);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cookie_store_import_hosts() -> anyhow::Result<()> {
+    use crate::database::entities::Cookie;
+    let store = memory_cookie_store(memory_cookie_db().await?).await?;
+    let cookies = [
+        ".wenku8.net",
+        "www.wenku8.net",
+        "m.www.wenku8.net",
+        "wenku8.cc",
+        "fake-wenku8.net",
+    ]
+    .into_iter()
+    .enumerate()
+    .map(|(idx, domain)| Cookie {
+        account_id: 0,
+        domain: domain.to_string(),
+        path: "/".to_string(),
+        name: format!("c{idx}"),
+        value: "1".to_string(),
+        expires: None,
+        secure: Some(false),
+        http_only: Some(false),
+        host_only: Some(false),
+    })
+    .collect();
+    // 接口地址的上级域名和子域名都保留，其他站点的跳过
+    let hosts = ["www.wenku8.net".to_string()];
+    assert_eq!(store.import_cookies(7, &hosts, cookies), 3);
+    Ok(())
+}
+
+/// 单线程的运行时中通过 reqwest 的 CookieStore 读写，修改写入数据库后可以重新载入
+#[tokio::test]
+async fn test_cookie_store_persist() -> anyhow::Result<()> {
+    use reqwest::cookie::CookieStore;
+    let db = memory_cookie_db().await?;
+    let store = memory_cookie_store(db.clone()).await?;
+    let url = "https://www.wenku8.net/login.php".parse()?;
+    let headers = [
+        reqwest::header::HeaderValue::from_static("jieqiUserInfo=1; Domain=wenku8.net; Path=/"),
+        reqwest::header::HeaderValue::from_static("PHPSESSID=abc; Path=/"),
+    ];
+    store.set_cookies(&mut headers.iter(), &url);
+    let header = store.cookies(&"https://www.wenku8.net/index.php".parse()?);
+    assert_eq!(header.unwrap(), "jieqiUserInfo=1; PHPSESSID=abc");
+    assert!(store
+        .cookies(&"https://www.example.com/".parse()?)
+        .is_none());
+    store.flush().await?;
+    let reloaded = memory_cookie_store(db.clone()).await?;
+    assert_eq!(
+        cookie_names(&reloaded, "https://www.wenku8.net/")?,
+        ["PHPSESSID", "jieqiUserInfo"]
+    );
+
+    // 账号间转移和删除
+    let account_id = crate::account::active_account_id();
+    store.move_account(account_id, account_id + 100);
+    assert!(store.has_cookie(account_id + 100, "jieqiUserInfo"));
+    assert!(!store.has_cookie(account_id, "jieqiUserInfo"));
+    store.delete_account(account_id + 100);
+    store.flush().await?;
+    let reloaded = memory_cookie_store(db).await?;
+    assert!(!reloaded.has_cookie(account_id + 100, "jieqiUserInfo"));
+    Ok(())
+}
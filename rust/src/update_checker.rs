@@ -0,0 +1,313 @@
+use crate::{
+    api::database::{load_property, save_property},
+    database::entities::{
+        active::{
+            novel_download, novel_download_chapter, novel_download_volume,
+            DOWNLOAD_STATUS_DELETING, DOWNLOAD_STATUS_FAILED, DOWNLOAD_STATUS_NOT_DOWNLOAD,
+            DOWNLOAD_STATUS_SUCCESS,
+        },
+        ChapterSnapshotEntity, NovelUpdateEntity, ReadingHistoryEntity,
+    },
+    source::{all_sources, source, source_key},
+    wenku8::{Language, Volume},
+    Result,
+};
+use once_cell::sync::Lazy;
+use std::collections::HashSet;
+use tokio::sync::Mutex;
+use tokio::task::spawn;
+use tracing::{debug, error, info, instrument, warn};
+
+const DEFAULT_CHECK_INTERVAL_HOURS: i64 = 6;
+
+// 后台检查和手动检查不能同时进行，否则同一章节会被重复加入下载
+static CHECK_LOCK: Lazy<Mutex<()>> = Lazy::new(|| Mutex::new(()));
+
+#[instrument(skip_all)]
+pub async fn start_update_checker() -> Result<()> {
+    info!("Starting update checker...");
+    spawn(update_check_loop());
+    Ok(())
+}
+
+async fn update_check_loop() {
+    loop {
+        tokio::time::sleep(tokio::time::Duration::from_secs(60)).await;
+        match is_check_due().await {
+            Ok(true) => match check_updates().await {
+                Ok(count) => info!(count, "Update check finished"),
+                Err(e) => error!(error = %e, "Update check failed"),
+            },
+            Ok(false) => {}
+            Err(e) => error!(error = %e, "Failed to load update check settings"),
+        }
+    }
+}
+
+/// 检查间隔（小时），0 表示关闭自动检查
+pub(crate) async fn check_interval_hours() -> Result<i64> {
+    let value = load_property("update_check_interval".to_string()).await?;
+    Ok(value.parse::<i64>().unwrap_or(DEFAULT_CHECK_INTERVAL_HOURS))
+}
+
+pub(crate) async fn set_check_interval_hours(hours: i64) -> Result<()> {
+    save_property(
+        "update_check_interval".to_string(),
+        hours.max(0).to_string(),
+    )
+    .await
+}
+
+/// 已下载的小说出现新章节时是否自动加入下载
+pub(crate) async fn auto_download_updates() -> Result<bool> {
+    Ok(load_property("auto_download_updates".to_string()).await? == "true")
+}
+
+pub(crate) async fn set_auto_download_updates(enabled: bool) -> Result<()> {
+    save_property("auto_download_updates".to_string(), enabled.to_string()).await
+}
+
+async fn is_check_due() -> Result<bool> {
+    let interval = check_interval_hours().await?;
+    if interval <= 0 {
+        return Ok(false);
+    }
+    let last_check = load_property("update_check_time".to_string())
+        .await?
+        .parse::<i64>()
+        .unwrap_or(0);
+    Ok(chrono::Utc::now().timestamp() - last_check >= interval * 60 * 60)
+}
+
+/// 检查阅读历史、书架和下载中的小说，返回新章节数
+#[instrument(skip_all)]
+pub(crate) async fn check_updates() -> Result<i32> {
+    let _guard = CHECK_LOCK.lock().await;
+    let novels = followed_novels().await?;
+    let auto_download = auto_download_updates().await?;
+    let mut total = 0;
+    for (source_id, novel_id, novel_name) in novels {
+        match check_novel(&source_id, &novel_id, &novel_name, auto_download).await {
+            Ok(count) => total += count,
+            Err(e) => warn!(novel_id = %novel_id, error = %e, "Failed to check novel updates"),
+        }
+    }
+    NovelUpdateEntity::delete_old_records().await?;
+    save_property(
+        "update_check_time".to_string(),
+        chrono::Utc::now().timestamp().to_string(),
+    )
+    .await?;
+    Ok(total)
+}
+
+/// 返回 (书源ID, 小说ID, 小说名)
+async fn followed_novels() -> Result<Vec<(String, String, String)>> {
+    let mut seen = HashSet::new();
+    let mut novels = Vec::new();
+    for novel in novel_download::Entity::find_all().await? {
+        if novel.download_status != DOWNLOAD_STATUS_DELETING
+            && seen.insert(source_key(&novel.source_id, &novel.novel_id))
+        {
+            novels.push((novel.source_id, novel.novel_id, novel.novel_name));
+        }
+    }
+    for history in ReadingHistoryEntity::list_reading_history(None, 0, 100).await? {
+        if seen.insert(source_key(&history.source_id, &history.novel_id)) {
+            novels.push((history.source_id, history.novel_id, history.novel_name));
+        }
+    }
+    // 未登录时拿不到书架，只检查本地的小说
+    for source in all_sources() {
+        match source.bookshelf().await {
+            Ok(bookshelf) => {
+                for item in bookshelf {
+                    if seen.insert(source_key(source.id(), &item.novel.id)) {
+                        novels.push((source.id().to_string(), item.novel.id, item.novel.title));
+                    }
+                }
+            }
+            Err(e) => debug!(source_id = source.id(), error = %e, "Skip bookshelf in update check"),
+        }
+    }
+    Ok(novels)
+}
+
+async fn check_novel(
+    source_id: &str,
+    novel_id: &str,
+    novel_name: &str,
+    auto_download: bool,
+) -> Result<i32> {
+    let volumes = source(source_id)?.novel_reader(novel_id).await?;
+    let snapshot_id = source_key(source_id, novel_id);
+    let chapter_ids = volumes
+        .iter()
+        .flat_map(|volume| volume.chapters.iter().map(|chapter| chapter.cid.clone()))
+        .collect::<Vec<_>>();
+    if chapter_ids.is_empty() {
+        return Ok(0);
+    }
+    let known = match ChapterSnapshotEntity::find_chapter_ids(&snapshot_id).await? {
+        Some(known) => known.into_iter().collect::<HashSet<_>>(),
+        // 第一次检查：已下载的小说和下载记录比较，其他小说只记录目录
+        None => match downloaded_chapter_ids(source_id, novel_id, &chapter_ids).await? {
+            Some(known) => known,
+            None => {
+                ChapterSnapshotEntity::save_chapter_ids(&snapshot_id, &chapter_ids).await?;
+                return Ok(0);
+            }
+        },
+    };
+
+    let mut new_chapter_ids = Vec::new();
+    for volume in &volumes {
+        for chapter in &volume.chapters {
+            if known.contains(&chapter.cid) {
+                continue;
+            }
+            NovelUpdateEntity::insert_update(
+                source_id,
+                novel_id,
+                novel_name,
+                &volume.id,
+                &volume.title,
+                &chapter.cid,
+                &chapter.title,
+            )
+            .await?;
+            new_chapter_ids.push(chapter.cid.clone());
+        }
+    }
+
+    if auto_download && !new_chapter_ids.is_empty() {
+        for novel in downloaded_novels(source_id, novel_id).await? {
+            queue_new_chapters(&novel, &volumes, &new_chapter_ids).await?;
+        }
+    }
+
+    ChapterSnapshotEntity::save_chapter_ids(&snapshot_id, &chapter_ids).await?;
+    if !new_chapter_ids.is_empty() {
+        info!(novel_id = %novel_id, count = new_chapter_ids.len(), "Found new chapters");
+    }
+    Ok(new_chapter_ids.len() as i32)
+}
+
+/// 小说已下载的各个语言版本，删除中的除外
+async fn downloaded_novels(source_id: &str, novel_id: &str) -> Result<Vec<novel_download::Model>> {
+    let mut novels = Vec::new();
+    for language in Language::ALL {
+        if let Some(novel) =
+            novel_download::Entity::find_by_novel_id(source_id, language.code(), novel_id).await?
+        {
+            if novel.download_status != DOWNLOAD_STATUS_DELETING {
+                novels.push(novel);
+            }
+        }
+    }
+    Ok(novels)
+}
+
+/// 已下载小说的已知章节：目录中最后一个已下载章节及之前的章节都算已知，
+/// 只选了部分卷下载时，之前没选的章节不会被当成更新
+async fn downloaded_chapter_ids(
+    source_id: &str,
+    novel_id: &str,
+    chapter_ids: &[String],
+) -> Result<Option<HashSet<String>>> {
+    let mut downloaded = HashSet::new();
+    for novel in downloaded_novels(source_id, novel_id).await? {
+        downloaded.extend(
+            novel_download_chapter::Entity::find_by_novel_id(source_id, &novel.language, novel_id)
+                .await?
+                .into_iter()
+                .map(|chapter| chapter.id),
+        );
+    }
+    let Some(last) = chapter_ids.iter().rposition(|id| downloaded.contains(id)) else {
+        return Ok(None);
+    };
+    Ok(Some(
+        chapter_ids[..=last]
+            .iter()
+            .cloned()
+            .chain(downloaded)
+            .collect(),
+    ))
+}
+
+/// 把新章节加入已下载小说的下载队列
+async fn queue_new_chapters(
+    novel: &novel_download::Model,
+    volumes: &[Volume],
+    new_chapter_ids: &[String],
+) -> Result<()> {
+    let mut queued = 0;
+    for (volume_idx, volume) in volumes.iter().enumerate() {
+        let mut volume_queued = false;
+        for (chapter_idx, chapter) in volume.chapters.iter().enumerate() {
+            if !new_chapter_ids.contains(&chapter.cid)
+                || novel_download_chapter::Entity::find_by_id(
+                    &novel.source_id,
+                    &novel.language,
+                    &chapter.cid,
+                )
+                    .await?
+                    .is_some()
+            {
+                continue;
+            }
+            novel_download_chapter::Entity::upsert(
+                &novel.source_id,
+                &novel.language,
+                &chapter.cid,
+                &chapter.title,
+                &chapter.url,
+                &novel.novel_id,
+                &volume.id,
+                DOWNLOAD_STATUS_NOT_DOWNLOAD,
+                0,
+                chapter_idx as i32,
+            )
+            .await?;
+            volume_queued = true;
+            queued += 1;
+        }
+        if volume_queued {
+            novel_download_volume::Entity::upsert(
+                &novel.source_id,
+                &novel.language,
+                &volume.id,
+                &novel.novel_id,
+                volume_idx as i32,
+                &volume.title,
+                DOWNLOAD_STATUS_NOT_DOWNLOAD,
+            )
+            .await?;
+        }
+    }
+    if queued == 0 {
+        return Ok(());
+    }
+    novel_download::Entity::add_choose_chapter_count(
+        &novel.source_id,
+        &novel.language,
+        &novel.novel_id,
+        queued,
+    )
+    .await?;
+    // 暂停中的小说保持暂停
+    if novel.download_status == DOWNLOAD_STATUS_SUCCESS
+        || novel.download_status == DOWNLOAD_STATUS_FAILED
+    {
+        novel_download::Entity::update_status(
+            &novel.source_id,
+            &novel.language,
+            &novel.novel_id,
+            DOWNLOAD_STATUS_NOT_DOWNLOAD,
+        )
+        .await?;
+    }
+    info!(novel_id = %novel.novel_id, queued, "Queued new chapters for download");
+    Ok(())
+}
@@ -0,0 +1,216 @@
+//! 可选的账号密码保险箱，用于登录过期后自动重新登录
+//! 密码用 AES-256-GCM 加密后保存在 property 中，密钥由用户输入的口令或平台提供的密钥
+//! （例如保存在 Android Keystore 中的随机值）经 PBKDF2 派生，只保存在内存中
+
+use crate::api::database::{load_property, save_property};
+use crate::database::entities::AccountEntity;
+use crate::Result;
+use anyhow::anyhow;
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
+use once_cell::sync::Lazy;
+use ring::aead::{Aad, LessSafeKey, Nonce, UnboundKey, AES_256_GCM, NONCE_LEN};
+use ring::rand::{SecureRandom, SystemRandom};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::num::NonZeroU32;
+use tokio::sync::RwLock;
+
+const VAULT_PROPERTY: &str = "credential_vault";
+const PBKDF2_ITERATIONS: u32 = 100_000;
+/// 解锁时用来验证密钥是否正确
+const CHECK_TEXT: &[u8] = b"wild-credential-vault";
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VaultState {
+    pub enabled: bool,
+    pub unlocked: bool,
+}
+
+/// 保存的内容，除 salt 外都是 base64(nonce + 密文)
+#[derive(Default, Serialize, Deserialize)]
+struct VaultData {
+    salt: String,
+    check: String,
+    /// 整个 用户名 -> 密码 的表加密保存，不暴露保存了哪些用户
+    credentials: String,
+}
+
+type Credentials = HashMap<String, String>;
+
+static KEY: Lazy<RwLock<Option<[u8; 32]>>> = Lazy::new(|| RwLock::new(None));
+
+pub(crate) fn derive_key(secret: &str, salt: &[u8]) -> [u8; 32] {
+    let mut key = [0u8; 32];
+    ring::pbkdf2::derive(
+        ring::pbkdf2::PBKDF2_HMAC_SHA256,
+        NonZeroU32::new(PBKDF2_ITERATIONS).unwrap(),
+        salt,
+        secret.as_bytes(),
+        &mut key,
+    );
+    key
+}
+
+/// PBKDF2 很慢，放到阻塞线程中执行
+async fn derive_key_blocking(secret: &str, salt: Vec<u8>) -> Result<[u8; 32]> {
+    let secret = secret.to_string();
+    Ok(tokio::task::spawn_blocking(move || derive_key(&secret, &salt)).await?)
+}
+
+fn random_bytes<const N: usize>() -> Result<[u8; N]> {
+    let mut bytes = [0u8; N];
+    SystemRandom::new()
+        .fill(&mut bytes)
+        .map_err(|_| anyhow!("Failed to generate random bytes"))?;
+    Ok(bytes)
+}
+
+pub(crate) fn encrypt(key: &[u8; 32], plain: &[u8]) -> Result<String> {
+    let key = LessSafeKey::new(UnboundKey::new(&AES_256_GCM, key).unwrap());
+    let nonce = random_bytes::<NONCE_LEN>()?;
+    let mut data = plain.to_vec();
+    key.seal_in_place_append_tag(Nonce::assume_unique_for_key(nonce), Aad::empty(), &mut data)
+        .map_err(|_| anyhow!("Failed to encrypt"))?;
+    let mut result = nonce.to_vec();
+    result.append(&mut data);
+    Ok(BASE64_STANDARD.encode(result))
+}
+
+/// 密钥不正确或数据被修改时返回错误
+pub(crate) fn decrypt(key: &[u8; 32], text: &str) -> Result<Vec<u8>> {
+    let key = LessSafeKey::new(UnboundKey::new(&AES_256_GCM, key).unwrap());
+    let data = BASE64_STANDARD.decode(text)?;
+    if data.len() < NONCE_LEN {
+        return Err(anyhow!("Invalid vault data"));
+    }
+    let (nonce, data) = data.split_at(NONCE_LEN);
+    let nonce = Nonce::try_assume_unique_for_key(nonce).map_err(|_| anyhow!("Invalid nonce"))?;
+    let mut data = data.to_vec();
+    let plain = key
+        .open_in_place(nonce, Aad::empty(), &mut data)
+        .map_err(|_| anyhow!("Wrong vault secret"))?;
+    Ok(plain.to_vec())
+}
+
+async fn load_data() -> Result<Option<VaultData>> {
+    let value = load_property(VAULT_PROPERTY.to_string()).await?;
+    if value.is_empty() {
+        return Ok(None);
+    }
+    Ok(Some(serde_json::from_str(&value)?))
+}
+
+async fn save_data(data: &VaultData) -> Result<()> {
+    save_property(VAULT_PROPERTY.to_string(), serde_json::to_string(data)?).await
+}
+
+fn decrypt_credentials(key: &[u8; 32], data: &VaultData) -> Result<Credentials> {
+    Ok(serde_json::from_slice(&decrypt(key, &data.credentials)?)?)
+}
+
+fn encrypt_credentials(key: &[u8; 32], credentials: &Credentials) -> Result<String> {
+    encrypt(key, &serde_json::to_vec(credentials)?)
+}
+
+pub(crate) async fn vault_state() -> Result<VaultState> {
+    Ok(VaultState {
+        enabled: load_data().await?.is_some(),
+        unlocked: KEY.read().await.is_some(),
+    })
+}
+
+/// 开启保险箱并解锁，之后登录成功的账号密码会被保存
+pub(crate) async fn enable_vault(secret: &str) -> Result<()> {
+    if secret.is_empty() {
+        return Err(anyhow!("Vault secret can not be empty"));
+    }
+    if load_data().await?.is_some() {
+        return Err(anyhow!("Vault already enabled"));
+    }
+    let salt = random_bytes::<16>()?;
+    let key = derive_key_blocking(secret, salt.to_vec()).await?;
+    let data = VaultData {
+        salt: BASE64_STANDARD.encode(salt),
+        check: encrypt(&key, CHECK_TEXT)?,
+        credentials: encrypt_credentials(&key, &Credentials::new())?,
+    };
+    save_data(&data).await?;
+    *KEY.write().await = Some(key);
+    Ok(())
+}
+
+/// 口令不正确时返回错误，未开启时不做任何事
+/// 锁定期间删除的账号无法从加密的表中移除，解锁时一并清理
+pub(crate) async fn unlock_vault(secret: &str) -> Result<()> {
+    let Some(mut data) = load_data().await? else {
+        return Ok(());
+    };
+    let key = derive_key_blocking(secret, BASE64_STANDARD.decode(&data.salt)?).await?;
+    if decrypt(&key, &data.check)? != CHECK_TEXT {
+        return Err(anyhow!("Wrong vault secret"));
+    }
+    let mut credentials = decrypt_credentials(&key, &data)?;
+    let accounts = AccountEntity::list_all().await?;
+    let count = credentials.len();
+    credentials.retain(|username, _| accounts.iter().any(|a| &a.username == username));
+    if credentials.len() != count {
+        data.credentials = encrypt_credentials(&key, &credentials)?;
+        save_data(&data).await?;
+    }
+    *KEY.write().await = Some(key);
+    Ok(())
+}
+
+pub(crate) async fn lock_vault() {
+    *KEY.write().await = None;
+}
+
+/// 关闭保险箱，删除保存的所有密码
+pub(crate) async fn disable_vault() -> Result<()> {
+    save_property(VAULT_PROPERTY.to_string(), String::new()).await?;
+    lock_vault().await;
+    Ok(())
+}
+
+/// 保险箱已解锁时保存密码，否则不做任何事
+pub(crate) async fn remember(username: &str, password: &str) -> Result<()> {
+    let Some(key) = *KEY.read().await else {
+        return Ok(());
+    };
+    let Some(mut data) = load_data().await? else {
+        return Ok(());
+    };
+    let mut credentials = decrypt_credentials(&key, &data)?;
+    // 与账号表一致，用户名去掉首尾空白
+    credentials.insert(username.trim().to_string(), password.to_string());
+    data.credentials = encrypt_credentials(&key, &credentials)?;
+    save_data(&data).await
+}
+
+/// 保险箱锁定时不做任何事，下次解锁时清理
+pub(crate) async fn forget(username: &str) -> Result<()> {
+    let Some(key) = *KEY.read().await else {
+        return Ok(());
+    };
+    let Some(mut data) = load_data().await? else {
+        return Ok(());
+    };
+    let mut credentials = decrypt_credentials(&key, &data)?;
+    if credentials.remove(username).is_some() {
+        data.credentials = encrypt_credentials(&key, &credentials)?;
+        save_data(&data).await?;
+    }
+    Ok(())
+}
+
+/// 保险箱未解锁或没有保存这个用户时返回 None
+pub(crate) async fn password(username: &str) -> Result<Option<String>> {
+    let Some(key) = *KEY.read().await else {
+        return Ok(None);
+    };
+    let Some(data) = load_data().await? else {
+        return Ok(None);
+    };
+    Ok(decrypt_credentials(&key, &data)?.remove(username))
+}
@@ -0,0 +1,314 @@
+//! 文库 Android 客户端使用的接口 (android.php)
+//! 请求参数 base64 编码后放在 request 字段，返回 XML 或纯文本
+//! 不经过网页所在的域名，网页被 Cloudflare 拦截时通常仍然可以访问
+
+use super::client::Wenku8Client;
+use super::error::Wenku8Error;
+use super::models::*;
+use anyhow::{anyhow, Context, Result};
+use base64::Engine;
+use futures::{StreamExt, TryStreamExt};
+use roxmltree::{Document, Node};
+
+pub(crate) const APP_HOST: &str = "http://app.wenku8.com";
+const APP_VERSION: &str = "1.21";
+/// 搜索接口一次返回全部结果，按这个大小在本地分页
+const SEARCH_PAGE_SIZE: usize = 20;
+/// 查询列表条目简介时同时发出的请求数
+const APP_COVER_CONCURRENCY: usize = 4;
+
+/// 排行榜或搜索结果中的一项，带 info 的列表会有标题
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(crate) struct AppListItem {
+    pub aid: String,
+    pub title: String,
+}
+
+impl Wenku8Client {
+    /// 发送 android.php 请求，retry 为 false 时不会重复提交
+    pub(crate) async fn app_request(&self, request: &str, retry: bool) -> Result<String> {
+        let params = [
+            (
+                "request",
+                base64::prelude::BASE64_STANDARD.encode(request.as_bytes()),
+            ),
+            ("appver", APP_VERSION.to_string()),
+            ("timestamp", chrono::Utc::now().timestamp().to_string()),
+        ];
+        let request = self
+            .http()
+            .post(format!("{APP_HOST}/android.php"))
+            .header("User-Agent", self.load_user_agent().await)
+            .form(&params);
+        let response = if retry {
+            self.send(request).await?
+        } else {
+            self.send_once(request).await?
+        };
+        Self::read_text(response).await
+    }
+
+    pub async fn app_novel_info(&self, aid: &str) -> Result<NovelInfo> {
+        let t = self.load_language().await.app_t();
+        let xml = self
+            .app_request(&format!("action=book&do=meta&aid={aid}&t={t}"), true)
+            .await
+            .context("Failed to get novel info")?;
+        let mut novel_info = Self::parse_app_metadata(&xml)?;
+        novel_info.img_url = app_cover_url(aid);
+        novel_info.introduce = self
+            .app_request(&format!("action=book&do=intro&aid={aid}&t={t}"), true)
+            .await
+            .context("Failed to get novel intro")?
+            .trim()
+            .to_string();
+        Ok(novel_info)
+    }
+
+    pub async fn app_novel_reader(&self, aid: &str) -> Result<Vec<Volume>> {
+        let t = self.load_language().await.app_t();
+        let xml = self
+            .app_request(&format!("action=book&do=list&aid={aid}&t={t}"), true)
+            .await
+            .context("Failed to get novel reader")?;
+        Self::parse_app_index(&xml, aid, &self.load_api_host().await)
+    }
+
+    /// search_type: articlename author
+    pub async fn app_search(
+        &self,
+        search_type: &str,
+        search_key: &str,
+        page: i32,
+    ) -> Result<PageStats<NovelCover>> {
+        let t = self.load_language().await.app_t();
+        let search_key =
+            url::form_urlencoded::byte_serialize(search_key.as_bytes()).collect::<String>();
+        let xml = self
+            .app_request(
+                &format!("action=search&searchtype={search_type}&searchkey={search_key}&t={t}"),
+                true,
+            )
+            .await
+            .context("Failed to get search result")?;
+        let (_, items) = Self::parse_app_list(&xml)?;
+        let max_page = items.len().div_ceil(SEARCH_PAGE_SIZE).max(1) as i32;
+        let page = page.clamp(1, max_page);
+        let items = items
+            .into_iter()
+            .skip((page as usize - 1) * SEARCH_PAGE_SIZE)
+            .take(SEARCH_PAGE_SIZE)
+            .collect();
+        Ok(PageStats {
+            current_page: page,
+            max_page,
+            records: self.app_covers(items).await?,
+        })
+    }
+
+    /// sort 与网页排行榜相同，例如 allvisit、monthvote、lastupdate、postdate
+    pub async fn app_toplist(&self, sort: &str, page: i32) -> Result<PageStats<NovelCover>> {
+        let t = self.load_language().await.app_t();
+        let xml = self
+            .app_request(
+                &format!("action=novellist&sort={sort}&page={page}&t={t}"),
+                true,
+            )
+            .await
+            .context("Failed to get toplist")?;
+        let (max_page, items) = Self::parse_app_list(&xml)?;
+        Ok(PageStats {
+            current_page: page,
+            max_page,
+            records: self.app_covers(items).await?,
+        })
+    }
+
+    /// app 接口的首页由几个排行榜组成
+    pub async fn app_index(&self) -> Result<Vec<HomeBlock>> {
+        let mut blocks = vec![];
+        for (title, sort) in [
+            ("今日热门", "dayvisit"),
+            ("最近更新", "lastupdate"),
+            ("新书入库", "postdate"),
+        ] {
+            blocks.push(HomeBlock {
+                title: title.to_string(),
+                list: self.app_toplist(sort, 1).await?.records,
+            });
+        }
+        Ok(blocks)
+    }
+
+    pub async fn app_login(&self, username: &str, password: &str) -> Result<()> {
+        let username =
+            url::form_urlencoded::byte_serialize(username.as_bytes()).collect::<String>();
+        let password =
+            url::form_urlencoded::byte_serialize(password.as_bytes()).collect::<String>();
+        let code = self
+            .app_request(
+                &format!("action=login&username={username}&password={password}"),
+                false,
+            )
+            .await
+            .context("Login failed")?;
+        Self::parse_app_result(&code)
+    }
+
+    // 列表里没有标题的条目并发查询简介，结果保持列表顺序
+    async fn app_covers(&self, items: Vec<AppListItem>) -> Result<Vec<NovelCover>> {
+        let t = self.load_language().await.app_t();
+        let api_host = self.load_api_host().await;
+        futures::stream::iter(items)
+            .map(|item| {
+                let api_host = &api_host;
+                async move {
+                    let title = if item.title.is_empty() {
+                        let xml = self
+                            .app_request(
+                                &format!("action=book&do=info&aid={}&t={t}", item.aid),
+                                true,
+                            )
+                            .await?;
+                        Self::parse_app_metadata(&xml)?.title
+                    } else {
+                        item.title
+                    };
+                    Ok(NovelCover {
+                        title,
+                        img: app_cover_url(&item.aid),
+                        detail_url: format!("{}/book/{}.htm", api_host, item.aid),
+                        aid: item.aid,
+                    })
+                }
+            })
+            .buffered(APP_COVER_CONCURRENCY)
+            .try_collect()
+            .await
+    }
+
+    pub(crate) fn parse_app_metadata(xml: &str) -> Result<NovelInfo> {
+        let document = Document::parse(xml.trim())?;
+        let mut novel_info = NovelInfo::default();
+        for data in document
+            .root_element()
+            .children()
+            .filter(|n| n.has_tag_name("data"))
+        {
+            let value = data_value(&data);
+            match data.attribute("name").unwrap_or_default() {
+                "Title" => novel_info.title = value,
+                "Author" => novel_info.author = value,
+                "BookStatus" => novel_info.status = value,
+                "LastUpdate" => novel_info.fin_update = value,
+                "TotalHitsCount" => novel_info.heat = value,
+                "PushCount" => novel_info.trending = value,
+                "Tags" => {
+                    novel_info.tags = value
+                        .split_whitespace()
+                        .map(|tag| tag.to_string())
+                        .collect()
+                }
+                _ => {}
+            }
+        }
+        if novel_info.title.is_empty() {
+            return Err(Wenku8Error::NotFound.into());
+        }
+        Ok(novel_info)
+    }
+
+    pub(crate) fn parse_app_index(xml: &str, aid: &str, api_host: &str) -> Result<Vec<Volume>> {
+        let document = Document::parse(xml.trim())?;
+        let mut volumes = vec![];
+        for volume in document
+            .root_element()
+            .children()
+            .filter(|n| n.has_tag_name("volume"))
+        {
+            let chapters = volume
+                .children()
+                .filter(|n| n.has_tag_name("chapter"))
+                .map(|chapter| {
+                    let cid = chapter.attribute("cid").unwrap_or_default().to_string();
+                    Chapter {
+                        title: node_text(&chapter),
+                        url: format!("{api_host}/modules/article/reader.php?aid={aid}&cid={cid}"),
+                        cid,
+                        aid: aid.to_string(),
+                    }
+                })
+                .collect();
+            volumes.push(Volume {
+                id: volume
+                    .attribute("vid")
+                    .ok_or_else(|| anyhow!("Failed to find vid"))?
+                    .to_string(),
+                title: node_text(&volume),
+                chapters,
+            });
+        }
+        Ok(volumes)
+    }
+
+    /// 返回 (总页数, 条目)
+    pub(crate) fn parse_app_list(xml: &str) -> Result<(i32, Vec<AppListItem>)> {
+        let document = Document::parse(xml.trim())?;
+        let mut max_page = 1;
+        let mut items = vec![];
+        for node in document.root_element().children() {
+            if node.has_tag_name("page") {
+                max_page = node
+                    .attribute("num")
+                    .and_then(|num| num.parse().ok())
+                    .unwrap_or(1);
+            } else if node.has_tag_name("item") {
+                let title = node
+                    .children()
+                    .find(|n| n.has_tag_name("data") && n.attribute("name") == Some("Title"))
+                    .map(|n| data_value(&n))
+                    .unwrap_or_default();
+                items.push(AppListItem {
+                    aid: node.attribute("aid").unwrap_or_default().to_string(),
+                    title,
+                });
+            }
+        }
+        Ok((max_page, items))
+    }
+
+    /// 操作类接口返回一个数字，1 为成功
+    pub(crate) fn parse_app_result(text: &str) -> Result<()> {
+        let error = match text.trim() {
+            "1" => return Ok(()),
+            "2" => Wenku8Error::LoginFailed("用户名不存在".to_string()),
+            "3" => Wenku8Error::LoginFailed("密码错误".to_string()),
+            "4" => Wenku8Error::NotLoggedIn,
+            code => Wenku8Error::Server(format!("app code {code}")),
+        };
+        Err(error.into())
+    }
+}
+
+fn app_cover_url(aid: &str) -> String {
+    let group = aid.parse::<i64>().map(|aid| aid / 1000).unwrap_or(0);
+    format!("http://img.wenku8.com/image/{group}/{aid}/{aid}s.jpg")
+}
+
+// <data name="Author" value="..."/> 或 <data name="Title"><![CDATA[...]]></data>
+fn data_value(node: &Node) -> String {
+    match node.attribute("value") {
+        Some(value) => value.trim().to_string(),
+        None => node_text(node),
+    }
+}
+
+// 只取节点自身的文字，不包括子节点
+fn node_text(node: &Node) -> String {
+    node.children()
+        .filter(|n| n.is_text())
+        .filter_map(|n| n.text())
+        .collect::<String>()
+        .trim()
+        .to_string()
+}
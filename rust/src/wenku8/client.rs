@@ -1,7 +1,6 @@
 use super::models::*;
 use anyhow::{anyhow, Context, Result};
-use base64::Engine;
-use encoding_rs::GBK;
+use encoding_rs::{Encoding, BIG5, GBK};
 use rand::Rng;
 use regex::Regex;
 use reqwest::{
@@ -9,22 +8,143 @@ use reqwest::{
         HeaderMap, HeaderValue, ACCEPT, ACCEPT_LANGUAGE, CONNECTION, CONTENT_TYPE, REFERER,
         USER_AGENT,
     },
-    Client,
+    Client, RequestBuilder, Response,
 };
 use scraper::Node::Element;
 use scraper::{ElementRef, Html, Selector};
-use tokio::sync::RwLock;
+use tokio::sync::{broadcast, RwLock};
+
+use super::error::{
+    check_response, classify_page, error_reason, is_cf_challenge, is_success_page, page_message,
+    Wenku8Error,
+};
+use super::language::Language;
+use super::mirror::is_unreachable;
+use super::rate_limit::RateLimiter;
+use super::retry::{backoff_delay, is_retryable_error, retry_delay, MAX_RETRIES};
+use tracing::warn;
 
-const DEFAULT_API_HOST: &str = "https://www.wenku8.net";
-const APP_HOST: &str = "http://app.wenku8.com";
 
 pub struct Wenku8Client {
-    pub client: Client,
+    /// 修改代理后会整个替换，通过 http() 取得
+    pub client: std::sync::RwLock<Client>,
     pub user_agent: RwLock<String>,
     pub api_host: RwLock<String>,
+    pub mirror_hosts: RwLock<Vec<String>>,
+    pub language: RwLock<Language>,
+    pub host_changes: broadcast::Sender<String>,
+    pub text_limiter: RateLimiter,
+    pub image_limiter: RateLimiter,
 }
 
 impl Wenku8Client {
+    // 统一处理文库页面的响应：状态码、按请求的 charset 解码、错误页面
+    pub(super) async fn read_page(response: Response) -> Result<String> {
+        let response = check_response(response)?;
+        let big5 = response
+            .url()
+            .query_pairs()
+            .any(|(key, value)| key == "charset" && value == "big5");
+        // 登录过期时会被重定向到登录页
+        if response.url().path().ends_with("/login.php") {
+            return Err(Wenku8Error::NotLoggedIn.into());
+        }
+        let bytes = response.bytes().await.map_err(Wenku8Error::from)?;
+        // 挑战页面是 UTF-8 的，要在解码之前判断
+        if is_cf_challenge(&String::from_utf8_lossy(&bytes)) {
+            return Err(Wenku8Error::CloudflareChallenge.into());
+        }
+        let text = if big5 {
+            decode_big5(bytes)?
+        } else {
+            decode_gbk(bytes)?
+        };
+        if let Some(error) = classify_page(&text) {
+            return Err(error.into());
+        }
+        Ok(text)
+    }
+
+    // app 接口返回 UTF-8 文本，同样检查状态码和挑战页面
+    pub(super) async fn read_text(response: Response) -> Result<String> {
+        let response = check_response(response)?;
+        let text = response.text().await.map_err(Wenku8Error::from)?;
+        if is_cf_challenge(&text) {
+            return Err(Wenku8Error::CloudflareChallenge.into());
+        }
+        Ok(text)
+    }
+
+    /// 发送请求，网络错误或服务器暂时不可用时按退避重试
+    pub(super) async fn send(&self, request: RequestBuilder) -> Result<Response, Wenku8Error> {
+        self.execute(&self.text_limiter, request, true).await
+    }
+
+    /// 不能重复提交的请求（登录、签到等）只发送一次
+    pub(super) async fn send_once(&self, request: RequestBuilder) -> Result<Response, Wenku8Error> {
+        self.execute(&self.text_limiter, request, false).await
+    }
+
+    async fn send_image(&self, request: RequestBuilder) -> Result<Response, Wenku8Error> {
+        self.execute(&self.image_limiter, request, true).await
+    }
+
+    async fn execute(
+        &self,
+        limiter: &RateLimiter,
+        request: RequestBuilder,
+        retry: bool,
+    ) -> Result<Response, Wenku8Error> {
+        let max_retries = if retry { MAX_RETRIES } else { 0 };
+        let max_failovers = self.load_mirror_hosts().await.len().saturating_sub(1);
+        let (client, request) = request.build_split();
+        let mut request = request?;
+        let mut attempt = 0;
+        let mut failovers = 0;
+        loop {
+            // 请求体无法复制时只能发送一次
+            let Some(current) = request.try_clone() else {
+                limiter.acquire().await;
+                return Ok(client.execute(request).await?);
+            };
+            limiter.acquire().await;
+            let result = client.execute(current).await;
+            // 当前镜像不可用时换下一个镜像，不计入重试次数
+            if failovers < max_failovers
+                && is_unreachable(&result)
+                && self.failover(request.url_mut()).await
+            {
+                failovers += 1;
+                continue;
+            }
+            let delay = match result {
+                Ok(response) => match retry_delay(&response, attempt) {
+                    Some(delay) if attempt < max_retries => {
+                        warn!(status = %response.status(), attempt, ?delay, "Retrying request");
+                        delay
+                    }
+                    _ => return Ok(response),
+                },
+                Err(e) if attempt < max_retries && is_retryable_error(&e) => {
+                    let delay = backoff_delay(attempt);
+                    warn!(error = %e, attempt, ?delay, "Retrying request");
+                    delay
+                }
+                Err(e) => return Err(e.into()),
+            };
+            attempt += 1;
+            tokio::time::sleep(delay).await;
+        }
+    }
+
+    pub fn http(&self) -> Client {
+        self.client.read().unwrap().clone()
+    }
+
+    pub fn set_http(&self, client: Client) {
+        *self.client.write().unwrap() = client;
+    }
+
     pub async fn load_user_agent(&self) -> String {
         let user_agent = self.user_agent.read().await;
         user_agent.clone()
@@ -37,11 +157,11 @@ impl Wenku8Client {
 
     pub async fn load_api_host(&self) -> String {
         let api_host = self.api_host.read().await;
-        if api_host.is_empty() {
-            DEFAULT_API_HOST.to_string()
-        } else {
-            api_host.clone()
+        if !api_host.is_empty() {
+            return api_host.clone();
         }
+        drop(api_host);
+        self.load_mirror_hosts().await.remove(0)
     }
 
     pub async fn set_api_host(&self, api_host_value: String) {
@@ -83,11 +203,9 @@ impl Wenku8Client {
         let ua = self.load_user_agent().await;
         let headers = Self::default_headers_sync(&ua);
 
+        let request = self.http().get(url).headers(headers);
         let _ = self
-            .client
-            .get(url)
-            .headers(headers)
-            .send()
+            .send(request)
             .await
             .context("init_session: GET login.php failed")?;
         Ok(())
@@ -106,13 +224,8 @@ impl Wenku8Client {
         let headers = Self::default_headers_sync(&ua);
 
         // 3) 取驗證碼
-        let resp = self
-            .client
-            .get(url)
-            .headers(headers)
-            .send()
-            .await
-            .context("checkcode: GET failed")?;
+        let request = self.http().get(url).headers(headers);
+        let resp = self.send(request).await.context("checkcode: GET failed")?;
 
         let status = resp.status();
         let ct: String = resp
@@ -123,7 +236,7 @@ impl Wenku8Client {
             .to_string(); // <-- 這裡變成 String，不再借用 resp
 
         // 現在再讀取 body（會移動 resp）
-        let bytes = resp.bytes().await?.to_vec();
+        let bytes = resp.bytes().await.map_err(Wenku8Error::from)?.to_vec();
 
         // 4) 判斷是否為圖片
         if status.is_success() && ct.starts_with("image/") {
@@ -133,13 +246,14 @@ impl Wenku8Client {
         // 5) 若被 Cloudflare 擋，會回 text/html 的挑戰頁
         if ct.contains("text/html") {
             let snippet = String::from_utf8_lossy(&bytes);
-            let looks_cf = snippet.contains("__cf_chl_")
-                || snippet.contains("Just a moment")
-                || snippet.contains("Enable JavaScript and cookies");
-            if looks_cf {
-                return Err(anyhow!("cf_challenge")); // ← 前端可據此觸發 WebView2 fallback
+            if is_cf_challenge(&snippet) {
+                // ← 前端可據此觸發 WebView2 fallback
+                return Err(Wenku8Error::CloudflareChallenge.into());
             }
         }
+        if !status.is_success() {
+            return Err(Wenku8Error::from_status(status)).context("captcha_fetch_failed");
+        }
 
         Err(anyhow!(format!(
             "captcha_fetch_failed status={} content_type={}",
@@ -168,42 +282,44 @@ impl Wenku8Client {
             ),
         );
 
-        let resp = self
-            .client
-            .post(url)
-            .headers(headers)
-            .form(&params)
-            .send()
-            .await?;
+        let request = self.http().post(url).headers(headers).form(&params);
+        let resp = self.send_once(request).await?;
 
-        if !resp.status().is_success() {
-            return Err(anyhow!("Login failed: HTTP {}", resp.status()));
-        }
-
-        let body = decode_gbk(resp.bytes().await?)?;
+        let resp = check_response(resp).context("Login failed")?;
+        let body = decode_gbk(resp.bytes().await.map_err(Wenku8Error::from)?)?;
         if body.contains("登录成功") {
-            Ok(())
-        } else {
-            Err(anyhow!("Login failed: {}", body))
+            return Ok(());
         }
+        if is_cf_challenge(&body) {
+            return Err(Wenku8Error::CloudflareChallenge.into());
+        }
+        // 登录页的错误原因都当作登录失败，校验码错误单独区分
+        let error = match error_reason(&body) {
+            Some(reason) if reason.contains("校验码") => Wenku8Error::CaptchaWrong,
+            Some(reason) => Wenku8Error::LoginFailed(reason),
+            None => Wenku8Error::LoginFailed(String::new()),
+        };
+        Err(error.into())
     }
 
-    // /userdetail.php?charset=gbk
+    // /userdetail.php?charset={charset}
     pub async fn userdetail(&self) -> Result<UserDetail> {
-        let url = format!("{}/userdetail.php?charset=gbk", self.load_api_host().await);
-        let response = self
-            .client
+        let charset = self.load_language().await.charset();
+        let url = format!("{}/userdetail.php?charset={charset}", self.load_api_host().await);
+        let request = self
+            .http()
             .get(url)
-            .header("User-Agent", self.load_user_agent().await)
-            .send()
-            .await?;
-        if !response.status().is_success() {
-            return Err(anyhow!("Login failed: {}", response.status()));
+            .header("User-Agent", self.load_user_agent().await);
+        let response = self.send(request).await?;
+        let text = Self::read_page(response)
+            .await
+            .context("Failed to get user detail")?;
+        let user_detail = Self::parse_user_detail(text.as_str())?;
+        // 未登录时返回的是登录页面，解析不出用户ID
+        if user_detail.user_id.is_empty() {
+            return Err(Wenku8Error::NotLoggedIn.into());
         }
-
-        let text = response.bytes().await?;
-        let text = decode_gbk(text)?;
-        Self::parse_user_detail(text.as_str())
+        Ok(user_detail)
     }
 
     pub(crate) fn parse_user_detail(text: &str) -> Result<UserDetail> {
@@ -220,55 +336,63 @@ impl Wenku8Client {
                 if let Some(even) = even_select {
                     let title = odd.inner_html();
                     let value = even.inner_html();
-                    if title.trim().starts_with("用户ID：") {
+                    // 繁体页面的标签是繁体字
+                    let is_label = |labels: &[&str]| {
+                        labels.iter().any(|label| title.trim().starts_with(label))
+                    };
+                    if is_label(&["用户ID：", "用戶ID："]) {
                         user_detail.user_id = value.trim().to_string();
-                    } else if title.trim().starts_with("用户名：") {
+                    } else if is_label(&["用户名：", "用戶名："]) {
                         user_detail.username = value.trim().to_string();
-                    } else if title.trim().starts_with("昵称：") {
+                    } else if is_label(&["昵称：", "暱稱：", "昵稱："]) {
                         user_detail.nickname =
-                            value.trim().replace("(留空则用户名做昵称)", "").to_string();
-                    } else if title.trim().starts_with("等级：") {
+                            value
+                            .trim()
+                            .replace("(留空则用户名做昵称)", "")
+                            .replace("(留空則用戶名做暱稱)", "")
+                            .to_string();
+                    } else if is_label(&["等级：", "等級："]) {
                         user_detail.level = value.trim().to_string();
-                    } else if title.trim().starts_with("头衔：") {
+                    } else if is_label(&["头衔：", "頭銜："]) {
                         user_detail.title = value.trim().to_string();
-                    } else if title.trim().starts_with("性别：") {
+                    } else if is_label(&["性别：", "性別："]) {
                         user_detail.sex = value.trim().to_string();
-                    } else if title.trim().starts_with("Email：") {
+                    } else if is_label(&["Email："]) {
                         user_detail.email = regex::Regex::new("<a[^>]+>")?
                             .replace(value.trim(), "")
                             .replace("</a>", "")
                             .to_string();
-                    } else if title.trim().starts_with("QQ：") {
+                    } else if is_label(&["QQ："]) {
                         user_detail.qq = value.trim().to_string();
-                    } else if title.trim().starts_with("MSN：") {
+                    } else if is_label(&["MSN："]) {
                         user_detail.msn = regex::Regex::new("<a[^>]+>")?
                             .replace(value.trim(), "")
                             .replace("</a>", "")
                             .to_string();
-                    } else if title.trim().starts_with("网站：") {
+                    } else if is_label(&["网站：", "網站："]) {
                         user_detail.web = regex::Regex::new("<a[^>]+>")?
                             .replace(value.trim(), "")
                             .replace("</a>", "")
                             .to_string();
-                    } else if title.trim().starts_with("注册日期：") {
+                    } else if is_label(&["注册日期：", "註冊日期：", "注冊日期："]) {
                         user_detail.register_date = value.trim().to_string();
-                    } else if title.trim().starts_with("贡献值：") {
+                    } else if is_label(&["贡献值：", "貢獻值："]) {
                         user_detail.contribute_point = value.trim().to_string();
-                    } else if title.trim().starts_with("经验值：") {
+                    } else if is_label(&["经验值：", "經驗值："]) {
                         user_detail.experience_value = value.trim().to_string();
-                    } else if title.trim().starts_with("现有积分：") {
+                    } else if is_label(&["现有积分：", "現有積分："]) {
                         user_detail.holding_points = value.trim().to_string();
-                    } else if title.trim().starts_with("最多好友数：") {
+                    } else if is_label(&["最多好友数：", "最多好友數："]) {
                         user_detail.quantity_of_friends = value.trim().to_string();
-                    } else if title.trim().starts_with("信箱最多消息数：") {
+                    } else if is_label(&["信箱最多消息数：", "信箱最多消息數："]) {
                         user_detail.quantity_of_mail = value.trim().to_string();
-                    } else if title.trim().starts_with("书架最大收藏量：") {
+                    } else if is_label(&["书架最大收藏量：", "書架最大收藏量："]) {
                         user_detail.quantity_of_collection = value.trim().to_string();
-                    } else if title.trim().starts_with("每天允许推荐次数：") {
+                    } else if is_label(&["每天允许推荐次数：", "每天允許推薦次數："]) {
                         user_detail.quantity_of_recommend_daily = value.trim().to_string();
-                    } else if title.trim().starts_with("用户签名：") {
+                    } else if is_label(&["用户签名：", "用戶簽名："]) {
                         user_detail.personalized_signature = value.trim().to_string();
-                    } else if title.trim().starts_with("个人简介：") {
+                    } else if is_label(&["个人简介：", "個人簡介："]) {
                         user_detail.personalized_description = value.trim().to_string();
                     }
                 }
@@ -278,22 +402,19 @@ impl Wenku8Client {
     }
 
     pub async fn novel_info(&self, aid: &str) -> Result<NovelInfo> {
+        let charset = self.load_language().await.charset();
         let url = format!(
-            "{}/modules/article/articleinfo.php?id={aid}&charset=gbk",
+            "{}/modules/article/articleinfo.php?id={aid}&charset={charset}",
             self.load_api_host().await
         );
-        let response = self
-            .client
+        let request = self
+            .http()
             .get(url)
-            .header("User-Agent", self.load_user_agent().await)
-            .send()
-            .await?;
-        if !response.status().is_success() {
-            return Err(anyhow!("Failed to get novel info: {}", response.status()));
-        }
-
-        let text = response.bytes().await?;
-        let text = decode_gbk(text)?;
+            .header("User-Agent", self.load_user_agent().await);
+        let response = self.send(request).await?;
+        let text = Self::read_page(response)
+            .await
+            .context("Failed to get novel info")?;
         Self::parse_novel_info(text.as_str())
     }
 
@@ -438,22 +559,17 @@ impl Wenku8Client {
     }
 
     pub async fn index(&self) -> Result<Vec<HomeBlock>> {
-        let resp = self
-            .client
+        let charset = self.load_language().await.charset();
+        let request = self
+            .http()
             .get(format!(
-                "{}/index.php?charset=gbk",
+                "{}/index.php?charset={charset}",
                 self.load_api_host().await
             ))
-            .header("User-Agent", self.load_user_agent().await)
-            .send()
-            .await?;
+            .header("User-Agent", self.load_user_agent().await);
+        let resp = self.send(request).await?;
 
-        if !resp.status().is_success() {
-            return Err(anyhow!("Failed to get index: HTTP {}", resp.status()));
-        }
-
-        let text = resp.bytes().await?;
-        let text = decode_gbk(text)?;
+        let text = Self::read_page(resp).await.context("Failed to get index")?;
         Self::parse_index(text.as_str())
     }
 
@@ -496,10 +612,12 @@ impl Wenku8Client {
                 );
                 let block_title = block_title.text().collect::<String>();
                 println!("block_title: {}", block_title);
-                if "文库Telegram群组".eq(&block_title) {
+                if "文库Telegram群组".eq(&block_title) || "文庫Telegram群組".eq(&block_title) {
                     continue;
                 }
-                if block_title.starts_with("轻小说文库公告") {
+                if block_title.starts_with("轻小说文库公告")
+                    || block_title.starts_with("輕小說文庫公告")
+                {
                     continue;
                 }
                 let mut novel_covers = Vec::new();
@@ -613,22 +731,17 @@ impl Wenku8Client {
     }
 
     pub async fn tags(&self) -> Result<Vec<TagGroup>> {
-        let resp = self
-            .client
+        let charset = self.load_language().await.charset();
+        let request = self
+            .http()
             .get(format!(
-                "{}/modules/article/tags.php?charset=gbk",
+                "{}/modules/article/tags.php?charset={charset}",
                 self.load_api_host().await
             ))
-            .header("User-Agent", self.load_user_agent().await)
-            .send()
-            .await?;
-
-        if !resp.status().is_success() {
-            return Err(anyhow!("Failed to get tags: HTTP {}", resp.status()));
-        }
+            .header("User-Agent", self.load_user_agent().await);
+        let resp = self.send(request).await?;
 
-        let text = resp.bytes().await?;
-        let text = decode_gbk(text)?;
+        let text = Self::read_page(resp).await.context("Failed to get tags")?;
         Self::parse_tags(text.as_str())
     }
 
@@ -658,7 +771,9 @@ impl Wenku8Client {
                         .replace("Tags：", "")
                         .replace("系", "")
                         .replace("属性", "")
-                        .replace("类", "");
+                        .replace("屬性", "")
+                        .replace("类", "")
+                        .replace("類", "");
                     tags.clear();
                 } else {
                     let a = li.select(&a_selector);
@@ -682,25 +797,23 @@ impl Wenku8Client {
         v: &str,
         page_number: i32,
     ) -> Result<PageStats<NovelCover>> {
+        let language = self.load_language().await;
+        let charset = language.charset();
         let url = format!(
-            "{}/modules/article/tags.php?t={}&v={}&page={}&charset=gbk",
+            "{}/modules/article/tags.php?t={}&v={}&page={}&charset={charset}",
             self.load_api_host().await,
-            gbk_url_encode(tag),
+            charset_url_encode(tag, language),
             v,
             page_number,
         );
-        let response = self
-            .client
+        let request = self
+            .http()
             .get(url)
-            .header("User-Agent", self.load_user_agent().await)
-            .send()
-            .await?;
-        if !response.status().is_success() {
-            return Err(anyhow!("Failed to get tag page: {}", response.status()));
-        }
-
-        let text = response.bytes().await?;
-        let text = decode_gbk(text)?;
+            .header("User-Agent", self.load_user_agent().await);
+        let response = self.send(request).await?;
+        let text = Self::read_page(response)
+            .await
+            .context("Failed to get tag page")?;
         Self::parse_tag_page(text.as_str())
     }
 
@@ -782,17 +895,15 @@ impl Wenku8Client {
     }
 
     pub async fn get_bookshelf(&self) -> Result<Vec<BookshelfItem>> {
-        let resp = self
-            .client
-            .get("https://www.wenku8.net/modules/article/bookcase.php")
-            .send()
-            .await?;
-
-        if !resp.status().is_success() {
-            return Err(anyhow!("Failed to get bookshelf: HTTP {}", resp.status()));
-        }
+        let request = self.http().get(format!(
+            "{}/modules/article/bookcase.php",
+            self.load_api_host().await
+        ));
+        let resp = self.send(request).await?;
 
-        let body = decode_gbk(resp.bytes().await?)?;
+        let body = Self::read_page(resp)
+            .await
+            .context("Failed to get bookshelf")?;
         let document = Html::parse_document(&body);
 
         let mut items = Vec::new();
@@ -830,17 +941,13 @@ impl Wenku8Client {
     }
 
     pub async fn download_image(&self, url: &str) -> Result<Vec<u8>> {
-        let response = self.client.get(url)
+        let request = self.http().get(url)
             .header("User-Agent", "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/91.0.4472.124 Safari/537.36")
-            .header("Referer", "https://www.wenku8.net/")
-            .send()
-            .await?;
+            .header("Referer", "https://www.wenku8.net/");
+        let response = self.send_image(request).await?;
 
-        if !response.status().is_success() {
-            return Err(anyhow!("Failed to download image: {}", response.status()));
-        }
-
-        Ok(response.bytes().await?.to_vec())
+        let response = check_response(response).context("Failed to download image")?;
+        Ok(response.bytes().await.map_err(Wenku8Error::from)?.to_vec())
     }
 
     pub(crate) fn parse_reader(text: &str) -> Result<Vec<Volume>> {
@@ -910,67 +1017,55 @@ impl Wenku8Client {
     }
 
     pub async fn novel_reader(&self, aid: &str) -> Result<Vec<Volume>> {
+        let charset = self.load_language().await.charset();
         let url = format!(
-            "{}/modules/article/reader.php?aid={aid}&charset=gbk",
+            "{}/modules/article/reader.php?aid={aid}&charset={charset}",
             self.load_api_host().await
         );
-        let response = self
-            .client
+        let request = self
+            .http()
             .get(url)
-            .header("User-Agent", self.load_user_agent().await)
-            .send()
-            .await?;
-        if !response.status().is_success() {
-            return Err(anyhow!("Failed to get novel reader: {}", response.status()));
-        }
-
-        let text = response.bytes().await?;
-        let text = decode_gbk(text)?;
-        Self::parse_reader(text.as_str())
+            .header("User-Agent", self.load_user_agent().await);
+        let response = self.send(request).await?;
+        let text = Self::read_page(response)
+            .await
+            .context("Failed to get novel reader")?;
+        // 版权受限的小说没有目录，只有一段提示
+        Self::parse_reader(text.as_str()).map_err(|e| {
+            if text.contains("版权问题") || text.contains("版權問題") {
+                Wenku8Error::CopyrightRestricted.into()
+            } else {
+                e
+            }
+        })
     }
 
     pub async fn c_content(&self, aid: &str, cid: &str) -> Result<String> {
-        let url = format!("{APP_HOST}/android.php");
-        let params = [
-            (
-                "request",
-                base64::prelude::BASE64_STANDARD
-                    .encode(format!("action=book&do=text&aid={aid}&cid={cid}&t=0").as_bytes()),
-            ),
-            ("appver", "1.21".to_string()),
-            ("timestamp", chrono::Utc::now().timestamp().to_string()),
-        ];
-        let response = self
-            .client
-            .post(url)
-            .header("User-Agent", self.load_user_agent().await)
-            .form(&params)
-            .send()
-            .await?;
-        if !response.status().is_success() {
-            return Err(anyhow!("Failed to get novel reader: {}", response.status()));
-        }
-        let text = response.text().await?;
-        Ok(text)
+        self.c_content_in(aid, cid, self.load_language().await).await
+    }
+
+    /// 按指定的语言获取章节，下载时使用下载记录的语言
+    pub async fn c_content_in(&self, aid: &str, cid: &str, language: Language) -> Result<String> {
+        let t = language.app_t();
+        self.app_request(&format!("action=book&do=text&aid={aid}&cid={cid}&t={t}"), true)
+            .await
+            .context("Failed to get chapter content")
     }
 
     pub async fn toplist(&self, sort: &str, page: i32) -> Result<PageStats<NovelCover>> {
+        let charset = self.load_language().await.charset();
         let url = format!(
-            "{}/modules/article/toplist.php?sort={sort}&page={page}&charset=gbk",
+            "{}/modules/article/toplist.php?sort={sort}&page={page}&charset={charset}",
             self.load_api_host().await
         );
-        let response = self
-            .client
+        let request = self
+            .http()
             .get(url)
-            .header("User-Agent", self.load_user_agent().await)
-            .send()
-            .await?;
-        if !response.status().is_success() {
-            return Err(anyhow!("Failed to get toplist: {}", response.status()));
-        }
-
-        let text = response.bytes().await?;
-        let text = decode_gbk(text)?;
+            .header("User-Agent", self.load_user_agent().await);
+        let response = self.send(request).await?;
+        let text = Self::read_page(response)
+            .await
+            .context("Failed to get toplist")?;
         Self::parse_toplist(text.as_str())
     }
 
@@ -979,22 +1074,19 @@ impl Wenku8Client {
     }
 
     pub async fn articlelist(&self, fullflag: i32, page: i32) -> Result<PageStats<NovelCover>> {
+        let charset = self.load_language().await.charset();
         let url = format!(
-            "{}/modules/article/articlelist.php?fullflag={fullflag}&page={page}&charset=gbk",
+            "{}/modules/article/articlelist.php?fullflag={fullflag}&page={page}&charset={charset}",
             self.load_api_host().await
         );
-        let response = self
-            .client
+        let request = self
+            .http()
             .get(url)
-            .header("User-Agent", self.load_user_agent().await)
-            .send()
-            .await?;
-        if !response.status().is_success() {
-            return Err(anyhow!("Failed to get article list: {}", response.status()));
-        }
-
-        let text = response.bytes().await?;
-        let text = decode_gbk(text)?;
+            .header("User-Agent", self.load_user_agent().await);
+        let response = self.send(request).await?;
+        let text = Self::read_page(response)
+            .await
+            .context("Failed to get article list")?;
         Self::parse_articlelist(text.as_str())
     }
 
@@ -1003,49 +1095,67 @@ impl Wenku8Client {
     }
 
     pub async fn add_bookshelf(&self, aid: &str) -> Result<()> {
+        let charset = self.load_language().await.charset();
         let url = format!(
-            "{}/modules/article/addbookcase.php?bid={aid}&charset=gbk",
+            "{}/modules/article/addbookcase.php?bid={aid}&charset={charset}",
             self.load_api_host().await
         );
-        let response = self
-            .client
+        let request = self
+            .http()
             .get(url)
-            .header("User-Agent", self.load_user_agent().await)
-            .send()
-            .await?;
-        if !response.status().is_success() {
-            return Err(anyhow!("Failed to add bookshelf: {}", response.status()));
+            .header("User-Agent", self.load_user_agent().await);
+        let response = self.send(request).await?;
+        let text = Self::read_page(response)
+            .await
+            .context("Failed to add bookshelf")?;
+        if is_success_page(&text) {
+            Ok(())
+        } else {
+            Err(Wenku8Error::Server(page_message(&text)).into())
         }
+    }
+
+    /// 推荐小说，次数用完返回 Wenku8Error::VotesExhausted，未登录返回 Wenku8Error::NotLoggedIn
+    pub async fn recommend(&self, aid: &str) -> Result<()> {
+        let charset = self.load_language().await.charset();
+        let url = format!(
+            "{}/modules/article/uservote.php?id={aid}&charset={charset}",
+            self.load_api_host().await
+        );
+        let request = self
+            .http()
+            .get(url)
+            .header("User-Agent", self.load_user_agent().await);
+        let response = self.send_once(request).await?;
+        let text = Self::read_page(response)
+            .await
+            .context("Failed to recommend")?;
+        Self::parse_recommend(text.as_str())
+    }
 
-        let text = response.bytes().await?;
-        let text = decode_gbk(text)?;
-        if text.contains("处理成功") {
+    /// 错误页面已经在 read_page 中分类，这里只看提示页面是否为处理成功
+    pub(crate) fn parse_recommend(text: &str) -> Result<()> {
+        if is_success_page(text) {
             Ok(())
         } else {
-            Err(anyhow!("Failed to add bookshelf: {}", text))
+            Err(Wenku8Error::Server(page_message(text)).into())
         }
     }
 
     pub async fn bookcase_list(&self) -> Result<Vec<Bookcase>> {
+        let charset = self.load_language().await.charset();
         let url = format!(
-            "{}/modules/article/bookcase.php?charset=gbk",
+            "{}/modules/article/bookcase.php?charset={charset}",
             self.load_api_host().await
         );
-        let response = self
-            .client
+        let request = self
+            .http()
             .get(url)
-            .header("User-Agent", self.load_user_agent().await)
-            .send()
-            .await?;
-        if !response.status().is_success() {
-            return Err(anyhow!(
-                "Failed to get bookcase list: {}",
-                response.status()
-            ));
-        }
-
-        let text = response.bytes().await?;
-        let text = decode_gbk(text)?;
+            .header("User-Agent", self.load_user_agent().await);
+        let response = self.send(request).await?;
+        let text = Self::read_page(response)
+            .await
+            .context("Failed to get bookcase list")?;
         Self::parse_bookcase_list(text.as_str())
     }
 
@@ -1068,22 +1178,19 @@ impl Wenku8Client {
     }
 
     pub async fn book_in_case(&self, case_id: &str) -> Result<BookcaseDto> {
+        let charset = self.load_language().await.charset();
         let url = format!(
-            "{}/modules/article/bookcase.php?classid={case_id}&charset=gbk",
+            "{}/modules/article/bookcase.php?classid={case_id}&charset={charset}",
             self.load_api_host().await
         );
-        let response = self
-            .client
+        let request = self
+            .http()
             .get(url)
-            .header("User-Agent", self.load_user_agent().await)
-            .send()
-            .await?;
-        if !response.status().is_success() {
-            return Err(anyhow!("Failed to get book in case: {}", response.status()));
-        }
-
-        let text = response.bytes().await?;
-        let text = decode_gbk(text)?;
+            .header("User-Agent", self.load_user_agent().await);
+        let response = self.send(request).await?;
+        let text = Self::read_page(response)
+            .await
+            .context("Failed to get book in case")?;
         Self::parse_book_in_case(text.as_str())
     }
 
@@ -1189,7 +1296,7 @@ impl Wenku8Client {
         let mut tip: &str = "";
 
         // 您的书架可收藏 300 本，已收藏 7 本 regex
-        let re = Regex::new(r"您的书架可收藏 (\d+) 本，已收藏 (\d+) 本").unwrap();
+        let re = Regex::new(r"您的[书書]架可收藏 (\d+) 本，已收藏 (\d+) 本").unwrap();
         if let Some(caps) = re.captures(text) {
             tip = caps.get(0).unwrap().as_str();
         }
@@ -1201,28 +1308,20 @@ impl Wenku8Client {
     }
 
     pub async fn delete_bookcase(&self, delid: &str) -> Result<()> {
+        let charset = self.load_language().await.charset();
         let url = format!(
-            "{}/modules/article/bookcase.php?delid={delid}&charset=gbk",
+            "{}/modules/article/bookcase.php?delid={delid}&charset={charset}",
             self.load_api_host().await
         );
-        let response = self
-            .client
+        let request = self
+            .http()
             .get(url)
-            .header("User-Agent", self.load_user_agent().await)
-            .send()
-            .await?;
-        if !response.status().is_success() {
-            return Err(anyhow!("Failed to delete bookcase: {}", response.status()));
-        }
-
-        let code = response.status();
-        let text = response.bytes().await?;
-        let text = decode_gbk(text)?;
-        if code.is_success() {
-            Ok(())
-        } else {
-            Err(anyhow!("Failed to delete bookcase: {}", text))
-        }
+            .header("User-Agent", self.load_user_agent().await);
+        let response = self.send(request).await?;
+        Self::read_page(response)
+            .await
+            .context("Failed to delete bookcase")?;
+        Ok(())
     }
 
     pub async fn move_bookcase(
@@ -1244,19 +1343,15 @@ impl Wenku8Client {
         params.push(("newclassid", new_classid));
         params.push(("classid", old_classid));
 
-        let response = self
-            .client
+        let request = self
+            .http()
             .post(url)
             .header("User-Agent", self.load_user_agent().await)
-            .form(&params)
-            .send()
-            .await?;
-        let status = response.status();
-        let text = response.bytes().await?;
-        let text = decode_gbk(text)?;
-        if !status.is_success() {
-            return Err(anyhow!("Failed to move bookcase: {}", status));
-        }
+            .form(&params);
+        let response = self.send_once(request).await?;
+        Self::read_page(response)
+            .await
+            .context("Failed to move bookcase")?;
         Ok(())
     }
 
@@ -1267,25 +1362,20 @@ impl Wenku8Client {
         search_key: &str,
         page: i32,
     ) -> Result<PageStats<NovelCover>> {
-        let search_key = gbk_url_encode(search_key);
+        let language = self.load_language().await;
+        let search_key = charset_url_encode(search_key, language);
+        let charset = language.charset();
         let url = format!(
-            "{}/modules/article/search.php?searchtype={search_type}&searchkey={search_key}&page={page}&charset=gbk",self.load_api_host().await
+            "{}/modules/article/search.php?searchtype={search_type}&searchkey={search_key}&page={page}&charset={charset}",self.load_api_host().await
         );
-        let response = self
-            .client
+        let request = self
+            .http()
             .get(url)
-            .header("User-Agent", self.load_user_agent().await)
-            .send()
-            .await?;
-        if !response.status().is_success() {
-            return Err(anyhow!(
-                "Failed to get search result: {}",
-                response.status()
-            ));
-        }
-
-        let text = response.bytes().await?;
-        let text = decode_gbk(text)?;
+            .header("User-Agent", self.load_user_agent().await);
+        let response = self.send(request).await?;
+        let text = Self::read_page(response)
+            .await
+            .context("Failed to get search result")?;
         Self::parse_search(text.as_str())
     }
 
@@ -1294,52 +1384,26 @@ impl Wenku8Client {
     }
 
     pub async fn sign(&self) -> Result<String> {
-        let url = format!("{APP_HOST}/android.php");
-        let params = [
-            (
-                "request",
-                base64::prelude::BASE64_STANDARD.encode(format!("action=block&do=sign").as_bytes()),
-            ),
-            ("appver", "1.21".to_string()),
-            ("timestamp", chrono::Utc::now().timestamp().to_string()),
-        ];
-        let response = self
-            .client
-            .post(url)
-            .header("User-Agent", self.load_user_agent().await)
-            .form(&params)
-            .send()
-            .await?;
-        if !response.status().is_success() {
-            return Err(anyhow!("Failed to get novel reader: {}", response.status()));
-        }
-        let text = response.text().await?;
-        Ok(text)
+        self.app_request("action=block&do=sign", false)
+            .await
+            .context("Failed to sign")
     }
 
     pub async fn reviews(&self, aid: &str, page_number: i32) -> Result<PageStats<Review>> {
+        let charset = self.load_language().await.charset();
         let url = format!(
-            "{}/modules/article/reviews.php?aid={aid}&page={page_number}&charset=gbk",
+            "{}/modules/article/reviews.php?aid={aid}&page={page_number}&charset={charset}",
             self.load_api_host().await
         );
-        let response = self
-            .client
+        let request = self
+            .http()
             .get(url)
-            .header("User-Agent", self.load_user_agent().await)
-            .send()
-            .await?;
-        if !response.status().is_success() {
-            return Err(anyhow!("Failed to delete bookcase: {}", response.status()));
-        }
-
-        let code = response.status();
-        let text = response.bytes().await?;
-        let text = decode_gbk(text)?;
-        if code.is_success() {
-            Self::parse_reviews(text.as_str())
-        } else {
-            Err(anyhow!("Failed to load reviews: {}", text))
-        }
+            .header("User-Agent", self.load_user_agent().await);
+        let response = self.send(request).await?;
+        let text = Self::read_page(response)
+            .await
+            .context("Failed to load reviews")?;
+        Self::parse_reviews(text.as_str())
     }
 
     pub fn parse_reviews(text: &str) -> Result<PageStats<Review>> {
@@ -1411,17 +1475,44 @@ impl Wenku8Client {
     }
 }
 
-fn decode_gbk(bytes: bytes::Bytes) -> Result<String> {
+fn decode_gbk(bytes: bytes::Bytes) -> Result<String, Wenku8Error> {
     let (cow, _, had_errors) = GBK.decode(&bytes);
     if had_errors {
-        Err(anyhow!("Failed to decode GBK"))
+        Err(Wenku8Error::Decode)
+    } else {
+        Ok(cow.into_owned())
+    }
+}
+
+fn decode_big5(bytes: bytes::Bytes) -> Result<String, Wenku8Error> {
+    let (cow, _, had_errors) = BIG5.decode(&bytes);
+    if had_errors {
+        Err(Wenku8Error::Decode)
     } else {
         Ok(cow.into_owned())
     }
 }
 
 fn gbk_url_encode(text: &str) -> String {
-    let gbk_bytes = GBK
+    charset_url_encode(text, Language::Simplified)
+}
+
+/// 文库的表单按 GBK 提交，reqwest 的 form 只能用 UTF-8，需要自己拼接
+pub(super) fn gbk_form(params: &[(&str, &str)]) -> String {
+    params
+        .iter()
+        .map(|(key, value)| format!("{}={}", key, gbk_url_encode(value)))
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+// 搜索等参数要用页面的编码，繁体为 Big5
+fn charset_url_encode(text: &str, language: Language) -> String {
+    let encoding: &'static Encoding = match language {
+        Language::Simplified => GBK,
+        Language::Traditional => BIG5,
+    };
+    let gbk_bytes = encoding
         .encode(text)
         .0
         .into_iter()
@@ -0,0 +1,247 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
+use reqwest::{header::SERVER, Response, StatusCode};
+use scraper::{Html, Selector};
+
+static TITLE_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"<title>([^<]*)</title>").unwrap());
+static CONTENT_SELECTOR: Lazy<Selector> = Lazy::new(|| Selector::parse("#content").unwrap());
+
+// Cloudflare 挑战和拦截页面的标题
+const CF_TITLES: &[&str] = &[
+    "Just a moment",
+    "Attention Required! | Cloudflare",
+    "请稍候…",
+];
+
+/// 文库请求的错误分类，Display 的文字是固定的错误码，前端据此判断
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum Wenku8Error {
+    #[error("cf_challenge")]
+    CloudflareChallenge,
+    #[error("not_logged_in")]
+    NotLoggedIn,
+    #[error("captcha_wrong")]
+    CaptchaWrong,
+    /// 自动重新登录时服务器要求输入校验码
+    #[error("captcha_required")]
+    CaptchaRequired,
+    #[error("login_failed: {0}")]
+    LoginFailed(String),
+    #[error("rate_limited")]
+    RateLimited,
+    #[error("not_found")]
+    NotFound,
+    #[error("copyright_restricted")]
+    CopyrightRestricted,
+    /// 没有权限或帖子已锁定
+    #[error("permission_denied")]
+    PermissionDenied,
+    /// 今天的推荐次数已经用完
+    #[error("votes_exhausted")]
+    VotesExhausted,
+    #[error("decode_failed")]
+    Decode,
+    #[error("network: {0}")]
+    Network(String),
+    #[error("http_status: {0}")]
+    Http(u16),
+    /// 文库错误页面上的其他提示
+    #[error("server_message: {0}")]
+    Server(String),
+}
+
+impl From<reqwest::Error> for Wenku8Error {
+    fn from(e: reqwest::Error) -> Self {
+        match e.status() {
+            Some(status) => Self::from_status(status),
+            None => Wenku8Error::Network(e.to_string()),
+        }
+    }
+}
+
+impl Wenku8Error {
+    /// 从错误链中找到 Wenku8Error
+    pub fn of(err: &anyhow::Error) -> Option<Wenku8Error> {
+        err.chain().find_map(|e| {
+            if let Some(e) = e.downcast_ref::<Wenku8Error>() {
+                Some(e.clone())
+            } else {
+                e.downcast_ref::<reqwest::Error>()
+                    .map(|e| Wenku8Error::Network(e.to_string()))
+            }
+        })
+    }
+
+    /// 从错误信息的文字还原，用于前端拿到的异常信息
+    pub fn parse(message: &str) -> Option<Wenku8Error> {
+        message
+            .lines()
+            .find_map(|line| Self::parse_line(line.trim()))
+    }
+
+    fn parse_line(line: &str) -> Option<Wenku8Error> {
+        let error = match line {
+            "cf_challenge" => Wenku8Error::CloudflareChallenge,
+            "not_logged_in" => Wenku8Error::NotLoggedIn,
+            "captcha_wrong" => Wenku8Error::CaptchaWrong,
+            "captcha_required" => Wenku8Error::CaptchaRequired,
+            "rate_limited" => Wenku8Error::RateLimited,
+            "not_found" => Wenku8Error::NotFound,
+            "copyright_restricted" => Wenku8Error::CopyrightRestricted,
+            "permission_denied" => Wenku8Error::PermissionDenied,
+            "votes_exhausted" => Wenku8Error::VotesExhausted,
+            "decode_failed" => Wenku8Error::Decode,
+            _ => {
+                let (code, value) = line.split_once(": ")?;
+                match code {
+                    "login_failed" => Wenku8Error::LoginFailed(value.to_string()),
+                    "network" => Wenku8Error::Network(value.to_string()),
+                    "http_status" => Wenku8Error::Http(value.parse().ok()?),
+                    "server_message" => Wenku8Error::Server(value.to_string()),
+                    _ => return None,
+                }
+            }
+        };
+        Some(error)
+    }
+
+    pub(crate) fn from_status(status: StatusCode) -> Wenku8Error {
+        match status {
+            StatusCode::NOT_FOUND => Wenku8Error::NotFound,
+            StatusCode::TOO_MANY_REQUESTS => Wenku8Error::RateLimited,
+            _ => Wenku8Error::Http(status.as_u16()),
+        }
+    }
+}
+
+/// 检查响应状态，Cloudflare 拦截时返回 CloudflareChallenge
+pub(crate) fn check_response(response: Response) -> Result<Response, Wenku8Error> {
+    let status = response.status();
+    if status.is_success() {
+        return Ok(response);
+    }
+    if is_cloudflare_block(&response) {
+        return Err(Wenku8Error::CloudflareChallenge);
+    }
+    Err(Wenku8Error::from_status(status))
+}
+
+/// 响应是否为 Cloudflare 的拦截页面
+pub(crate) fn is_cloudflare_block(response: &Response) -> bool {
+    let status = response.status();
+    let headers = response.headers();
+    let mitigated = headers.contains_key("cf-mitigated");
+    let cloudflare = headers
+        .get(SERVER)
+        .and_then(|v| v.to_str().ok())
+        .map(|v| v.eq_ignore_ascii_case("cloudflare"))
+        .unwrap_or(false);
+    mitigated
+        || (cloudflare
+            && (status == StatusCode::FORBIDDEN || status == StatusCode::SERVICE_UNAVAILABLE))
+}
+
+/// 页面的 <title>，只看标题不会被正文里的文字误判
+pub(crate) fn page_title(text: &str) -> Option<&str> {
+    TITLE_REGEX
+        .captures(text)
+        .and_then(|caps| caps.get(1))
+        .map(|title| title.as_str().trim())
+}
+
+/// 文库提示页面 #content 中的文字，每个文本节点一行，去掉空行
+pub(crate) fn content_lines(text: &str) -> Vec<String> {
+    let document = Html::parse_document(text);
+    let Some(content) = document.select(&CONTENT_SELECTOR).next() else {
+        return vec![];
+    };
+    content
+        .text()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// 文库提示页面（处理成功、出现错误等）上的提示文字，没有 #content 时用标题
+pub(crate) fn page_message(text: &str) -> String {
+    let lines = content_lines(text);
+    if lines.is_empty() {
+        return page_title(text).unwrap_or_default().to_string();
+    }
+    lines.join(" ")
+}
+
+/// 是否为 "处理成功" 提示页面，繁体页面为 "處理成功"
+pub(crate) fn is_success_page(text: &str) -> bool {
+    let is_success = |text: &str| text.contains("处理成功") || text.contains("處理成功");
+    page_title(text).map(is_success).unwrap_or(false)
+        || content_lines(text).iter().any(|line| is_success(line))
+}
+
+pub(crate) fn is_cf_challenge(text: &str) -> bool {
+    page_title(text)
+        .map(|title| CF_TITLES.iter().any(|word| title.starts_with(word)))
+        .unwrap_or(false)
+}
+
+/// 文库 "出现错误！" 页面上的错误原因，繁体页面为 "出現錯誤！"
+/// 标题不是错误页面时返回 None，原因只从 #content 的错误块中读取
+pub(crate) fn error_reason(text: &str) -> Option<String> {
+    let title = page_title(text)?;
+    if !title.starts_with("出现错误") && !title.starts_with("出現錯誤") {
+        return None;
+    }
+    let reason = content_lines(text)
+        .into_iter()
+        .find_map(|line| {
+            line.split_once("错误原因：")
+                .or_else(|| line.split_once("錯誤原因："))
+                .map(|(_, reason)| reason.trim().to_string())
+        })
+        .unwrap_or_default();
+    Some(reason)
+}
+
+/// 登录过期后需要登录的页面会变成登录页，标题为 "用户登录"
+/// 其他页面侧边栏的登录框不算
+pub(crate) fn is_login_page(text: &str) -> bool {
+    page_title(text)
+        .map(|title| {
+            ["用户登录", "用戶登錄", "用戶登入"]
+                .iter()
+                .any(|word| title.starts_with(word))
+        })
+        .unwrap_or(false)
+}
+
+/// 按页面内容分类，正常页面返回 None
+pub(crate) fn classify_page(text: &str) -> Option<Wenku8Error> {
+    if is_cf_challenge(text) {
+        return Some(Wenku8Error::CloudflareChallenge);
+    }
+    if is_login_page(text) {
+        return Some(Wenku8Error::NotLoggedIn);
+    }
+    let reason = error_reason(text)?;
+    let contains_any = |words: &[&str]| words.iter().any(|word| reason.contains(word));
+    let error = if contains_any(&["校验码", "校驗碼"]) {
+        Wenku8Error::CaptchaWrong
+    } else if contains_any(&["登录", "登陆", "登錄", "登陸"]) {
+        Wenku8Error::NotLoggedIn
+    } else if contains_any(&["版权", "版權"]) {
+        Wenku8Error::CopyrightRestricted
+    } else if reason.contains("不存在") {
+        Wenku8Error::NotFound
+    } else if contains_any(&["推荐", "推薦"]) && contains_any(&["用完", "最多", "超过", "超過"])
+    {
+        Wenku8Error::VotesExhausted
+    } else if contains_any(&["频繁", "頻繁", "间隔", "間隔", "太快"]) {
+        Wenku8Error::RateLimited
+    } else if contains_any(&["权限", "權限", "锁定", "鎖定", "禁止"]) {
+        Wenku8Error::PermissionDenied
+    } else {
+        Wenku8Error::Server(reason)
+    };
+    Some(error)
+}
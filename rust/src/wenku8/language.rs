@@ -0,0 +1,71 @@
+use serde::{Deserialize, Serialize};
+
+use super::client::Wenku8Client;
+
+/// 内容语言，繁体使用网页的 charset=big5 和 app 接口的 t=1
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Language {
+    #[default]
+    Simplified,
+    Traditional,
+}
+
+impl Language {
+    pub const ALL: [Language; 2] = [Language::Simplified, Language::Traditional];
+
+    /// 保存在 property 和下载记录中的值
+    pub fn code(self) -> &'static str {
+        match self {
+            Language::Simplified => "zh-Hans",
+            Language::Traditional => "zh-Hant",
+        }
+    }
+
+    /// 无法识别的值当作简体
+    pub fn from_code(code: &str) -> Language {
+        match code.trim() {
+            "zh-Hant" => Language::Traditional,
+            _ => Language::Simplified,
+        }
+    }
+
+    /// 网页的 charset 参数
+    pub fn charset(self) -> &'static str {
+        match self {
+            Language::Simplified => "gbk",
+            Language::Traditional => "big5",
+        }
+    }
+
+    /// app 接口的 t 参数
+    pub fn app_t(self) -> i32 {
+        match self {
+            Language::Simplified => 0,
+            Language::Traditional => 1,
+        }
+    }
+
+    /// 缓存键加上语言后缀，简体保持原样以兼容旧缓存
+    pub fn cache_key(self, key: &str) -> String {
+        match self {
+            Language::Simplified => key.to_string(),
+            Language::Traditional => format!("{key}${}", self.charset()),
+        }
+    }
+}
+
+impl Wenku8Client {
+    pub async fn load_language(&self) -> Language {
+        *self.language.read().await
+    }
+
+    pub async fn set_language(&self, language: Language) {
+        let mut current = self.language.write().await;
+        *current = language;
+    }
+
+    /// 按当前语言生成缓存键
+    pub async fn language_key(&self, key: &str) -> String {
+        self.load_language().await.cache_key(key)
+    }
+}
@@ -0,0 +1,219 @@
+//! 站内短消息 (message.php / messagedetail.php / newmessage.php)
+
+use super::client::{gbk_form, Wenku8Client};
+use super::models::*;
+use anyhow::{anyhow, Context, Result};
+use regex::Regex;
+use reqwest::header::{CONTENT_TYPE, REFERER};
+use scraper::{ElementRef, Html, Node, Selector};
+
+/// 收件箱
+pub const INBOX: &str = "inbox";
+/// 发件箱
+pub const OUTBOX: &str = "outbox";
+
+impl Wenku8Client {
+    /// box_name 为 inbox 或 outbox
+    pub async fn messages(&self, box_name: &str, page: i32) -> Result<PageStats<Message>> {
+        let charset = self.load_language().await.charset();
+        let url = format!(
+            "{}/message.php?box={}&page={page}&charset={charset}",
+            self.load_api_host().await,
+            check_box(box_name)?,
+        );
+        let request = self
+            .http()
+            .get(url)
+            .header("User-Agent", self.load_user_agent().await);
+        let response = self.send(request).await?;
+        let text = Self::read_page(response)
+            .await
+            .context("Failed to load messages")?;
+        Self::parse_messages(text.as_str())
+    }
+
+    pub async fn message_detail(&self, id: &str) -> Result<MessageDetail> {
+        let charset = self.load_language().await.charset();
+        let url = format!(
+            "{}/messagedetail.php?id={id}&charset={charset}",
+            self.load_api_host().await
+        );
+        let request = self
+            .http()
+            .get(url)
+            .header("User-Agent", self.load_user_agent().await);
+        let response = self.send(request).await?;
+        let text = Self::read_page(response)
+            .await
+            .context("Failed to load message")?;
+        let mut detail = Self::parse_message_detail(text.as_str())?;
+        detail.id = id.to_string();
+        Ok(detail)
+    }
+
+    /// 发送短消息给 receiver（用户名）
+    pub async fn send_message(&self, receiver: &str, title: &str, content: &str) -> Result<()> {
+        if receiver.trim().is_empty() || title.trim().is_empty() || content.trim().is_empty() {
+            return Err(anyhow!("Receiver, title and content can not be empty"));
+        }
+        let url = format!("{}/newmessage.php", self.load_api_host().await);
+        let form = gbk_form(&[
+            ("receiver", receiver.trim()),
+            ("title", title.trim()),
+            ("content", content.trim()),
+            ("action", "newmessage"),
+        ]);
+        let request = self
+            .http()
+            .post(&url)
+            .header("User-Agent", self.load_user_agent().await)
+            .header(REFERER, &url)
+            .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+            .body(form);
+        let response = self.send_once(request).await?;
+        Self::read_page(response)
+            .await
+            .context("Failed to send message")?;
+        Ok(())
+    }
+
+    pub async fn delete_messages(&self, box_name: &str, ids: &[String]) -> Result<()> {
+        let box_name = check_box(box_name)?;
+        let charset = self.load_language().await.charset();
+        for id in ids {
+            let url = format!(
+                "{}/message.php?box={box_name}&delid={id}&charset={charset}",
+                self.load_api_host().await
+            );
+            let request = self
+                .http()
+                .get(url)
+                .header("User-Agent", self.load_user_agent().await);
+            let response = self.send_once(request).await?;
+            Self::read_page(response)
+                .await
+                .context("Failed to delete message")?;
+        }
+        Ok(())
+    }
+
+    /// 列表的每一行有消息链接 messagedetail.php?id= 和对方的 userpage.php?uid=
+    /// 未读的消息标题加粗
+    pub(crate) fn parse_messages(text: &str) -> Result<PageStats<Message>> {
+        let html = Html::parse_document(text);
+        let row_selector = Selector::parse("#content tr").unwrap();
+        let message_selector = Selector::parse(r#"a[href*="messagedetail.php"]"#).unwrap();
+        let user_selector = Selector::parse(r#"a[href*="uid="]"#).unwrap();
+        let bold_selector = Selector::parse("b, strong").unwrap();
+        let time_regex =
+            Regex::new(r"\d{4}-\d{1,2}-\d{1,2}(?: \d{1,2}:\d{2}(?::\d{2})?)?").unwrap();
+
+        let mut messages = vec![];
+        for row in html.select(&row_selector) {
+            let Some(link) = row.select(&message_selector).next() else {
+                continue;
+            };
+            let mut message = Message {
+                id: query_value(link.attr("href").unwrap_or_default(), "id"),
+                title: link.text().collect::<String>().trim().to_string(),
+                unread: link.select(&bold_selector).next().is_some()
+                    || link
+                        .parent()
+                        .and_then(ElementRef::wrap)
+                        .map(|p| matches!(p.value().name(), "b" | "strong"))
+                        .unwrap_or(false),
+                ..Default::default()
+            };
+            if let Some(user) = row.select(&user_selector).next() {
+                message.user_id = query_value(user.attr("href").unwrap_or_default(), "uid");
+                message.username = user.text().collect::<String>().trim().to_string();
+            }
+            if let Some(time) = time_regex.find(&row.text().collect::<String>()) {
+                message.time = time.as_str().to_string();
+            }
+            messages.push(message);
+        }
+        let (current_page, max_page) = Self::parse_page_stats(&html)?;
+        Ok(PageStats {
+            current_page,
+            max_page,
+            records: messages,
+        })
+    }
+
+    /// 详情是 "标签：值" 的表格，最后一行是正文
+    pub(crate) fn parse_message_detail(text: &str) -> Result<MessageDetail> {
+        let html = Html::parse_document(text);
+        let row_selector = Selector::parse("#content tr").unwrap();
+        let cell_selector = Selector::parse("td, th").unwrap();
+
+        let mut detail = MessageDetail::default();
+        let mut found = false;
+        for row in html.select(&row_selector) {
+            let cells = row.select(&cell_selector).collect::<Vec<_>>();
+            let (label, value) = match cells.as_slice() {
+                [label, value, ..] => (cell_text(label), multiline_text(value)),
+                [cell] => match cell_text(cell).split_once('：') {
+                    Some((label, _)) => (label.to_string(), multiline_text(cell)),
+                    None => continue,
+                },
+                [] => continue,
+            };
+            // 单列时值里带着标签
+            let value = match value.split_once('：') {
+                Some((prefix, rest)) if label.starts_with(prefix.trim()) => rest.trim().to_string(),
+                _ => value,
+            };
+            let label = label.trim_end_matches('：');
+            match label {
+                "标题" | "消息标题" | "標題" | "消息標題" => detail.title = value,
+                "发件人" | "發件人" => detail.sender = value,
+                "收件人" => detail.receiver = value,
+                "时间" | "发送时间" | "日期" | "時間" | "發送時間" => detail.time = value,
+                "内容" | "消息内容" | "內容" | "消息內容" => detail.content = value,
+                _ => continue,
+            }
+            found = true;
+        }
+        if !found {
+            return Err(anyhow!("Failed to find message"));
+        }
+        Ok(detail)
+    }
+}
+
+fn check_box(box_name: &str) -> Result<&str> {
+    match box_name {
+        INBOX | OUTBOX => Ok(box_name),
+        _ => Err(anyhow!("Unknown message box: {}", box_name)),
+    }
+}
+
+fn query_value(href: &str, key: &str) -> String {
+    href.split(['?', '&'])
+        .find_map(|pair| pair.strip_prefix(&format!("{key}=")))
+        .unwrap_or_default()
+        .to_string()
+}
+
+fn cell_text(cell: &ElementRef) -> String {
+    cell.text().collect::<String>().trim().to_string()
+}
+
+// <br> 换行，其余标签只保留文字
+fn multiline_text(cell: &ElementRef) -> String {
+    let mut text = String::new();
+    for node in cell.descendants() {
+        match node.value() {
+            Node::Text(t) => text.push_str(t),
+            Node::Element(e) if e.name() == "br" => text.push('\n'),
+            _ => {}
+        }
+    }
+    text.lines()
+        .map(|line| line.trim())
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim()
+        .to_string()
+}
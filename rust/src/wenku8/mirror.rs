@@ -0,0 +1,159 @@
+use reqwest::Url;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use tokio::sync::broadcast;
+use tokio::time::Instant;
+use tracing::{info, warn};
+
+use super::client::Wenku8Client;
+use super::error::{check_response, is_cloudflare_block};
+
+/// 默认的镜像列表，第一个为主站
+pub const DEFAULT_MIRROR_HOSTS: &[&str] = &["https://www.wenku8.net", "https://www.wenku8.cc"];
+
+const PROBE_TIMEOUT: Duration = Duration::from_secs(8);
+
+/// 镜像测速结果，latency_ms 在不可用时为 -1
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MirrorProbe {
+    pub host: String,
+    pub reachable: bool,
+    pub latency_ms: i64,
+    pub error: String,
+}
+
+/// 统一镜像地址的格式：补全协议，去掉结尾的斜杠
+pub fn normalize_host(host: &str) -> String {
+    let host = host.trim().trim_end_matches('/');
+    if host.is_empty() || host.contains("://") {
+        host.to_string()
+    } else {
+        format!("https://{host}")
+    }
+}
+
+fn same_origin(url: &Url, host: &str) -> bool {
+    match Url::parse(host) {
+        Ok(host) => url.origin() == host.origin(),
+        Err(_) => false,
+    }
+}
+
+/// 把请求地址换到另一个镜像，路径和参数不变
+fn replace_origin(url: &mut Url, host: &str) -> bool {
+    let Ok(host) = Url::parse(host) else {
+        return false;
+    };
+    url.set_scheme(host.scheme()).is_ok()
+        && url.set_host(host.host_str()).is_ok()
+        && url.set_port(host.port()).is_ok()
+}
+
+/// 连接失败或被 Cloudflare 拦截时需要换镜像
+pub(crate) fn is_unreachable(result: &Result<reqwest::Response, reqwest::Error>) -> bool {
+    match result {
+        Ok(response) => is_cloudflare_block(response),
+        Err(e) => e.is_connect() || e.is_timeout(),
+    }
+}
+
+impl Wenku8Client {
+    pub async fn load_mirror_hosts(&self) -> Vec<String> {
+        let mirror_hosts = self.mirror_hosts.read().await;
+        if mirror_hosts.is_empty() {
+            DEFAULT_MIRROR_HOSTS.iter().map(|h| h.to_string()).collect()
+        } else {
+            mirror_hosts.clone()
+        }
+    }
+
+    pub async fn set_mirror_hosts(&self, hosts: Vec<String>) {
+        let mut mirror_hosts = self.mirror_hosts.write().await;
+        *mirror_hosts = hosts;
+    }
+
+    /// 当前使用的镜像变化时通知
+    pub fn subscribe_host_changes(&self) -> broadcast::Receiver<String> {
+        self.host_changes.subscribe()
+    }
+
+    /// 请求的地址是当前镜像时切换到下一个镜像，并把请求改到新镜像上
+    /// 没有其他镜像可用时返回 false
+    pub(crate) async fn failover(&self, url: &mut Url) -> bool {
+        let mirror_hosts = self.load_mirror_hosts().await;
+        let current = self.load_api_host().await;
+        if !same_origin(url, &current) {
+            // 其他请求已经切换过了，直接用新的镜像
+            return !current.is_empty()
+                && mirror_hosts.iter().any(|h| same_origin(url, h))
+                && replace_origin(url, &current);
+        }
+        let next = match mirror_hosts.iter().position(|h| h == &current) {
+            Some(position) => mirror_hosts.get((position + 1) % mirror_hosts.len()),
+            None => mirror_hosts.first(),
+        };
+        let Some(next) = next.filter(|next| *next != &current) else {
+            return false;
+        };
+        warn!(from = %current, to = %next, "Mirror unreachable, switching");
+        self.set_api_host(next.clone()).await;
+        let _ = self.host_changes.send(next.clone());
+        replace_origin(url, next)
+    }
+
+    /// 测试镜像是否可用以及响应时间
+    pub async fn probe_mirror(&self, host: &str) -> MirrorProbe {
+        let host = normalize_host(host);
+        let start = Instant::now();
+        let result = self
+            .http()
+            .get(format!("{host}/index.php?charset=gbk"))
+            .header("User-Agent", self.load_user_agent().await)
+            .timeout(PROBE_TIMEOUT)
+            .send()
+            .await;
+        let latency_ms = start.elapsed().as_millis() as i64;
+        let error = match result {
+            Ok(response) => match check_response(response) {
+                Ok(_) => None,
+                Err(e) => Some(e.to_string()),
+            },
+            Err(e) => Some(e.to_string()),
+        };
+        match error {
+            None => MirrorProbe {
+                host,
+                reachable: true,
+                latency_ms,
+                error: String::new(),
+            },
+            Some(error) => MirrorProbe {
+                host,
+                reachable: false,
+                latency_ms: -1,
+                error,
+            },
+        }
+    }
+
+    pub async fn probe_mirrors(&self) -> Vec<MirrorProbe> {
+        let hosts = self.load_mirror_hosts().await;
+        futures::future::join_all(hosts.iter().map(|host| self.probe_mirror(host))).await
+    }
+
+    /// 测速后切换到最快的可用镜像，没有可用镜像时返回 None
+    pub async fn select_fastest_mirror(&self) -> Option<String> {
+        let fastest = self
+            .probe_mirrors()
+            .await
+            .into_iter()
+            .filter(|probe| probe.reachable)
+            .min_by_key(|probe| probe.latency_ms)?;
+        if fastest.host != self.load_api_host().await {
+            info!(host = %fastest.host, latency_ms = fastest.latency_ms, "Switching to fastest mirror");
+            self.set_api_host(fastest.host.clone()).await;
+            let _ = self.host_changes.send(fastest.host.clone());
+        }
+        Some(fastest.host)
+    }
+}
@@ -1,5 +1,17 @@
+pub mod app_api;
 pub mod client;
+pub mod error;
+pub mod language;
+pub mod message;
+pub mod mirror;
 pub mod models;
+pub mod rate_limit;
+pub mod retry;
+pub mod review;
+pub mod source;
 
 pub use client::*;
-pub use models::*; 
\ No newline at end of file
+pub use error::Wenku8Error;
+pub use language::Language;
+pub use mirror::MirrorProbe;
+pub use models::*;
@@ -151,3 +151,94 @@ pub struct Review {
     pub uname: String,
     pub time: String,
 }
+
+/// 书评帖子的一页
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub struct ReviewDetail {
+    pub rid: String,
+    pub title: String,
+    pub current_page: i32,
+    pub max_page: i32,
+    pub posts: Vec<ReviewPost>,
+}
+
+/// 书评中的一楼
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub struct ReviewPost {
+    pub uid: String,
+    pub uname: String,
+    pub time: String,
+    pub floor: i32,
+    pub blocks: Vec<ReviewBlock>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ReviewBlockKind {
+    Text,
+    Quote,
+    Image,
+    Link,
+}
+
+/// 帖子正文按块拆分，不返回 HTML；Text 和 Quote 只有 text，Image 只有 url，Link 两者都有
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReviewBlock {
+    pub kind: ReviewBlockKind,
+    pub text: String,
+    pub url: String,
+}
+
+impl ReviewBlock {
+    pub(crate) fn text(text: String) -> Self {
+        Self {
+            kind: ReviewBlockKind::Text,
+            text,
+            url: String::new(),
+        }
+    }
+
+    pub(crate) fn quote(text: String) -> Self {
+        Self {
+            kind: ReviewBlockKind::Quote,
+            text,
+            url: String::new(),
+        }
+    }
+
+    pub(crate) fn image(url: String) -> Self {
+        Self {
+            kind: ReviewBlockKind::Image,
+            text: String::new(),
+            url,
+        }
+    }
+
+    pub(crate) fn link(text: String, url: String) -> Self {
+        Self {
+            kind: ReviewBlockKind::Link,
+            text,
+            url,
+        }
+    }
+}
+
+/// 收件箱或发件箱中的一条消息，user 为对方（收件箱是发件人，发件箱是收件人）
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub struct Message {
+    pub id: String,
+    pub title: String,
+    pub user_id: String,
+    pub username: String,
+    pub time: String,
+    pub unread: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub struct MessageDetail {
+    pub id: String,
+    pub title: String,
+    pub sender: String,
+    pub receiver: String,
+    pub time: String,
+    pub content: String,
+}
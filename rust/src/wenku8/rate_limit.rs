@@ -0,0 +1,83 @@
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tokio::time::Instant;
+
+/// 允许的最低限速（每秒请求数），再低就等于停止请求
+pub const MIN_RATE: f64 = 0.01;
+
+// 单次等待的上限，限速改小后最多等这么久就重新计算
+const MAX_WAIT: Duration = Duration::from_secs(100);
+
+/// 令牌桶限速器，所有请求共享
+/// rate 为每秒允许的请求数，小于等于 0 时不限速
+pub struct RateLimiter {
+    bucket: Mutex<Bucket>,
+}
+
+struct Bucket {
+    rate: f64,
+    capacity: f64,
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl Bucket {
+    fn refill(&mut self) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.last_refill).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.rate).min(self.capacity);
+        self.last_refill = now;
+    }
+}
+
+impl RateLimiter {
+    pub fn new(rate: f64) -> Self {
+        let capacity = Self::capacity_of(rate);
+        RateLimiter {
+            bucket: Mutex::new(Bucket {
+                rate,
+                capacity,
+                tokens: capacity,
+                last_refill: Instant::now(),
+            }),
+        }
+    }
+
+    // 突发容量取每秒请求数，至少为 1
+    fn capacity_of(rate: f64) -> f64 {
+        rate.ceil().max(1.0)
+    }
+
+    pub async fn rate(&self) -> f64 {
+        self.bucket.lock().await.rate
+    }
+
+    pub async fn set_rate(&self, rate: f64) {
+        let mut bucket = self.bucket.lock().await;
+        bucket.refill();
+        bucket.rate = rate;
+        bucket.capacity = Self::capacity_of(rate);
+        bucket.tokens = bucket.tokens.min(bucket.capacity);
+    }
+
+    /// 取得一个令牌，令牌不足时等待
+    pub async fn acquire(&self) {
+        loop {
+            let wait = {
+                let mut bucket = self.bucket.lock().await;
+                if bucket.rate <= 0.0 {
+                    return;
+                }
+                bucket.refill();
+                if bucket.tokens >= 1.0 {
+                    bucket.tokens -= 1.0;
+                    return;
+                }
+                (1.0 - bucket.tokens) / bucket.rate
+            };
+            let wait =
+                Duration::try_from_secs_f64(wait).map_or(MAX_WAIT, |wait| wait.min(MAX_WAIT));
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use rand::Rng;
+use reqwest::{header::RETRY_AFTER, Response, StatusCode};
+use std::time::Duration;
+
+use super::error::is_cloudflare_block;
+
+/// 最多重试次数（不含第一次请求）
+pub const MAX_RETRIES: u32 = 3;
+const BASE_DELAY_MS: u64 = 500;
+const MAX_DELAY_MS: u64 = 8_000;
+/// Retry-After 超过这个时间就不再等待，直接返回错误
+const MAX_RETRY_AFTER: Duration = Duration::from_secs(60);
+
+/// 第 attempt 次重试前等待的时间，指数退避加随机抖动
+pub fn backoff_delay(attempt: u32) -> Duration {
+    let delay = BASE_DELAY_MS
+        .saturating_mul(1 << attempt.min(16))
+        .min(MAX_DELAY_MS);
+    let jitter = rand::rng().random_range(0..=delay / 2);
+    Duration::from_millis(delay + jitter)
+}
+
+/// 网络层的错误（超时、连接失败）可以重试
+pub fn is_retryable_error(e: &reqwest::Error) -> bool {
+    e.is_timeout() || e.is_connect() || e.is_request()
+}
+
+/// 需要重试时返回等待时间，否则返回 None
+pub fn retry_delay(response: &Response, attempt: u32) -> Option<Duration> {
+    let status = response.status();
+    let retryable = matches!(
+        status,
+        StatusCode::REQUEST_TIMEOUT
+            | StatusCode::TOO_MANY_REQUESTS
+            | StatusCode::INTERNAL_SERVER_ERROR
+            | StatusCode::BAD_GATEWAY
+            | StatusCode::SERVICE_UNAVAILABLE
+            | StatusCode::GATEWAY_TIMEOUT
+    );
+    // Cloudflare 挑战重试也过不去
+    if !retryable || is_cloudflare_block(response) {
+        return None;
+    }
+    match retry_after(response) {
+        Some(delay) if delay > MAX_RETRY_AFTER => None,
+        Some(delay) => Some(delay),
+        None => Some(backoff_delay(attempt)),
+    }
+}
+
+/// 解析 Retry-After，支持秒数和 HTTP 日期两种格式
+pub fn retry_after(response: &Response) -> Option<Duration> {
+    let value = response.headers().get(RETRY_AFTER)?.to_str().ok()?.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = chrono::DateTime::parse_from_rfc2822(value).ok()?;
+    let seconds = (date.timestamp() - chrono::Utc::now().timestamp()).max(0);
+    Some(Duration::from_secs(seconds as u64))
+}
@@ -0,0 +1,286 @@
+//! 书评帖子 (reviewshow.php)
+
+use super::client::{gbk_form, Wenku8Client};
+use super::models::*;
+use anyhow::{anyhow, Context, Result};
+use ego_tree::NodeRef;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use reqwest::header::{CONTENT_TYPE, REFERER};
+use scraper::{ElementRef, Html, Node, Selector};
+
+static IMG_SELECTOR: Lazy<Selector> = Lazy::new(|| Selector::parse("img").unwrap());
+
+impl Wenku8Client {
+    pub async fn review_detail(&self, rid: &str, page: i32) -> Result<ReviewDetail> {
+        let charset = self.load_language().await.charset();
+        let url = format!(
+            "{}/modules/article/reviewshow.php?rid={rid}&page={page}&charset={charset}",
+            self.load_api_host().await
+        );
+        let request = self
+            .http()
+            .get(url)
+            .header("User-Agent", self.load_user_agent().await);
+        let response = self.send(request).await?;
+        let text = Self::read_page(response)
+            .await
+            .context("Failed to load review detail")?;
+        let mut detail = Self::parse_review_detail(text.as_str())?;
+        detail.rid = rid.to_string();
+        Ok(detail)
+    }
+
+    /// 发表新书评
+    pub async fn post_review(&self, aid: &str, title: &str, content: &str) -> Result<()> {
+        if title.trim().is_empty() || content.trim().is_empty() {
+            return Err(anyhow!("Review title and content can not be empty"));
+        }
+        let url = format!(
+            "{}/modules/article/reviews.php?aid={aid}",
+            self.load_api_host().await
+        );
+        let form = gbk_form(&[
+            ("ptitle", title.trim()),
+            ("pcontent", content.trim()),
+            ("action", "newpost"),
+        ]);
+        self.submit_review(url, form)
+            .await
+            .context("Failed to post review")
+    }
+
+    /// 回复书评
+    pub async fn reply_review(&self, rid: &str, content: &str) -> Result<()> {
+        if content.trim().is_empty() {
+            return Err(anyhow!("Reply content can not be empty"));
+        }
+        let url = format!(
+            "{}/modules/article/reviewshow.php?rid={rid}",
+            self.load_api_host().await
+        );
+        let form = gbk_form(&[("pcontent", content.trim()), ("action", "newpost")]);
+        self.submit_review(url, form)
+            .await
+            .context("Failed to reply review")
+    }
+
+    // 发帖不能重复提交；发帖太快、没有权限、未登录都会返回错误页面，由 read_page 分类
+    async fn submit_review(&self, url: String, form: String) -> Result<()> {
+        let request = self
+            .http()
+            .post(&url)
+            .header("User-Agent", self.load_user_agent().await)
+            .header(REFERER, &url)
+            .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+            .body(form);
+        let response = self.send_once(request).await?;
+        Self::read_page(response).await?;
+        Ok(())
+    }
+
+    /// 每一楼是一个两列的表格：左边是用户，右边是时间、楼层和正文，正文在 <hr> 之后
+    pub(crate) fn parse_review_detail(text: &str) -> Result<ReviewDetail> {
+        let html = Html::parse_document(text);
+        let content_selector = Selector::parse("#content").unwrap();
+        let table_selector = Selector::parse("table").unwrap();
+        let user_selector = Selector::parse(r#"a[href*="uid="]"#).unwrap();
+        let time_regex = Regex::new(r"\d{4}-\d{1,2}-\d{1,2} \d{1,2}:\d{2}(?::\d{2})?").unwrap();
+        let floor_regex = Regex::new(r"(\d+)\s*[楼樓]").unwrap();
+
+        let content = html
+            .select(&content_selector)
+            .next()
+            .ok_or_else(|| anyhow!("Failed to find content"))?;
+        let mut detail = ReviewDetail::default();
+        // 正文里可能也有表格，只处理帖子本身的表格和行
+        let nested = |table: &ElementRef| {
+            table
+                .ancestors()
+                .filter_map(ElementRef::wrap)
+                .take_while(|e| e.id() != content.id())
+                .any(|e| e.value().name() == "table")
+        };
+        for table in content.select(&table_selector) {
+            if nested(&table) {
+                continue;
+            }
+            for row in table_rows(table) {
+                let tds = child_elements(row, "td").collect::<Vec<_>>();
+                if detail.title.is_empty() {
+                    if let Some(title) = review_title(&row) {
+                        detail.title = title;
+                        continue;
+                    }
+                }
+                if tds.len() != 2 {
+                    continue;
+                }
+                let Some(user) = tds[0].select(&user_selector).next() else {
+                    continue;
+                };
+                let mut post = ReviewPost {
+                    uid: user
+                        .attr("href")
+                        .and_then(|href| href.split("uid=").nth(1))
+                        .unwrap_or_default()
+                        .to_string(),
+                    uname: user.text().collect::<String>().trim().to_string(),
+                    ..Default::default()
+                };
+                // 楼层信息和正文用 <hr> 分开，没有 <hr> 时整个单元格都当作正文
+                let body = tds[1];
+                let hr = body
+                    .children()
+                    .position(|node| matches!(node.value(), Node::Element(e) if e.name() == "hr"));
+                let header = body
+                    .children()
+                    .take(hr.unwrap_or(0))
+                    .filter_map(ElementRef::wrap)
+                    .flat_map(|e| e.text())
+                    .collect::<String>();
+                let header = if header.is_empty() {
+                    body.text().collect::<String>()
+                } else {
+                    header
+                };
+                if let Some(time) = time_regex.find(&header) {
+                    post.time = time.as_str().to_string();
+                }
+                if let Some(caps) = floor_regex.captures(&header) {
+                    post.floor = caps[1].parse().unwrap_or_default();
+                }
+                let mut parser = BlockParser::default();
+                for node in body.children().skip(hr.map(|i| i + 1).unwrap_or(0)) {
+                    parser.node(node);
+                }
+                post.blocks = parser.finish();
+                detail.posts.push(post);
+            }
+        }
+        let (current_page, max_page) = Self::parse_page_stats(&html)?;
+        detail.current_page = current_page;
+        detail.max_page = max_page;
+        Ok(detail)
+    }
+}
+
+// 表格自己的行，浏览器解析时会补上 <tbody>
+fn table_rows(table: ElementRef) -> impl Iterator<Item = ElementRef> {
+    child_elements(table, "tr").chain(
+        table
+            .children()
+            .filter_map(ElementRef::wrap)
+            .filter(|e| matches!(e.value().name(), "thead" | "tbody" | "tfoot"))
+            .flat_map(|section| child_elements(section, "tr")),
+    )
+}
+
+fn child_elements<'a>(
+    element: ElementRef<'a>,
+    name: &'static str,
+) -> impl Iterator<Item = ElementRef<'a>> {
+    element
+        .children()
+        .filter_map(ElementRef::wrap)
+        .filter(move |e| e.value().name() == name)
+}
+
+// 标题行只有一个单元格，以 "主题：" 开头
+fn review_title(row: &ElementRef) -> Option<String> {
+    let text = row.text().collect::<String>();
+    let text = text.trim();
+    let title = text
+        .strip_prefix("主题：")
+        .or_else(|| text.strip_prefix("主題："))?;
+    Some(title.trim().to_string())
+}
+
+/// 把正文的 HTML 节点转换为 ReviewBlock，相邻的文字合并为一段
+#[derive(Default)]
+struct BlockParser {
+    blocks: Vec<ReviewBlock>,
+    text: String,
+}
+
+impl BlockParser {
+    fn node(&mut self, node: NodeRef<Node>) {
+        match node.value() {
+            Node::Text(text) => self.text.push_str(text),
+            Node::Element(element) => {
+                let element_ref = ElementRef::wrap(node).unwrap();
+                match element.name() {
+                    "br" => self.flush(),
+                    "img" => {
+                        if let Some(src) = element.attr("src") {
+                            self.flush();
+                            self.blocks.push(ReviewBlock::image(src.to_string()));
+                        }
+                    }
+                    "a" if element_ref.select(&IMG_SELECTOR).next().is_some() => {
+                        for child in node.children() {
+                            self.node(child);
+                        }
+                    }
+                    "a" => {
+                        let text = element_ref.text().collect::<String>().trim().to_string();
+                        match element.attr("href") {
+                            Some(href) if !href.starts_with('#') => {
+                                self.flush();
+                                self.blocks.push(ReviewBlock::link(text, href.to_string()));
+                            }
+                            _ => self.text.push_str(&text),
+                        }
+                    }
+                    "script" | "style" => {}
+                    _ if is_quote(element) => {
+                        self.flush();
+                        let text = element_ref
+                            .text()
+                            .map(|t| t.trim())
+                            .filter(|t| !t.is_empty())
+                            .collect::<Vec<_>>()
+                            .join("\n");
+                        if !text.is_empty() {
+                            self.blocks.push(ReviewBlock::quote(text));
+                        }
+                    }
+                    name => {
+                        let block = matches!(name, "p" | "div" | "li" | "tr");
+                        if block {
+                            self.flush();
+                        }
+                        for child in node.children() {
+                            self.node(child);
+                        }
+                        if block {
+                            self.flush();
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn flush(&mut self) {
+        let text = self.text.trim();
+        if !text.is_empty() {
+            self.blocks.push(ReviewBlock::text(text.to_string()));
+        }
+        self.text.clear();
+    }
+
+    fn finish(mut self) -> Vec<ReviewBlock> {
+        self.flush();
+        self.blocks
+    }
+}
+
+// 引用是 <blockquote> 或 class 带 quote 的 div（例如 jieqiQuote）
+fn is_quote(element: &scraper::node::Element) -> bool {
+    element.name() == "blockquote"
+        || element
+            .classes()
+            .any(|class| class.to_ascii_lowercase().contains("quote"))
+}
@@ -0,0 +1,126 @@
+use super::client::Wenku8Client;
+use super::error::Wenku8Error;
+use super::language::Language;
+use super::models::*;
+use crate::source::{NovelSource, DEFAULT_SOURCE_ID};
+use crate::CLIENT;
+use anyhow::Result;
+use tracing::warn;
+
+pub(crate) const APP_SOURCE_ID: &str = "wenku8_app";
+
+fn is_challenge(e: &anyhow::Error) -> bool {
+    Wenku8Error::of(e) == Some(Wenku8Error::CloudflareChallenge)
+}
+
+#[async_trait::async_trait]
+impl NovelSource for Wenku8Client {
+    fn id(&self) -> &'static str {
+        DEFAULT_SOURCE_ID
+    }
+
+    fn name(&self) -> &'static str {
+        "轻小说文库"
+    }
+
+    async fn index(&self) -> Result<Vec<HomeBlock>> {
+        Wenku8Client::index(self).await
+    }
+
+    async fn search(
+        &self,
+        search_type: &str,
+        search_key: &str,
+        page: i32,
+    ) -> Result<PageStats<NovelCover>> {
+        Wenku8Client::search(self, search_type, search_key, page).await
+    }
+
+    // 网页被 Cloudflare 拦截时改用 app 接口，保证阅读和下载可用
+    async fn novel_info(&self, aid: &str) -> Result<NovelInfo> {
+        match Wenku8Client::novel_info(self, aid).await {
+            Err(e) if is_challenge(&e) => {
+                warn!(aid, "Web blocked by challenge, using app api for novel info");
+                self.app_novel_info(aid).await
+            }
+            result => result,
+        }
+    }
+
+    async fn novel_reader(&self, aid: &str) -> Result<Vec<Volume>> {
+        match Wenku8Client::novel_reader(self, aid).await {
+            Err(e) if is_challenge(&e) => {
+                warn!(aid, "Web blocked by challenge, using app api for novel reader");
+                self.app_novel_reader(aid).await
+            }
+            result => result,
+        }
+    }
+
+    async fn chapter_content(&self, aid: &str, cid: &str) -> Result<String> {
+        self.c_content(aid, cid).await
+    }
+
+    async fn chapter_content_in(&self, aid: &str, cid: &str, language: Language) -> Result<String> {
+        self.c_content_in(aid, cid, language).await
+    }
+
+    async fn download_image(&self, url: &str) -> Result<Vec<u8>> {
+        Wenku8Client::download_image(self, url).await
+    }
+
+    async fn bookshelf(&self) -> Result<Vec<BookshelfItem>> {
+        self.get_bookshelf().await
+    }
+}
+
+/// 只使用 android.php 接口的书源，书架仍然来自网页
+pub(crate) struct Wenku8AppSource;
+
+#[async_trait::async_trait]
+impl NovelSource for Wenku8AppSource {
+    fn id(&self) -> &'static str {
+        APP_SOURCE_ID
+    }
+
+    fn name(&self) -> &'static str {
+        "轻小说文库 (App)"
+    }
+
+    async fn index(&self) -> Result<Vec<HomeBlock>> {
+        CLIENT.app_index().await
+    }
+
+    async fn search(
+        &self,
+        search_type: &str,
+        search_key: &str,
+        page: i32,
+    ) -> Result<PageStats<NovelCover>> {
+        CLIENT.app_search(search_type, search_key, page).await
+    }
+
+    async fn novel_info(&self, aid: &str) -> Result<NovelInfo> {
+        CLIENT.app_novel_info(aid).await
+    }
+
+    async fn novel_reader(&self, aid: &str) -> Result<Vec<Volume>> {
+        CLIENT.app_novel_reader(aid).await
+    }
+
+    async fn chapter_content(&self, aid: &str, cid: &str) -> Result<String> {
+        CLIENT.c_content(aid, cid).await
+    }
+
+    async fn chapter_content_in(&self, aid: &str, cid: &str, language: Language) -> Result<String> {
+        CLIENT.c_content_in(aid, cid, language).await
+    }
+
+    async fn download_image(&self, url: &str) -> Result<Vec<u8>> {
+        CLIENT.download_image(url).await
+    }
+
+    async fn bookshelf(&self) -> Result<Vec<BookshelfItem>> {
+        CLIENT.get_bookshelf().await
+    }
+}
@@ -0,0 +1,133 @@
+//! 离线简繁转换，词典在 dict 目录，先按最长的短语匹配，匹配不到再逐字转换
+//!
+//! 词典格式与 OpenCC 相同：每行 "原文\t译文"，多个译文用空格分隔时取第一个
+
+use crate::api::database::{load_property, save_property};
+use crate::wenku8::Language;
+use crate::Result;
+use once_cell::sync::Lazy;
+use std::collections::{HashMap, HashSet};
+use tokio::sync::RwLock;
+
+struct Dictionary {
+    chars: HashMap<char, char>,
+    phrases: HashMap<String, String>,
+    /// 短语的首字，不是首字的位置不用查短语
+    phrase_starts: HashSet<char>,
+    /// 最长短语的字数
+    max_phrase_len: usize,
+}
+
+impl Dictionary {
+    fn new(chars: &str, phrases: &str) -> Dictionary {
+        let chars = entries(chars)
+            .filter_map(|(from, to)| {
+                let mut from = from.chars();
+                let mut to = to.chars();
+                match (from.next(), from.next(), to.next(), to.next()) {
+                    (Some(from), None, Some(to), None) => Some((from, to)),
+                    _ => None,
+                }
+            })
+            .collect();
+        let phrases = entries(phrases)
+            .map(|(from, to)| (from.to_string(), to.to_string()))
+            .collect::<HashMap<_, _>>();
+        let phrase_starts = phrases.keys().filter_map(|k| k.chars().next()).collect();
+        let max_phrase_len = phrases.keys().map(|k| k.chars().count()).max().unwrap_or(0);
+        Dictionary {
+            chars,
+            phrases,
+            phrase_starts,
+            max_phrase_len,
+        }
+    }
+
+    fn convert(&self, text: &str) -> String {
+        let chars = text.chars().collect::<Vec<_>>();
+        let mut result = String::with_capacity(text.len());
+        let mut i = 0;
+        'outer: while i < chars.len() {
+            if self.phrase_starts.contains(&chars[i]) {
+                let max = self.max_phrase_len.min(chars.len() - i);
+                for len in (2..=max).rev() {
+                    let key = chars[i..i + len].iter().collect::<String>();
+                    if let Some(value) = self.phrases.get(&key) {
+                        result.push_str(value);
+                        i += len;
+                        continue 'outer;
+                    }
+                }
+            }
+            result.push(*self.chars.get(&chars[i]).unwrap_or(&chars[i]));
+            i += 1;
+        }
+        result
+    }
+}
+
+// 一对多的条目只取第一个译文，# 开头为注释
+fn entries(text: &str) -> impl Iterator<Item = (&str, &str)> {
+    text.lines()
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| {
+            let (from, to) = line.split_once('\t')?;
+            Some((from, to.split(' ').next()?))
+        })
+}
+
+static S2T: Lazy<Dictionary> = Lazy::new(|| {
+    Dictionary::new(
+        include_str!("../dict/STCharacters.txt"),
+        include_str!("../dict/STPhrases.txt"),
+    )
+});
+
+static T2S: Lazy<Dictionary> = Lazy::new(|| {
+    Dictionary::new(
+        include_str!("../dict/TSCharacters.txt"),
+        include_str!("../dict/TSPhrases.txt"),
+    )
+});
+
+/// 把文字转换为 to 指定的简体或繁体
+pub fn convert(text: &str, to: Language) -> String {
+    match to {
+        Language::Simplified => T2S.convert(text),
+        Language::Traditional => S2T.convert(text),
+    }
+}
+
+/// 阅读和导出时转换成的文字，None 为不转换
+static DISPLAY_LANGUAGE: Lazy<RwLock<Option<Language>>> = Lazy::new(|| RwLock::new(None));
+
+pub(crate) async fn init_display_language() -> Result<()> {
+    let value = load_property("display_language".to_string()).await?;
+    let language = (!value.is_empty()).then(|| Language::from_code(&value));
+    *DISPLAY_LANGUAGE.write().await = language;
+    Ok(())
+}
+
+pub(crate) async fn load_display_language() -> Option<Language> {
+    *DISPLAY_LANGUAGE.read().await
+}
+
+pub(crate) async fn set_display_language(language: Option<Language>) -> Result<()> {
+    let value = language.map(|l| l.code().to_string()).unwrap_or_default();
+    save_property("display_language".to_string(), value).await?;
+    *DISPLAY_LANGUAGE.write().await = language;
+    Ok(())
+}
+
+/// 语言为 from 的文字转换为 to，to 为 None 或与 from 相同时原样返回
+pub(crate) fn convert_from(text: String, from: Language, to: Option<Language>) -> String {
+    match to {
+        Some(to) if to != from => convert(&text, to),
+        _ => text,
+    }
+}
+
+/// 按阅读设置转换语言为 from 的文字
+pub(crate) async fn to_display(text: String, from: Language) -> String {
+    convert_from(text, from, load_display_language().await)
+}